@@ -0,0 +1,71 @@
+//! Measures the cost of polling high-frequency events.
+//!
+//! Every frame, a burst of mouse motion events is pushed to the queue and
+//! drained again with `EventPump::poll_into()`. Besides the time per event,
+//! the number of heap allocations made while polling is reported; it should
+//! stay at zero once the event buffer has grown.
+//!
+//! Run with `cargo bench --bench event`.
+
+extern crate sdl2;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use sdl2::event::{Event, push_event};
+use sdl2::mouse::MouseState;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const FRAMES: usize = 100;
+const EVENTS_PER_FRAME: usize = 1000;
+
+fn main() {
+    let mut sdl_context = sdl2::init().events().unwrap();
+    let mut events = Vec::with_capacity(EVENTS_PER_FRAME);
+
+    let mut elapsed = 0.0;
+    let mut allocations = 0;
+
+    for frame in 0..FRAMES {
+        for i in 0..EVENTS_PER_FRAME {
+            push_event(Event::MouseMotion {
+                timestamp: 0, window_id: 0, which: 0,
+                mousestate: MouseState::from_flags(0),
+                x: i as i32, y: frame as i32, xrel: 1, yrel: 0
+            }).unwrap();
+        }
+
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+
+        sdl_context.event_pump().poll_into(&mut events);
+
+        let duration = start.elapsed();
+        elapsed += duration.as_secs() as f64 * 1e9 + duration.subsec_nanos() as f64;
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+        assert_eq!(events.len(), EVENTS_PER_FRAME);
+    }
+
+    let total = (FRAMES * EVENTS_PER_FRAME) as f64;
+    println!("poll_into: {:.1} ns/event, {} allocations over {} events",
+             elapsed / total, allocations, total);
+}
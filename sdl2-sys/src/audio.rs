@@ -63,6 +63,10 @@ pub type SDL_AudioStatus = c_uint;
 pub const SDL_AUDIO_STOPPED: c_uint = 0;
 pub const SDL_AUDIO_PLAYING: c_uint = 1;
 pub const SDL_AUDIO_PAUSED: c_uint = 2;
+pub const SDL_MIX_MAXVOLUME: c_int = 128;
+pub const SDL_AUDIO_ALLOW_FREQUENCY_CHANGE: c_int = 0x00000001;
+pub const SDL_AUDIO_ALLOW_FORMAT_CHANGE: c_int = 0x00000002;
+pub const SDL_AUDIO_ALLOW_CHANNELS_CHANGE: c_int = 0x00000004;
 extern "C" {
     pub fn SDL_GetNumAudioDrivers() -> c_int;
     pub fn SDL_GetAudioDriver(index: c_int) -> *const c_char;
@@ -103,3 +107,28 @@ extern "C" {
     pub fn SDL_CloseAudio();
     pub fn SDL_CloseAudioDevice(dev: SDL_AudioDeviceID);
 }
+
+#[cfg(feature = "sdl_2_0_4")]
+extern "C" {
+    pub fn SDL_QueueAudio(dev: SDL_AudioDeviceID, data: *const c_void,
+                          len: uint32_t) -> c_int;
+    pub fn SDL_GetQueuedAudioSize(dev: SDL_AudioDeviceID) -> uint32_t;
+    pub fn SDL_ClearQueuedAudio(dev: SDL_AudioDeviceID);
+}
+
+#[cfg(feature = "sdl_2_0_7")]
+pub type SDL_AudioStream = c_void;
+#[cfg(feature = "sdl_2_0_7")]
+extern "C" {
+    pub fn SDL_NewAudioStream(src_format: SDL_AudioFormat, src_channels: uint8_t,
+                              src_rate: c_int, dst_format: SDL_AudioFormat,
+                              dst_channels: uint8_t, dst_rate: c_int) -> *mut SDL_AudioStream;
+    pub fn SDL_AudioStreamPut(stream: *mut SDL_AudioStream, buf: *const c_void,
+                              len: c_int) -> c_int;
+    pub fn SDL_AudioStreamGet(stream: *mut SDL_AudioStream, buf: *mut c_void,
+                              len: c_int) -> c_int;
+    pub fn SDL_AudioStreamAvailable(stream: *mut SDL_AudioStream) -> c_int;
+    pub fn SDL_AudioStreamFlush(stream: *mut SDL_AudioStream) -> c_int;
+    pub fn SDL_AudioStreamClear(stream: *mut SDL_AudioStream);
+    pub fn SDL_FreeAudioStream(stream: *mut SDL_AudioStream);
+}
@@ -1,7 +1,10 @@
 #[cfg(feature = "no_std")]
 use core::prelude::*;
 use libc::{c_int, c_char, c_uchar, c_uint, c_void, int16_t, uint8_t};
+#[cfg(feature = "sdl_2_0_14")]
+use libc::c_float;
 use joystick::{SDL_Joystick, SDL_JoystickGUID};
+use rwops::SDL_RWops;
 
 pub type SDL_bool = c_int;
 
@@ -78,10 +81,11 @@ pub const SDL_CONTROLLER_BUTTON_MAX: SDL_GameControllerButton = 15;
 
 extern "C" {
     pub fn SDL_GameControllerAddMapping(mappingString: *const c_char) -> c_int;
+    pub fn SDL_GameControllerAddMappingsFromRW(rw: *mut SDL_RWops, freerw: c_int) -> c_int;
     pub fn SDL_GameControllerMappingForGUID(guid: SDL_JoystickGUID) ->
-              *const c_char;
+              *mut c_char;
     pub fn SDL_GameControllerMapping(gamecontroller: *mut SDL_GameController)
-              -> *const c_char;
+              -> *mut c_char;
     pub fn SDL_IsGameController(joystick_index: c_int) -> SDL_bool;
     pub fn SDL_GameControllerNameForIndex(joystick_index: c_int) ->
               *const c_char;
@@ -125,3 +129,22 @@ extern "C" {
               -> uint8_t;
     pub fn SDL_GameControllerClose(gamecontroller: *mut SDL_GameController);
 }
+
+pub type SDL_SensorType = c_int;
+pub const SDL_SENSOR_INVALID: SDL_SensorType = -1;
+pub const SDL_SENSOR_UNKNOWN: SDL_SensorType = 0;
+pub const SDL_SENSOR_ACCEL: SDL_SensorType = 1;
+pub const SDL_SENSOR_GYRO: SDL_SensorType = 2;
+
+#[cfg(feature = "sdl_2_0_14")]
+extern "C" {
+    pub fn SDL_GameControllerHasSensor(gamecontroller: *mut SDL_GameController,
+                                       type_: SDL_SensorType) -> SDL_bool;
+    pub fn SDL_GameControllerSetSensorEnabled(gamecontroller: *mut SDL_GameController,
+                                              type_: SDL_SensorType, enabled: SDL_bool) -> c_int;
+    pub fn SDL_GameControllerIsSensorEnabled(gamecontroller: *mut SDL_GameController,
+                                             type_: SDL_SensorType) -> SDL_bool;
+    pub fn SDL_GameControllerGetSensorData(gamecontroller: *mut SDL_GameController,
+                                           type_: SDL_SensorType, data: *mut c_float,
+                                           num_values: c_int) -> c_int;
+}
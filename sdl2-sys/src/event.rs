@@ -17,7 +17,7 @@ pub const SDL_DISABLE: SDL_EventState = 0;
 pub const SDL_ENABLE: SDL_EventState = 1;
 pub const SDL_QUERY: SDL_EventState = 0xFF;
 
-pub type SDL_SysWMmsg = c_void;
+pub use syswm::SDL_SysWMmsg;
 
 pub type SDL_EventType = c_uint;
 pub const SDL_FIRSTEVENT: SDL_EventType = 0;
@@ -51,6 +51,8 @@ pub const SDL_CONTROLLERBUTTONUP: SDL_EventType = 1618;
 pub const SDL_CONTROLLERDEVICEADDED: SDL_EventType = 1619;
 pub const SDL_CONTROLLERDEVICEREMOVED: SDL_EventType = 1620;
 pub const SDL_CONTROLLERDEVICEREMAPPED: SDL_EventType = 1621;
+#[cfg(feature = "sdl_2_0_14")]
+pub const SDL_CONTROLLERSENSORUPDATE: SDL_EventType = 1625;
 pub const SDL_FINGERDOWN: SDL_EventType = 1792;
 pub const SDL_FINGERUP: SDL_EventType = 1793;
 pub const SDL_FINGERMOTION: SDL_EventType = 1794;
@@ -59,6 +61,8 @@ pub const SDL_DOLLARRECORD: SDL_EventType = 2049;
 pub const SDL_MULTIGESTURE: SDL_EventType = 2050;
 pub const SDL_CLIPBOARDUPDATE: SDL_EventType = 2304;
 pub const SDL_DROPFILE: SDL_EventType = 4096;
+pub const SDL_AUDIODEVICEADDED: SDL_EventType = 4352;
+pub const SDL_AUDIODEVICEREMOVED: SDL_EventType = 4353;
 pub const SDL_USEREVENT: SDL_EventType = 32768;
 pub const SDL_LASTEVENT: SDL_EventType = 65535;
 
@@ -154,6 +158,12 @@ pub struct SDL_MouseWheelEvent {
     pub which: uint32_t,
     pub x: int32_t,
     pub y: int32_t,
+    #[cfg(feature = "sdl_2_0_4")]
+    pub direction: uint32_t,
+    #[cfg(feature = "sdl_2_0_18")]
+    pub preciseX: c_float,
+    #[cfg(feature = "sdl_2_0_18")]
+    pub preciseY: c_float,
 }
 
 #[derive(Copy, Clone)]
@@ -216,6 +226,18 @@ pub struct SDL_JoyDeviceEvent {
     pub which: int32_t,
 }
 
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_AudioDeviceEvent {
+    pub type_: uint32_t,
+    pub timestamp: uint32_t,
+    pub which: uint32_t,
+    pub iscapture: uint8_t,
+    pub padding1: uint8_t,
+    pub padding2: uint8_t,
+    pub padding3: uint8_t,
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct SDL_ControllerAxisEvent {
@@ -250,6 +272,17 @@ pub struct SDL_ControllerDeviceEvent {
     pub which: int32_t,
 }
 
+#[cfg(feature = "sdl_2_0_14")]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_ControllerSensorEvent {
+    pub type_: uint32_t,
+    pub timestamp: uint32_t,
+    pub which: int32_t,
+    pub sensor: int32_t,
+    pub data: [c_float; 3],
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct SDL_TouchFingerEvent {
@@ -407,6 +440,15 @@ impl SDL_Event {
         self.data.as_mut_ptr() as *mut _
     }
 
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn csensor(&mut self) -> *mut SDL_ControllerSensorEvent {
+        self.data.as_mut_ptr() as *mut _
+    }
+
+    pub fn adevice(&mut self) -> *mut SDL_AudioDeviceEvent {
+        self.data.as_mut_ptr() as *mut _
+    }
+
     pub fn quit(&mut self) -> *mut SDL_QuitEvent {
         self.data.as_mut_ptr() as *mut _
     }
@@ -459,10 +501,10 @@ extern "C" {
     pub fn SDL_WaitEventTimeout(event: *mut SDL_Event, timeout: c_int) ->
               c_int;
     pub fn SDL_PushEvent(event: *mut SDL_Event) -> c_int;
-    pub fn SDL_SetEventFilter(filter: SDL_EventFilter,
+    pub fn SDL_SetEventFilter(filter: Option<SDL_EventFilter>,
                                     userdata: *mut c_void);
-    /*pub fn SDL_GetEventFilter(filter: *SDL_EventFilter,
-                                    userdata: **c_void) -> SDL_bool;*/
+    pub fn SDL_GetEventFilter(filter: *mut Option<SDL_EventFilter>,
+                                    userdata: *mut *mut c_void) -> SDL_bool;
     pub fn SDL_AddEventWatch(filter: SDL_EventFilter, userdata: *mut c_void);
     pub fn SDL_DelEventWatch(filter: SDL_EventFilter, userdata: *mut c_void);
     pub fn SDL_FilterEvents(filter: SDL_EventFilter, userdata: *mut c_void);
@@ -20,6 +20,12 @@ pub const SDL_HAPTIC_AUTOCENTER: uint16_t = 1 << 13;
 pub const SDL_HAPTIC_STATUS: uint16_t = 1 << 14;
 pub const SDL_HAPTIC_PAUSE: uint16_t = 1 << 15;
 
+pub const SDL_HAPTIC_POLAR: uint8_t = 0;
+pub const SDL_HAPTIC_CARTESIAN: uint8_t = 1;
+pub const SDL_HAPTIC_SPHERICAL: uint8_t = 2;
+
+pub const SDL_HAPTIC_INFINITY: uint32_t = 4294967295;
+
 pub type SDL_Haptic = c_void;
 
 #[derive(Copy, Clone)]
@@ -85,6 +91,7 @@ pub struct SDL_HapticCondition {
 #[repr(C)]
 pub struct SDL_HapticRamp {
     pub type_: uint16_t,
+    pub direction: SDL_HapticDirection,
     pub length: uint32_t,
     pub delay: uint16_t,
     pub button: uint16_t,
@@ -125,8 +132,9 @@ pub struct SDL_HapticCustom {
     pub fade_level: uint16_t,
 }
 
+// The C union contains pointers, so it needs their alignment.
 #[allow(missing_copy_implementations)]
-#[repr(C)]
+#[repr(C, align(8))]
 pub struct SDL_HapticEffect {
     pub data: [uint8_t; 72],
 }
@@ -178,7 +186,7 @@ extern "C" {
     pub fn SDL_HapticNumAxes(haptic: *mut SDL_Haptic) -> c_int;
     pub fn SDL_HapticEffectSupported(haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect) -> c_int;
     pub fn SDL_HapticNewEffect(haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect) -> c_int;
-    pub fn SDL_HapticUpdateEffect(haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect) -> c_int;
+    pub fn SDL_HapticUpdateEffect(haptic: *mut SDL_Haptic, effect: c_int, data: *mut SDL_HapticEffect) -> c_int;
     pub fn SDL_HapticRunEffect(haptic: *mut SDL_Haptic, effect: c_int, iterations: uint32_t) -> c_int;
     pub fn SDL_HapticStopEffect(haptic: *mut SDL_Haptic, effect: c_int) -> c_int;
     pub fn SDL_HapticDestroyEffect(haptic: *mut SDL_Haptic, effect: c_int);
@@ -1,4 +1,6 @@
 use libc::{c_int, c_char, c_void, int32_t, int16_t, int8_t, uint8_t};
+#[cfg(feature = "sdl_2_0_6")]
+use libc::uint16_t;
 
 pub type SDL_bool = c_int;
 
@@ -11,6 +13,15 @@ pub struct SDL_JoystickGUID {
     pub data: [uint8_t; 16],
 }
 
+pub type SDL_JoystickPowerLevel = c_int;
+pub const SDL_JOYSTICK_POWER_UNKNOWN: SDL_JoystickPowerLevel = -1;
+pub const SDL_JOYSTICK_POWER_EMPTY: SDL_JoystickPowerLevel = 0;
+pub const SDL_JOYSTICK_POWER_LOW: SDL_JoystickPowerLevel = 1;
+pub const SDL_JOYSTICK_POWER_MEDIUM: SDL_JoystickPowerLevel = 2;
+pub const SDL_JOYSTICK_POWER_FULL: SDL_JoystickPowerLevel = 3;
+pub const SDL_JOYSTICK_POWER_WIRED: SDL_JoystickPowerLevel = 4;
+pub const SDL_JOYSTICK_POWER_MAX: SDL_JoystickPowerLevel = 5;
+
 extern "C" {
     pub fn SDL_NumJoysticks() -> c_int;
     pub fn SDL_JoystickNameForIndex(device_index: c_int) -> *const c_char;
@@ -42,3 +53,39 @@ extern "C" {
               -> uint8_t;
     pub fn SDL_JoystickClose(joystick: *mut SDL_Joystick);
 }
+
+#[cfg(feature = "sdl_2_0_4")]
+extern "C" {
+    pub fn SDL_JoystickCurrentPowerLevel(joystick: *mut SDL_Joystick) -> SDL_JoystickPowerLevel;
+    pub fn SDL_JoystickFromInstanceID(joyid: int32_t) -> *mut SDL_Joystick;
+}
+
+#[cfg(feature = "sdl_2_0_6")]
+extern "C" {
+    pub fn SDL_JoystickGetDeviceVendor(device_index: c_int) -> uint16_t;
+    pub fn SDL_JoystickGetDeviceProduct(device_index: c_int) -> uint16_t;
+    pub fn SDL_JoystickGetDeviceProductVersion(device_index: c_int) -> uint16_t;
+    pub fn SDL_JoystickGetDeviceInstanceID(device_index: c_int) -> int32_t;
+    pub fn SDL_JoystickGetVendor(joystick: *mut SDL_Joystick) -> uint16_t;
+    pub fn SDL_JoystickGetProduct(joystick: *mut SDL_Joystick) -> uint16_t;
+    pub fn SDL_JoystickGetProductVersion(joystick: *mut SDL_Joystick) -> uint16_t;
+}
+
+pub type SDL_JoystickType = c_int;
+pub const SDL_JOYSTICK_TYPE_UNKNOWN: SDL_JoystickType = 0;
+pub const SDL_JOYSTICK_TYPE_GAMECONTROLLER: SDL_JoystickType = 1;
+
+#[cfg(feature = "sdl_2_0_14")]
+extern "C" {
+    pub fn SDL_JoystickGetSerial(joystick: *mut SDL_Joystick) -> *const c_char;
+    pub fn SDL_JoystickAttachVirtual(type_: SDL_JoystickType, naxes: c_int,
+                                     nbuttons: c_int, nhats: c_int) -> c_int;
+    pub fn SDL_JoystickDetachVirtual(device_index: c_int) -> c_int;
+    pub fn SDL_JoystickIsVirtual(device_index: c_int) -> SDL_bool;
+    pub fn SDL_JoystickSetVirtualAxis(joystick: *mut SDL_Joystick, axis: c_int,
+                                      value: int16_t) -> c_int;
+    pub fn SDL_JoystickSetVirtualButton(joystick: *mut SDL_Joystick, button: c_int,
+                                        value: uint8_t) -> c_int;
+    pub fn SDL_JoystickSetVirtualHat(joystick: *mut SDL_Joystick, hat: c_int,
+                                     value: uint8_t) -> c_int;
+}
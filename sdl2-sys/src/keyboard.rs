@@ -9,6 +9,7 @@ pub type SDL_bool = c_int;
 
 // SDL_keyboard.h
 #[derive(Copy, Clone)]
+#[repr(C)]
 pub struct SDL_Keysym {
     pub scancode: SDL_Scancode,
     pub sym: SDL_Keycode,
@@ -25,11 +25,15 @@ pub mod rect;
 pub mod pixels;
 pub mod render;
 pub mod rwops;
+pub mod shape;
 pub mod surface;
 pub mod touch;
 pub mod video;
 pub mod mouse;
 pub mod sdl;
+pub mod syswm;
 pub mod timer;
 pub mod version;
+#[cfg(feature = "sdl_2_0_6")]
+pub mod vulkan;
 pub mod hint;
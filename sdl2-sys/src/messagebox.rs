@@ -1,4 +1,4 @@
-use libc::{c_int, c_char, uint32_t};
+use libc::{c_int, c_char, uint8_t, uint32_t};
 use video::SDL_Window;
 
 pub type SDL_MessageBoxFlags = u32;
@@ -6,6 +6,53 @@ pub const SDL_MESSAGEBOX_ERROR : SDL_MessageBoxFlags = 0x00000010;
 pub const SDL_MESSAGEBOX_WARNING : SDL_MessageBoxFlags = 0x00000020;
 pub const SDL_MESSAGEBOX_INFORMATION : SDL_MessageBoxFlags = 0x00000040;
 
+pub type SDL_MessageBoxButtonFlags = u32;
+pub const SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT : SDL_MessageBoxButtonFlags = 0x00000001;
+pub const SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT : SDL_MessageBoxButtonFlags = 0x00000002;
+
+#[allow(missing_copy_implementations)]
+#[repr(C)]
+pub struct SDL_MessageBoxButtonData {
+    pub flags: uint32_t,
+    pub buttonid: c_int,
+    pub text: *const c_char,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_MessageBoxColor {
+    pub r: uint8_t,
+    pub g: uint8_t,
+    pub b: uint8_t,
+}
+
+pub type SDL_MessageBoxColorType = c_int;
+pub const SDL_MESSAGEBOX_COLOR_BACKGROUND : SDL_MessageBoxColorType = 0;
+pub const SDL_MESSAGEBOX_COLOR_TEXT : SDL_MessageBoxColorType = 1;
+pub const SDL_MESSAGEBOX_COLOR_BUTTON_BORDER : SDL_MessageBoxColorType = 2;
+pub const SDL_MESSAGEBOX_COLOR_BUTTON_BACKGROUND : SDL_MessageBoxColorType = 3;
+pub const SDL_MESSAGEBOX_COLOR_BUTTON_SELECTED : SDL_MessageBoxColorType = 4;
+pub const SDL_MESSAGEBOX_COLOR_MAX : SDL_MessageBoxColorType = 5;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_MessageBoxColorScheme {
+    pub colors: [SDL_MessageBoxColor; 5],
+}
+
+#[allow(missing_copy_implementations, non_snake_case)]
+#[repr(C)]
+pub struct SDL_MessageBoxData {
+    pub flags: uint32_t,
+    pub window: *mut SDL_Window,
+    pub title: *const c_char,
+    pub message: *const c_char,
+    pub numbuttons: c_int,
+    pub buttons: *const SDL_MessageBoxButtonData,
+    pub colorScheme: *const SDL_MessageBoxColorScheme,
+}
+
 extern "C" {
     pub fn SDL_ShowSimpleMessageBox(flags: uint32_t, title: *const c_char, message: *const c_char, window: *mut SDL_Window) -> c_int;
+    pub fn SDL_ShowMessageBox(messageboxdata: *const SDL_MessageBoxData, buttonid: *mut c_int) -> c_int;
 }
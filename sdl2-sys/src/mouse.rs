@@ -5,6 +5,10 @@ use video::SDL_Window;
 pub type SDL_bool = c_int;
 pub type SDL_Cursor = c_void;
 
+pub type SDL_MouseWheelDirection = uint32_t;
+pub const SDL_MOUSEWHEEL_NORMAL: SDL_MouseWheelDirection = 0;
+pub const SDL_MOUSEWHEEL_FLIPPED: SDL_MouseWheelDirection = 1;
+
 pub type SDL_SystemCursor = c_uint;
 pub const SDL_SYSTEM_CURSOR_ARROW: SDL_SystemCursor = 0;
 pub const SDL_SYSTEM_CURSOR_IBEAM: SDL_SystemCursor = 1;
@@ -56,3 +60,10 @@ extern "C" {
     pub fn SDL_FreeCursor(cursor: *mut SDL_Cursor);
     pub fn SDL_ShowCursor(toggle: SDL_MouseState) -> SDL_MouseState;
 }
+
+#[cfg(feature = "sdl_2_0_4")]
+extern "C" {
+    pub fn SDL_WarpMouseGlobal(x: c_int, y: c_int);
+    pub fn SDL_GetGlobalMouseState(x: *mut c_int, y: *mut c_int) -> uint32_t;
+    pub fn SDL_CaptureMouse(enabled: SDL_bool) -> c_int;
+}
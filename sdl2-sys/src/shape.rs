@@ -0,0 +1,43 @@
+#![allow(non_upper_case_globals, non_snake_case)]
+
+use libc::{c_int, c_uint, c_char, uint8_t, uint32_t};
+use pixels::SDL_Color;
+use surface::SDL_Surface;
+use video::{SDL_bool, SDL_Window};
+
+pub const SDL_NONSHAPEABLE_WINDOW: c_int = -1;
+pub const SDL_INVALID_SHAPE_ARGUMENT: c_int = -2;
+pub const SDL_WINDOW_LACKS_SHAPE: c_int = -3;
+
+pub type WindowShapeMode = c_int;
+pub const ShapeModeDefault: WindowShapeMode = 0;
+pub const ShapeModeBinarizeAlpha: WindowShapeMode = 1;
+pub const ShapeModeReverseBinarizeAlpha: WindowShapeMode = 2;
+pub const ShapeModeColorKey: WindowShapeMode = 3;
+
+/// A C union of `uint8_t binarizationCutoff` and `SDL_Color colorKey`.
+/// `binarizationCutoff` shares its storage with `colorKey.r`.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_WindowShapeParams {
+    pub colorKey: SDL_Color,
+}
+
+impl SDL_WindowShapeParams {
+    pub fn binarizationCutoff(&self) -> uint8_t { self.colorKey.r }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_WindowShapeMode {
+    pub mode: WindowShapeMode,
+    pub parameters: SDL_WindowShapeParams,
+}
+
+//SDL_shape.h
+extern "C" {
+    pub fn SDL_CreateShapedWindow(title: *const c_char, x: c_uint, y: c_uint, w: c_uint, h: c_uint, flags: uint32_t) -> *mut SDL_Window;
+    pub fn SDL_IsShapedWindow(window: *const SDL_Window) -> SDL_bool;
+    pub fn SDL_SetWindowShape(window: *mut SDL_Window, shape: *mut SDL_Surface, shape_mode: *mut SDL_WindowShapeMode) -> c_int;
+    pub fn SDL_GetShapedWindowMode(window: *mut SDL_Window, shape_mode: *mut SDL_WindowShapeMode) -> c_int;
+}
@@ -0,0 +1,124 @@
+#![allow(non_snake_case)]
+use libc::{c_int, c_uint, c_ulong, c_void};
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+use libc::c_long;
+use version::SDL_version;
+use video::{SDL_bool, SDL_Window};
+
+// SDL_syswm.h
+pub type SDL_SYSWM_TYPE = c_int;
+pub const SDL_SYSWM_UNKNOWN: SDL_SYSWM_TYPE = 0;
+pub const SDL_SYSWM_WINDOWS: SDL_SYSWM_TYPE = 1;
+pub const SDL_SYSWM_X11: SDL_SYSWM_TYPE = 2;
+pub const SDL_SYSWM_DIRECTFB: SDL_SYSWM_TYPE = 3;
+pub const SDL_SYSWM_COCOA: SDL_SYSWM_TYPE = 4;
+pub const SDL_SYSWM_UIKIT: SDL_SYSWM_TYPE = 5;
+pub const SDL_SYSWM_WAYLAND: SDL_SYSWM_TYPE = 6;
+pub const SDL_SYSWM_MIR: SDL_SYSWM_TYPE = 7;
+pub const SDL_SYSWM_WINRT: SDL_SYSWM_TYPE = 8;
+pub const SDL_SYSWM_ANDROID: SDL_SYSWM_TYPE = 9;
+
+/// The contents of Xlib's `XEvent` union (`long pad[24]`).
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+pub type XEvent = [c_long; 24];
+
+#[cfg(windows)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMmsg_data {
+    pub hwnd: *mut c_void,
+    pub msg: c_uint,
+    pub wParam: usize,
+    pub lParam: isize,
+}
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMmsg_data {
+    pub event: XEvent,
+}
+
+#[cfg(not(any(windows, all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))))]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMmsg_data {
+    pub dummy: c_int,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMmsg {
+    pub version: SDL_version,
+    pub subsystem: SDL_SYSWM_TYPE,
+    pub msg: SDL_SysWMmsg_data,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_win {
+    pub window: *mut c_void,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_x11 {
+    pub display: *mut c_void,
+    pub window: c_ulong,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_cocoa {
+    pub window: *mut c_void,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_uikit {
+    pub window: *mut c_void,
+    pub framebuffer: c_uint,
+    pub colorbuffer: c_uint,
+    pub resolveFramebuffer: c_uint,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_wl {
+    pub display: *mut c_void,
+    pub surface: *mut c_void,
+    pub shell_surface: *mut c_void,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo_android {
+    pub window: *mut c_void,
+    pub surface: *mut c_void,
+}
+
+/// The subsystem-specific part of `SDL_SysWMinfo`; `subsystem` says which
+/// field is valid. Newer SDL versions pad the union to 64 bytes.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union SDL_SysWMinfo_info {
+    pub win: SDL_SysWMinfo_win,
+    pub x11: SDL_SysWMinfo_x11,
+    pub cocoa: SDL_SysWMinfo_cocoa,
+    pub uikit: SDL_SysWMinfo_uikit,
+    pub wl: SDL_SysWMinfo_wl,
+    pub android: SDL_SysWMinfo_android,
+    pub dummy: [u8; 64],
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SDL_SysWMinfo {
+    pub version: SDL_version,
+    pub subsystem: SDL_SYSWM_TYPE,
+    pub info: SDL_SysWMinfo_info,
+}
+
+extern "C" {
+    pub fn SDL_GetWindowWMInfo(window: *mut SDL_Window, info: *mut SDL_SysWMinfo) -> SDL_bool;
+}
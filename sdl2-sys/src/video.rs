@@ -1,4 +1,4 @@
-use rect::SDL_Rect;
+use rect::{SDL_Point, SDL_Rect};
 use surface::SDL_Surface;
 
 #[cfg(feature = "no_std")]
@@ -42,7 +42,9 @@ pub enum SDL_WindowFlags {
     SDL_WINDOW_MOUSE_FOCUS = 0x00000400,
     SDL_WINDOW_FULLSCREEN_DESKTOP = 0x00001001,
     SDL_WINDOW_FOREIGN = 0x00000800,
-    SDL_WINDOW_ALLOW_HIGHDPI = 0x00002000
+    SDL_WINDOW_ALLOW_HIGHDPI = 0x00002000,
+    SDL_WINDOW_ALWAYS_ON_TOP = 0x00008000,
+    SDL_WINDOW_VULKAN = 0x10000000
 }
 
 #[derive(Copy, Clone)]
@@ -92,7 +94,8 @@ pub enum SDL_GLattr {
     SDL_GL_CONTEXT_FLAGS = 20,
     SDL_GL_CONTEXT_PROFILE_MASK = 21,
     SDL_GL_SHARE_WITH_CURRENT_CONTEXT = 22,
-    SDL_GL_FRAMEBUFFER_SRGB_CAPABLE = 23
+    SDL_GL_FRAMEBUFFER_SRGB_CAPABLE = 23,
+    SDL_GL_CONTEXT_RELEASE_BEHAVIOR = 24
 }
 
 impl FromPrimitive for SDL_GLattr {
@@ -124,6 +127,7 @@ impl FromPrimitive for SDL_GLattr {
             21 => SDL_GL_CONTEXT_PROFILE_MASK,
             22 => SDL_GL_SHARE_WITH_CURRENT_CONTEXT,
             23 => SDL_GL_FRAMEBUFFER_SRGB_CAPABLE,
+            24 => SDL_GL_CONTEXT_RELEASE_BEHAVIOR,
             _  => return None,
         })
     }
@@ -138,6 +142,21 @@ pub enum SDL_GLprofile {
     SDL_GL_CONTEXT_PROFILE_ES = 0x0004
 }
 
+pub type SDL_HitTestResult = c_int;
+pub const SDL_HITTEST_NORMAL: SDL_HitTestResult = 0;
+pub const SDL_HITTEST_DRAGGABLE: SDL_HitTestResult = 1;
+pub const SDL_HITTEST_RESIZE_TOPLEFT: SDL_HitTestResult = 2;
+pub const SDL_HITTEST_RESIZE_TOP: SDL_HitTestResult = 3;
+pub const SDL_HITTEST_RESIZE_TOPRIGHT: SDL_HitTestResult = 4;
+pub const SDL_HITTEST_RESIZE_RIGHT: SDL_HitTestResult = 5;
+pub const SDL_HITTEST_RESIZE_BOTTOMRIGHT: SDL_HitTestResult = 6;
+pub const SDL_HITTEST_RESIZE_BOTTOM: SDL_HitTestResult = 7;
+pub const SDL_HITTEST_RESIZE_BOTTOMLEFT: SDL_HitTestResult = 8;
+pub const SDL_HITTEST_RESIZE_LEFT: SDL_HitTestResult = 9;
+
+pub type SDL_HitTest =
+    Option<extern "C" fn(win: *mut SDL_Window, area: *const SDL_Point, data: *mut c_void) -> SDL_HitTestResult>;
+
 //SDL_video.h
 extern "C" {
     pub fn SDL_GetNumVideoDrivers() -> c_int;
@@ -201,6 +220,7 @@ extern "C" {
     pub fn SDL_GL_GetProcAddress(procname: *const c_char) -> *const c_void;
     pub fn SDL_GL_UnloadLibrary();
     pub fn SDL_GL_ExtensionSupported(extension: *const c_char) -> SDL_bool;
+    pub fn SDL_GL_ResetAttributes();
     pub fn SDL_GL_SetAttribute(attr: SDL_GLattr, value: c_int) -> c_int;
     pub fn SDL_GL_GetAttribute(attr: SDL_GLattr, value: *mut c_int) -> c_int;
     pub fn SDL_GL_CreateContext(window: *mut SDL_Window) -> SDL_GLContext;
@@ -212,3 +232,23 @@ extern "C" {
     pub fn SDL_GL_SwapWindow(window: *mut SDL_Window);
     pub fn SDL_GL_DeleteContext(context: SDL_GLContext);
 }
+
+#[cfg(feature = "sdl_2_0_4")]
+extern "C" {
+    pub fn SDL_GetDisplayDPI(displayIndex: c_int, ddpi: *mut c_float, hdpi: *mut c_float, vdpi: *mut c_float) -> c_int;
+    pub fn SDL_GetGrabbedWindow() -> *mut SDL_Window;
+    pub fn SDL_SetWindowHitTest(window: *mut SDL_Window, callback: SDL_HitTest, callback_data: *mut c_void) -> c_int;
+}
+
+#[cfg(feature = "sdl_2_0_5")]
+extern "C" {
+    pub fn SDL_GetDisplayUsableBounds(displayIndex: c_int, rect: *mut SDL_Rect) -> c_int;
+    pub fn SDL_SetWindowResizable(window: *mut SDL_Window, resizable: SDL_bool);
+    pub fn SDL_SetWindowModalFor(modal_window: *mut SDL_Window, parent_window: *mut SDL_Window) -> c_int;
+    pub fn SDL_SetWindowInputFocus(window: *mut SDL_Window) -> c_int;
+}
+
+#[cfg(feature = "sdl_2_0_16")]
+extern "C" {
+    pub fn SDL_SetWindowAlwaysOnTop(window: *mut SDL_Window, on_top: SDL_bool);
+}
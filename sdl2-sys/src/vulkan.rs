@@ -0,0 +1,19 @@
+#![allow(non_snake_case)]
+
+use libc::{c_char, c_int, c_uint, c_void, uint64_t};
+use video::{SDL_bool, SDL_Window};
+
+/// A dispatchable Vulkan handle (a pointer).
+pub type VkInstance = usize;
+/// A non-dispatchable Vulkan handle (always 64 bits).
+pub type VkSurfaceKHR = uint64_t;
+
+//SDL_vulkan.h
+extern "C" {
+    pub fn SDL_Vulkan_LoadLibrary(path: *const c_char) -> c_int;
+    pub fn SDL_Vulkan_GetVkGetInstanceProcAddr() -> *mut c_void;
+    pub fn SDL_Vulkan_UnloadLibrary();
+    pub fn SDL_Vulkan_GetInstanceExtensions(window: *mut SDL_Window, pCount: *mut c_uint, pNames: *mut *const c_char) -> SDL_bool;
+    pub fn SDL_Vulkan_CreateSurface(window: *mut SDL_Window, instance: VkInstance, surface: *mut VkSurfaceKHR) -> SDL_bool;
+    pub fn SDL_Vulkan_GetDrawableSize(window: *mut SDL_Window, w: *mut c_int, h: *mut c_int);
+}
@@ -0,0 +1,163 @@
+//! Test signal generators.
+//!
+//! Each generator is an endless iterator of `f32` samples between
+//! `-amplitude` and `amplitude`, for a single channel. Wrap one in an
+//! `IterSource` to play it, or collect some of it to queue or write to a file.
+//!
+//! # Example
+//! ```no_run
+//! use sdl2::audio::{AudioDevice, AudioSpecDesired, IterSource};
+//! use sdl2::audio::generators::Sine;
+//!
+//! let _sdl_context = sdl2::init().audio().unwrap();
+//!
+//! let desired_spec = AudioSpecDesired { freq: Some(44100), channels: None, samples: None };
+//! let device = AudioDevice::open_playback(None, desired_spec, |spec| {
+//!     IterSource::mono(Sine::new(440.0, spec.freq, 0.25), spec.channels)
+//! }).unwrap();
+//!
+//! device.resume();
+//! sdl2::timer::delay(1000);
+//! ```
+extern crate rand;
+
+use std::f32::consts::PI;
+use std::iter::{repeat, Repeat};
+
+use self::rand::{Rng, XorShiftRng};
+
+/// Keeps track of the position within a period, from 0 to 1.
+#[derive(Copy, Clone, Debug)]
+struct Phase {
+    phase: f32,
+    step: f32
+}
+
+impl Phase {
+    fn new(freq: f32, sample_rate: i32) -> Phase {
+        assert!(sample_rate > 0, "Sample rate must be positive");
+        Phase { phase: 0.0, step: freq / sample_rate as f32 }
+    }
+
+    fn next(&mut self) -> f32 {
+        let phase = self.phase;
+        self.phase = (self.phase + self.step) % 1.0;
+        phase
+    }
+}
+
+/// A sine wave.
+#[derive(Copy, Clone, Debug)]
+pub struct Sine {
+    phase: Phase,
+    amplitude: f32
+}
+
+impl Sine {
+    /// Generates a sine wave of `freq` Hz at `sample_rate` samples per second.
+    pub fn new(freq: f32, sample_rate: i32, amplitude: f32) -> Sine {
+        Sine { phase: Phase::new(freq, sample_rate), amplitude: amplitude }
+    }
+}
+
+impl Iterator for Sine {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some((self.phase.next() * 2.0 * PI).sin() * self.amplitude)
+    }
+}
+
+/// A square wave with a 50% duty cycle.
+#[derive(Copy, Clone, Debug)]
+pub struct Square {
+    phase: Phase,
+    amplitude: f32
+}
+
+impl Square {
+    /// Generates a square wave of `freq` Hz at `sample_rate` samples per second.
+    pub fn new(freq: f32, sample_rate: i32, amplitude: f32) -> Square {
+        Square { phase: Phase::new(freq, sample_rate), amplitude: amplitude }
+    }
+}
+
+impl Iterator for Square {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.phase.next() < 0.5 {
+            Some(self.amplitude)
+        } else {
+            Some(-self.amplitude)
+        }
+    }
+}
+
+/// A sawtooth wave, rising from `-amplitude` to `amplitude` every period.
+#[derive(Copy, Clone, Debug)]
+pub struct Saw {
+    phase: Phase,
+    amplitude: f32
+}
+
+impl Saw {
+    /// Generates a sawtooth wave of `freq` Hz at `sample_rate` samples per second.
+    pub fn new(freq: f32, sample_rate: i32, amplitude: f32) -> Saw {
+        Saw { phase: Phase::new(freq, sample_rate), amplitude: amplitude }
+    }
+}
+
+impl Iterator for Saw {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some((self.phase.next() * 2.0 - 1.0) * self.amplitude)
+    }
+}
+
+/// White noise.
+#[derive(Clone)]
+pub struct Noise {
+    rng: XorShiftRng,
+    amplitude: f32
+}
+
+impl Noise {
+    pub fn new(amplitude: f32) -> Noise {
+        Noise { rng: rand::weak_rng(), amplitude: amplitude }
+    }
+}
+
+impl Iterator for Noise {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some((self.rng.next_f32() * 2.0 - 1.0) * self.amplitude)
+    }
+}
+
+/// Silence. Handy as a placeholder source, or to measure latency against.
+pub fn silence() -> Repeat<f32> {
+    repeat(0.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Noise, Saw, Sine, Square};
+
+    #[test]
+    fn test_generators() {
+        let sine: Vec<f32> = Sine::new(1.0, 4, 1.0).take(4).collect();
+        assert!(sine[0].abs() < 1e-6 && (sine[1] - 1.0).abs() < 1e-6);
+        assert!(sine[2].abs() < 1e-6 && (sine[3] + 1.0).abs() < 1e-6);
+
+        let square: Vec<f32> = Square::new(1.0, 4, 0.5).take(4).collect();
+        assert_eq!(square, vec![0.5, 0.5, -0.5, -0.5]);
+
+        let saw: Vec<f32> = Saw::new(1.0, 4, 1.0).take(5).collect();
+        assert_eq!(saw, vec![-1.0, -0.5, 0.0, 0.5, -1.0]);
+
+        assert!(Noise::new(0.5).take(100).all(|x| x >= -0.5 && x <= 0.5));
+    }
+}
@@ -0,0 +1,314 @@
+//! A small software mixer.
+//!
+//! `Mixer` is an `AudioCallback` that plays any number of sounds at once,
+//! each with its own volume, panning and looping. It's a lightweight
+//! alternative to SDL2_mixer for simple games.
+//!
+//! Sounds are started through `AudioDevice::lock()`, which returns a
+//! `Handle` that controls the playing sound without locking the device again.
+//!
+//! # Example
+//! ```no_run
+//! use sdl2::audio::{AudioDevice, AudioSpecDesired};
+//! use sdl2::audio::mixer::{Mixer, Sound};
+//!
+//! let _sdl_context = sdl2::init().audio().unwrap();
+//!
+//! let desired_spec = AudioSpecDesired { freq: Some(44100), channels: Some(2), samples: None };
+//! let mut device = AudioDevice::open_playback(None, desired_spec, |spec| {
+//!     Mixer::new(spec.channels)
+//! }).unwrap();
+//! device.resume();
+//!
+//! let freq = device.spec().freq;
+//! let wav = sdl2::audio::load_wav("jump.wav").unwrap();
+//! let jump = Sound::from_wav(&wav, freq).unwrap();
+//!
+//! let handle = device.lock().play(&jump);
+//! handle.set_pan(-0.5);
+//! ```
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use SdlResult;
+
+use super::{AudioCVT, AudioCallback, AudioFormat, AudioSpecWAV};
+
+/// A mono sound clip that can be played any number of times at once.
+///
+/// The samples are shared, so cloning a `Sound` is cheap.
+#[derive(Clone, Debug)]
+pub struct Sound {
+    samples: Arc<Vec<f32>>
+}
+
+impl Sound {
+    /// Creates a sound from mono samples at the device's frequency.
+    pub fn new(samples: Vec<f32>) -> Sound {
+        Sound { samples: Arc::new(samples) }
+    }
+
+    /// Converts a loaded WAVE to mono samples at `freq`, the frequency of
+    /// the device it'll be played on.
+    pub fn from_wav(wav: &AudioSpecWAV, freq: i32) -> SdlResult<Sound> {
+        let cvt = try!(AudioCVT::new(wav.format, wav.channels, wav.freq,
+                                     AudioFormat::f32_sys(), 1, freq));
+        let samples = try!(wav.convert_samples_with(&cvt));
+        Ok(Sound::new(samples))
+    }
+
+    pub fn samples(&self) -> &[f32] { &self.samples }
+
+    /// Gets the number of samples in the sound.
+    pub fn len(&self) -> usize { self.samples.len() }
+
+    pub fn is_empty(&self) -> bool { self.samples.is_empty() }
+}
+
+/// The settings of a playing sound, shared between the mixer and a `Handle`.
+struct Control {
+    volume: AtomicU32,
+    pan: AtomicU32,
+    looping: AtomicBool,
+    stopped: AtomicBool,
+    finished: AtomicBool
+}
+
+impl Control {
+    fn new() -> Control {
+        Control {
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            pan: AtomicU32::new(0.0f32.to_bits()),
+            looping: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+            finished: AtomicBool::new(false)
+        }
+    }
+}
+
+/// Controls a sound that was started with `Mixer::play()` or
+/// `Mixer::play_stream()`.
+///
+/// Changes take effect the next time the mixer runs. Dropping the handle
+/// doesn't stop the sound.
+#[derive(Clone)]
+pub struct Handle {
+    control: Arc<Control>
+}
+
+impl Handle {
+    /// Sets the volume of the sound, where 1.0 plays it unchanged.
+    pub fn set_volume(&self, volume: f32) {
+        self.control.volume.store(volume.max(0.0).to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.control.volume.load(Ordering::Relaxed))
+    }
+
+    /// Sets the stereo position of the sound, from -1.0 (left only) through
+    /// 0.0 (centered) to 1.0 (right only).
+    ///
+    /// Has no effect on a mono device.
+    pub fn set_pan(&self, pan: f32) {
+        self.control.pan.store(pan.clamp(-1.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn pan(&self) -> f32 {
+        f32::from_bits(self.control.pan.load(Ordering::Relaxed))
+    }
+
+    /// Sets whether the sound starts over when it reaches the end.
+    ///
+    /// Streams started with `play_stream()` can't loop.
+    pub fn set_looping(&self, looping: bool) {
+        self.control.looping.store(looping, Ordering::Relaxed);
+    }
+
+    pub fn is_looping(&self) -> bool {
+        self.control.looping.load(Ordering::Relaxed)
+    }
+
+    /// Stops the sound. It can't be restarted; play it again instead.
+    pub fn stop(&self) {
+        self.control.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks if the sound is still playing, i.e. it hasn't reached its end
+    /// or been stopped.
+    pub fn is_playing(&self) -> bool {
+        !self.control.finished.load(Ordering::Relaxed) && !self.control.stopped.load(Ordering::Relaxed)
+    }
+}
+
+enum Source {
+    Sound { sound: Sound, pos: usize },
+    Stream(Box<dyn Iterator<Item = f32> + Send>)
+}
+
+impl Source {
+    fn next(&mut self, looping: bool) -> Option<f32> {
+        match *self {
+            Source::Sound { ref sound, ref mut pos } => {
+                if *pos >= sound.len() && looping {
+                    *pos = 0;
+                }
+                let sample = sound.samples.get(*pos).cloned();
+                *pos += 1;
+                sample
+            },
+            Source::Stream(ref mut iter) => iter.next()
+        }
+    }
+}
+
+struct Voice {
+    source: Source,
+    control: Arc<Control>
+}
+
+/// Mixes playing sounds into the output of an audio device.
+///
+/// The mixer works with `f32` samples. With two or more channels, sounds
+/// are panned between the first two; any other channels are left silent.
+pub struct Mixer {
+    channels: usize,
+    voices: Vec<Voice>,
+    volume: f32
+}
+
+impl Mixer {
+    /// Creates a mixer for a device with `channels` channels.
+    ///
+    /// # Panics
+    /// Panics if `channels` is zero.
+    pub fn new(channels: u8) -> Mixer {
+        assert!(channels > 0, "Channel count must be non-zero");
+        Mixer {
+            channels: channels as usize,
+            voices: Vec::new(),
+            volume: 1.0
+        }
+    }
+
+    /// Starts playing `sound` from the beginning.
+    pub fn play(&mut self, sound: &Sound) -> Handle {
+        self.add_voice(Source::Sound { sound: sound.clone(), pos: 0 })
+    }
+
+    /// Starts playing mono samples from `iter` until it runs out.
+    pub fn play_stream<I>(&mut self, iter: I) -> Handle
+    where I: Iterator<Item = f32> + Send + 'static
+    {
+        self.add_voice(Source::Stream(Box::new(iter)))
+    }
+
+    fn add_voice(&mut self, source: Source) -> Handle {
+        let control = Arc::new(Control::new());
+        self.voices.push(Voice { source: source, control: control.clone() });
+        Handle { control: control }
+    }
+
+    /// Sets the volume of the whole mix, where 1.0 leaves it unchanged.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.max(0.0);
+    }
+
+    pub fn volume(&self) -> f32 { self.volume }
+
+    /// Gets the number of sounds that are playing.
+    pub fn num_playing(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Stops all sounds.
+    pub fn stop_all(&mut self) {
+        for voice in self.voices.drain(..) {
+            voice.control.finished.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+impl AudioCallback for Mixer {
+    type Channel = f32;
+
+    fn callback(&mut self, out: &mut [f32]) {
+        for x in out.iter_mut() {
+            *x = 0.0;
+        }
+
+        let channels = self.channels;
+        for voice in self.voices.iter_mut() {
+            let control = &voice.control;
+            if control.stopped.load(Ordering::Relaxed) {
+                control.finished.store(true, Ordering::Relaxed);
+                continue;
+            }
+
+            let volume = f32::from_bits(control.volume.load(Ordering::Relaxed));
+            let pan = f32::from_bits(control.pan.load(Ordering::Relaxed));
+            let looping = control.looping.load(Ordering::Relaxed);
+            let (left, right) = if channels == 1 {
+                (volume, volume)
+            } else {
+                (volume * (1.0 - pan).min(1.0), volume * (1.0 + pan).min(1.0))
+            };
+
+            for frame in out.chunks_mut(channels) {
+                let sample = match voice.source.next(looping) {
+                    Some(sample) => sample,
+                    None => {
+                        control.finished.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                };
+
+                frame[0] += sample * left;
+                if channels > 1 {
+                    frame[1] += sample * right;
+                }
+            }
+        }
+
+        self.voices.retain(|voice| !voice.control.finished.load(Ordering::Relaxed));
+
+        let volume = self.volume;
+        for x in out.iter_mut() {
+            *x = (*x * volume).clamp(-1.0, 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Mixer, Sound};
+    use audio::AudioCallback;
+
+    #[test]
+    fn test_mixer() {
+        let mut mixer = Mixer::new(2);
+        let sound = Sound::new(vec![0.5, 0.25]);
+
+        let first = mixer.play(&sound);
+        let second = mixer.play(&sound);
+        second.set_pan(1.0);
+
+        let mut out = [0.0; 6];
+        mixer.callback(&mut out);
+        assert_eq!(out, [0.5, 1.0, 0.25, 0.5, 0.0, 0.0]);
+        assert!(!first.is_playing());
+        assert_eq!(mixer.num_playing(), 0);
+
+        let looped = mixer.play(&sound);
+        looped.set_looping(true);
+        looped.set_volume(2.0);
+        mixer.callback(&mut out);
+        assert_eq!(out, [1.0, 1.0, 0.5, 0.5, 1.0, 1.0]);
+        assert!(looped.is_playing());
+
+        looped.stop();
+        mixer.callback(&mut out);
+        assert_eq!(out, [0.0; 6]);
+        assert_eq!(mixer.num_playing(), 0);
+    }
+}
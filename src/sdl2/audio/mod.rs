@@ -0,0 +1,1461 @@
+//! Audio Functions
+//!
+//! # Example
+//! ```no_run
+//! use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+//!
+//! struct SquareWave {
+//!     phase_inc: f32,
+//!     phase: f32,
+//!     volume: f32
+//! }
+//!
+//! impl AudioCallback for SquareWave {
+//!     type Channel = f32;
+//!
+//!     fn callback(&mut self, out: &mut [f32]) {
+//!         // Generate a square wave
+//!         for x in out.iter_mut() {
+//!             *x = match self.phase {
+//!                 0.0...0.5 => self.volume,
+//!                 _ => -self.volume
+//!             };
+//!             self.phase = (self.phase + self.phase_inc) % 1.0;
+//!         }
+//!     }
+//! }
+//!
+//! let _sdl_context = sdl2::init().audio().unwrap();
+//!
+//! let desired_spec = AudioSpecDesired {
+//!     freq: Some(44100),
+//!     channels: Some(1),  // mono
+//!     samples: None       // default sample size
+//! };
+//!
+//! let device = AudioDevice::open_playback(None, desired_spec, |spec| {
+//!     // initialize the audio callback
+//!     SquareWave {
+//!         phase_inc: 440.0 / spec.freq as f32,
+//!         phase: 0.0,
+//!         volume: 0.25
+//!     }
+//! }).unwrap();
+//!
+//! // Start playback
+//! device.resume();
+//!
+//! // Play for 2 seconds
+//! sdl2::timer::delay(2000);
+//! ```
+use std::ffi::{CStr, CString};
+use num::FromPrimitive;
+use libc::{c_int, c_void, uint8_t};
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::cell::Cell;
+use std::marker::PhantomData;
+#[cfg(feature = "sdl_2_0_4")]
+use std::time::Duration;
+
+use event::Event;
+use get_error;
+use rwops::RWops;
+use SdlResult;
+use util::CStringExt;
+
+use sys::audio as ll;
+
+pub mod generators;
+pub mod mixer;
+
+mod ring;
+mod source;
+#[cfg(feature = "sdl_2_0_7")]
+mod stream;
+mod wav;
+
+pub use self::ring::{RingBuffer, RingBufferProducer};
+pub use self::source::IterSource;
+#[cfg(feature = "sdl_2_0_7")]
+pub use self::stream::AudioStream;
+pub use self::wav::WavWriter;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum AudioFormat {
+    /// Unsigned 8-bit samples
+    U8 = ll::AUDIO_U8 as isize,
+    /// Signed 8-bit samples
+    S8 = ll::AUDIO_S8 as isize,
+    /// Unsigned 16-bit samples, little-endian
+    U16LSB = ll::AUDIO_U16LSB as isize,
+    /// Unsigned 16-bit samples, big-endian
+    U16MSB = ll::AUDIO_U16MSB as isize,
+    /// Signed 16-bit samples, little-endian
+    S16LSB = ll::AUDIO_S16LSB as isize,
+    /// Signed 16-bit samples, big-endian
+    S16MSB = ll::AUDIO_S16MSB as isize,
+    /// Signed 32-bit samples, little-endian
+    S32LSB = ll::AUDIO_S32LSB as isize,
+    /// Signed 32-bit samples, big-endian
+    S32MSB = ll::AUDIO_S32MSB as isize,
+    /// 32-bit floating point samples, little-endian
+    F32LSB = ll::AUDIO_F32LSB as isize,
+    /// 32-bit floating point samples, big-endian
+    F32MSB = ll::AUDIO_F32MSB as isize
+}
+
+impl AudioFormat {
+    fn from_ll(raw: ll::SDL_AudioFormat) -> Option<AudioFormat> {
+        use self::AudioFormat::*;
+        match raw {
+            ll::AUDIO_U8 => Some(U8),
+            ll::AUDIO_S8 => Some(S8),
+            ll::AUDIO_U16LSB => Some(U16LSB),
+            ll::AUDIO_U16MSB => Some(U16MSB),
+            ll::AUDIO_S16LSB => Some(S16LSB),
+            ll::AUDIO_S16MSB => Some(S16MSB),
+            ll::AUDIO_S32LSB => Some(S32LSB),
+            ll::AUDIO_S32MSB => Some(S32MSB),
+            ll::AUDIO_F32LSB => Some(F32LSB),
+            ll::AUDIO_F32MSB => Some(F32MSB),
+            _ => None
+        }
+    }
+
+    fn to_ll(self) -> ll::SDL_AudioFormat {
+        self as ll::SDL_AudioFormat
+    }
+}
+
+#[cfg(target_endian = "little")]
+impl AudioFormat {
+    /// Unsigned 16-bit samples, native endian
+    #[inline] pub fn u16_sys() -> AudioFormat { AudioFormat::U16LSB }
+    /// Signed 16-bit samples, native endian
+    #[inline] pub fn s16_sys() -> AudioFormat { AudioFormat::S16LSB }
+    /// Signed 32-bit samples, native endian
+    #[inline] pub fn s32_sys() -> AudioFormat { AudioFormat::S32LSB }
+    /// 32-bit floating point samples, native endian
+    #[inline] pub fn f32_sys() -> AudioFormat { AudioFormat::F32LSB }
+}
+
+#[cfg(target_endian = "big")]
+impl AudioFormat {
+    /// Unsigned 16-bit samples, native endian
+    #[inline] pub fn u16_sys() -> AudioFormat { AudioFormat::U16MSB }
+    /// Signed 16-bit samples, native endian
+    #[inline] pub fn s16_sys() -> AudioFormat { AudioFormat::S16MSB }
+    /// Signed 32-bit samples, native endian
+    #[inline] pub fn s32_sys() -> AudioFormat { AudioFormat::S32MSB }
+    /// 32-bit floating point samples, native endian
+    #[inline] pub fn f32_sys() -> AudioFormat { AudioFormat::F32MSB }
+}
+
+/// The playback state of an audio device, as returned by `get_status()`.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AudioStatus {
+    /// The device was closed or lost, e.g. because it was unplugged.
+    Stopped = ll::SDL_AUDIO_STOPPED as isize,
+    Playing = ll::SDL_AUDIO_PLAYING as isize,
+    /// The device is open but paused. Devices start out paused.
+    Paused  = ll::SDL_AUDIO_PAUSED  as isize,
+}
+
+impl FromPrimitive for AudioStatus {
+    fn from_i64(n: i64) -> Option<AudioStatus> {
+        use self::AudioStatus::*;
+
+        Some( match n as ll::SDL_AudioStatus {
+            ll::SDL_AUDIO_STOPPED => Stopped,
+            ll::SDL_AUDIO_PLAYING => Playing,
+            ll::SDL_AUDIO_PAUSED  => Paused,
+            _                     => return None,
+        })
+    }
+
+    fn from_u64(n: u64) -> Option<AudioStatus> { FromPrimitive::from_i64(n as i64) }
+}
+
+pub fn get_num_audio_drivers() -> i32 {
+    unsafe { ll::SDL_GetNumAudioDrivers() as i32 }
+}
+
+pub fn get_audio_driver(index: i32) -> String {
+    unsafe {
+        let driver = ll::SDL_GetAudioDriver(index as c_int);
+        String::from_utf8_lossy(CStr::from_ptr(driver).to_bytes()).to_string()
+    }
+}
+
+/// Gets the number of playback (`iscapture == 0`) or capture devices.
+///
+/// Returns -1 if an explicit list of devices can't be determined; opening
+/// the default device still works in that case.
+pub fn get_num_audio_devices(iscapture: i32) -> i32 {
+    unsafe { ll::SDL_GetNumAudioDevices(iscapture as c_int) as i32 }
+}
+
+/// Gets the name of a playback (`iscapture == 0`) or capture device.
+///
+/// Returns an empty string if the index is out of range.
+pub fn get_audio_device_name(index: i32, iscapture: i32) -> String {
+    unsafe {
+        let dev_name = ll::SDL_GetAudioDeviceName(index as c_int, iscapture as c_int);
+        if dev_name.is_null() {
+            String::new()
+        } else {
+            String::from_utf8_lossy(CStr::from_ptr(dev_name).to_bytes()).to_string()
+        }
+    }
+}
+
+/// Lists the names of the playback devices.
+///
+/// Any of these names can be passed to `AudioDevice::open_playback()` or
+/// `AudioQueue::open_queue()` instead of `None` (the default device).
+/// The list is empty if the audio driver can't enumerate its devices.
+///
+/// # Example
+/// ```no_run
+/// let _sdl_context = sdl2::init().audio().unwrap();
+///
+/// for name in sdl2::audio::list_playback_devices().unwrap() {
+///     println!("{}", name);
+/// }
+/// ```
+pub fn list_playback_devices() -> SdlResult<Vec<String>> {
+    list_audio_devices(0)
+}
+
+/// Lists the names of the capture devices, e.g. microphones.
+///
+/// The list is empty if the audio driver can't enumerate its devices.
+pub fn list_capture_devices() -> SdlResult<Vec<String>> {
+    list_audio_devices(1)
+}
+
+fn list_audio_devices(iscapture: c_int) -> SdlResult<Vec<String>> {
+    let count = get_num_audio_devices(iscapture);
+    let mut names = Vec::with_capacity(::std::cmp::max(count, 0) as usize);
+
+    for index in 0..count {
+        let dev_name = unsafe { ll::SDL_GetAudioDeviceName(index as c_int, iscapture) };
+        if dev_name.is_null() {
+            return Err(get_error());
+        }
+        names.push(unsafe { String::from_utf8_lossy(CStr::from_ptr(dev_name).to_bytes()).to_string() });
+    }
+
+    Ok(names)
+}
+
+pub fn audio_init(name: &str) -> SdlResult<()> {
+    let name = try!(CString::new(name).unwrap_or_sdlresult());
+    let ret = unsafe { ll::SDL_AudioInit(name.as_ptr()) };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
+
+pub fn audio_quit() {
+    unsafe { ll::SDL_AudioQuit() }
+}
+
+pub fn get_current_audio_driver() -> String {
+    unsafe {
+        let driver = ll::SDL_GetCurrentAudioDriver();
+        String::from_utf8_lossy(CStr::from_ptr(driver).to_bytes()).to_string()
+    }
+}
+
+/// Loads a WAVE from the file path.
+///
+/// Shorthand for `AudioSpecWAV::load_wav`.
+pub fn load_wav<P: AsRef<Path>>(path: P) -> SdlResult<AudioSpecWAV> {
+    AudioSpecWAV::load_wav(path)
+}
+
+/// Loads a WAVE from the data source.
+///
+/// Shorthand for `AudioSpecWAV::load_wav_rw`.
+pub fn load_wav_rw(src: &mut RWops) -> SdlResult<AudioSpecWAV> {
+    AudioSpecWAV::load_wav_rw(src)
+}
+
+/// The spec and sample data of a loaded WAVE file.
+///
+/// The sample buffer is allocated by SDL and freed with `SDL_FreeWAV` when
+/// this is dropped.
+///
+/// # Example
+/// ```no_run
+/// let wav = sdl2::audio::load_wav("sound.wav").unwrap();
+///
+/// // Access the samples in the file's own format...
+/// if let Ok(samples) = wav.get_samples::<i16>() {
+///     println!("{} samples", samples.len());
+/// }
+///
+/// // ...or convert them to the format of your choice.
+/// let samples: Vec<f32> = wav.convert_samples().unwrap();
+/// ```
+pub struct AudioSpecWAV {
+    pub freq: i32,
+    pub format: AudioFormat,
+    pub channels: u8,
+    audio_buf: *mut u8,
+    audio_len: u32
+}
+
+impl AudioSpecWAV {
+    /// Loads a WAVE from the file path.
+    pub fn load_wav<P: AsRef<Path>>(path: P) -> SdlResult<AudioSpecWAV> {
+        let mut file = try!(RWops::from_file(path, "rb"));
+        AudioSpecWAV::load_wav_rw(&mut file)
+    }
+
+    /// Loads a WAVE from the data source.
+    pub fn load_wav_rw(src: &mut RWops) -> SdlResult<AudioSpecWAV> {
+        use std::mem::zeroed;
+        use std::ptr::null_mut;
+
+        let mut desired = unsafe { zeroed::<ll::SDL_AudioSpec>() };
+        let mut audio_buf: *mut u8 = null_mut();
+        let mut audio_len: u32 = 0;
+        unsafe {
+            let ret = ll::SDL_LoadWAV_RW(src.raw(), 0, &mut desired, &mut audio_buf, &mut audio_len);
+            if ret.is_null() {
+                Err(get_error())
+            } else {
+                match AudioFormat::from_ll(desired.format) {
+                    Some(format) => Ok(AudioSpecWAV {
+                        freq: desired.freq,
+                        format: format,
+                        channels: desired.channels,
+                        audio_buf: audio_buf,
+                        audio_len: audio_len
+                    }),
+                    None => {
+                        ll::SDL_FreeWAV(audio_buf);
+                        Err(format!("Unsupported WAVE sample format: {:#x}", desired.format))
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn get_buffer(&self) -> &[u8] {
+        use std::slice::from_raw_parts;
+        unsafe {
+            let ptr = self.audio_buf as *const u8;
+            let len = self.audio_len as usize;
+            from_raw_parts(ptr, len)
+        }
+    }
+
+    /// Gets the sample data as a slice of `T`.
+    ///
+    /// Fails if `T` doesn't match the format of the file; use
+    /// `convert_samples()` to get the samples in a different format.
+    pub fn get_samples<T: AudioFormatNum>(&self) -> SdlResult<&[T]> {
+        use std::mem::{align_of, size_of};
+        use std::slice::from_raw_parts;
+
+        if T::get_audio_format() != self.format {
+            return Err(format!("WAVE samples are in the {:?} format", self.format));
+        }
+        if self.audio_len == 0 {
+            return Ok(&[]);
+        }
+        if (self.audio_buf as *const T).align_offset(align_of::<T>()) != 0 {
+            return Err("WAVE buffer is not aligned for the sample type".to_owned());
+        }
+
+        unsafe {
+            let ptr = self.audio_buf as *const T;
+            let len = self.audio_len as usize / size_of::<T>();
+            Ok(from_raw_parts(ptr, len))
+        }
+    }
+
+    /// Converts the sample data to the format of `T`, keeping the channel
+    /// count and frequency of the file.
+    pub fn convert_samples<T: AudioFormatNum>(&self) -> SdlResult<Vec<T>> {
+        let cvt = try!(AudioCVT::new(self.format, self.channels, self.freq,
+                                     T::get_audio_format(), self.channels, self.freq));
+        self.convert_samples_with(&cvt)
+    }
+
+    /// Converts the sample data with `cvt`, which must take the file's
+    /// format as its source.
+    ///
+    /// Use this to change the channel count or frequency as well.
+    pub fn convert_samples_with<T: AudioFormatNum>(&self, cvt: &AudioCVT) -> SdlResult<Vec<T>> {
+        use std::mem::size_of;
+        use std::ptr;
+
+        if cvt.get_src_format() != self.format {
+            return Err(format!("WAVE samples are in the {:?} format", self.format));
+        }
+        if T::get_audio_format() != cvt.get_dst_format() {
+            return Err(format!("Destination samples are not in the {:?} format", cvt.get_dst_format()));
+        }
+
+        let bytes = cvt.convert(self.get_buffer().to_vec());
+        let len = bytes.len() / size_of::<T>();
+        let mut dst: Vec<T> = Vec::with_capacity(len);
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), dst.as_mut_ptr() as *mut u8, len * size_of::<T>());
+            dst.set_len(len);
+        }
+        Ok(dst)
+    }
+}
+
+impl Drop for AudioSpecWAV {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_FreeWAV(self.audio_buf); }
+    }
+}
+
+/// Generates audio for a playback device.
+///
+/// `Channel` is the sample type handed to `callback()`. The device is opened
+/// with the matching `AudioFormat`, so the buffer is always in that format;
+/// SDL converts to the hardware format behind the scenes if it has to.
+/// Interleaved channels are laid out one sample after another (e.g. `L R L R`).
+pub trait AudioCallback: Send
+where Self::Channel: AudioFormatNum + 'static
+{
+    type Channel;
+
+    fn callback(&mut self, &mut [Self::Channel]);
+}
+
+mod private {
+    /// Prevents `AudioFormatNum` from being implemented outside of this crate.
+    pub trait Sealed {}
+
+    impl Sealed for i8 {}
+    impl Sealed for u8 {}
+    impl Sealed for i16 {}
+    impl Sealed for u16 {}
+    impl Sealed for i32 {}
+    impl Sealed for f32 {}
+}
+
+/// A phantom type for retreiving the SDL_AudioFormat of a given generic type.
+/// All format types are returned as native-endian.
+///
+/// This trait is sealed; it's implemented for `u8`, `i8`, `u16`, `i16`,
+/// `i32` and `f32` only.
+pub trait AudioFormatNum: private::Sealed + Copy {
+    fn get_audio_format() -> AudioFormat;
+    fn zero() -> Self;
+    /// The sample value that represents silence.
+    ///
+    /// This is zero for signed and floating point formats, and the midpoint
+    /// for unsigned formats.
+    fn silence() -> Self;
+}
+
+/// AUDIO_S8
+impl AudioFormatNum for i8 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::S8 }
+    fn zero() -> i8 { 0 }
+    fn silence() -> i8 { 0 }
+}
+/// AUDIO_U8
+impl AudioFormatNum for u8 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::U8 }
+    fn zero() -> u8 { 0 }
+    fn silence() -> u8 { 0x80 }
+}
+/// AUDIO_S16
+impl AudioFormatNum for i16 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::s16_sys() }
+    fn zero() -> i16 { 0 }
+    fn silence() -> i16 { 0 }
+}
+/// AUDIO_U16
+impl AudioFormatNum for u16 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::u16_sys() }
+    fn zero() -> u16 { 0 }
+    fn silence() -> u16 { 0x8000 }
+}
+/// AUDIO_S32
+impl AudioFormatNum for i32 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::s32_sys() }
+    fn zero() -> i32 { 0 }
+    fn silence() -> i32 { 0 }
+}
+/// AUDIO_F32
+impl AudioFormatNum for f32 {
+    fn get_audio_format() -> AudioFormat { AudioFormat::f32_sys() }
+    fn zero() -> f32 { 0.0 }
+    fn silence() -> f32 { 0.0 }
+}
+
+extern "C" fn audio_callback_marshall<CB: AudioCallback>
+(userdata: *mut c_void, stream: *mut uint8_t, len: c_int) {
+    use std::slice::from_raw_parts_mut;
+    use std::mem::{size_of, transmute};
+    unsafe {
+        let cb_userdata: &mut CB = transmute(userdata);
+        let buf: &mut [CB::Channel] = from_raw_parts_mut(
+            stream as *mut CB::Channel,
+            len as usize / size_of::<CB::Channel>()
+        );
+
+        cb_userdata.callback(buf);
+    }
+}
+
+/// The audio spec to ask for when opening a device.
+///
+/// Fields left as `None` are chosen by SDL. The sample format is always
+/// given by the sample type of the callback or queue.
+///
+/// ```
+/// use sdl2::audio::AudioSpecDesired;
+///
+/// let desired_spec = AudioSpecDesired { freq: Some(48000), ..Default::default() };
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AudioSpecDesired {
+    /// DSP frequency (samples per second). Set to None for the device's fallback frequency.
+    pub freq: Option<i32>,
+    /// Number of separate audio channels. Set to None for the device's fallback number of channels.
+    pub channels: Option<u8>,
+    /// Audio buffer size in samples (power of 2). Set to None for the device's fallback sample size.
+    pub samples: Option<u16>,
+}
+
+impl AudioSpecDesired {
+    #[cfg(feature = "sdl_2_0_4")]
+    fn convert_queue_to_ll<Channel: AudioFormatNum>(freq: Option<i32>, channels: Option<u8>, samples: Option<u16>) -> ll::SDL_AudioSpec {
+        if let Some(freq) = freq { assert!(freq > 0); }
+        if let Some(channels) = channels { assert!(channels > 0); }
+        if let Some(samples) = samples { assert!(samples > 0); }
+
+        // A value of 0 means "fallback" or "default".
+
+        ll::SDL_AudioSpec {
+            freq: freq.unwrap_or(0),
+            format: <Channel as AudioFormatNum>::get_audio_format().to_ll(),
+            channels: channels.unwrap_or(0),
+            silence: 0,
+            samples: samples.unwrap_or(0),
+            padding: 0,
+            size: 0,
+            // No callback: audio is pushed with `SDL_QueueAudio` instead.
+            callback: None,
+            userdata: ::std::ptr::null_mut()
+        }
+    }
+
+    fn convert_to_ll<CB: AudioCallback>(freq: Option<i32>, channels: Option<u8>, samples: Option<u16>, userdata: *mut CB) -> ll::SDL_AudioSpec {
+        use std::mem::transmute;
+
+        if let Some(freq) = freq { assert!(freq > 0); }
+        if let Some(channels) = channels { assert!(channels > 0); }
+        if let Some(samples) = samples { assert!(samples > 0); }
+
+        // A value of 0 means "fallback" or "default".
+
+        unsafe {
+            ll::SDL_AudioSpec {
+                freq: freq.unwrap_or(0),
+                format: <CB::Channel as AudioFormatNum>::get_audio_format().to_ll(),
+                channels: channels.unwrap_or(0),
+                silence: 0,
+                samples: samples.unwrap_or(0),
+                padding: 0,
+                size: 0,
+                callback: Some(audio_callback_marshall::<CB>
+                    as extern "C" fn
+                        (arg1: *mut c_void,
+                         arg2: *mut uint8_t,
+                         arg3: c_int)),
+                userdata: transmute(userdata)
+            }
+        }
+    }
+}
+
+bitflags! {
+    /// The parts of the desired spec that SDL may change when opening a
+    /// device, as passed to `AudioDevice::open_playback_with_changes()`.
+    ///
+    /// Without these, SDL converts to and from the hardware's spec behind
+    /// the scenes, which costs a little CPU time and latency. The format
+    /// can't be changed, since it's fixed by the sample type.
+    flags AllowedChanges: c_int {
+        const ALLOW_FREQUENCY_CHANGE = ll::SDL_AUDIO_ALLOW_FREQUENCY_CHANGE,
+        const ALLOW_CHANNELS_CHANGE = ll::SDL_AUDIO_ALLOW_CHANNELS_CHANGE
+    }
+}
+
+/// The parts of an obtained spec that differ from the desired spec, as
+/// returned by `AudioSpec::get_changes()`.
+///
+/// Fields that were `None` in the desired spec never count as changed.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SpecChanges {
+    pub freq: bool,
+    pub channels: bool,
+    pub samples: bool
+}
+
+impl SpecChanges {
+    /// Checks if anything differs from the desired spec.
+    pub fn any(&self) -> bool {
+        self.freq || self.channels || self.samples
+    }
+}
+
+/// The audio spec of an opened device, i.e. what SDL actually granted.
+#[allow(missing_copy_implementations)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AudioSpec {
+    pub freq: i32,
+    pub format: AudioFormat,
+    pub channels: u8,
+    pub silence: u8,
+    pub samples: u16,
+    pub size: u32
+}
+
+impl AudioSpec {
+    /// Compares this spec to the one that was asked for.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::audio::{AudioDevice, AudioSpecDesired, RingBuffer, ALLOW_FREQUENCY_CHANGE};
+    ///
+    /// let _sdl_context = sdl2::init().audio().unwrap();
+    /// let desired_spec = AudioSpecDesired { freq: Some(44100), channels: Some(2), samples: None };
+    /// let (ring, _producer) = RingBuffer::<f32>::new(8192);
+    /// let device = AudioDevice::open_playback_with_changes(None, desired_spec, ALLOW_FREQUENCY_CHANGE, |_| ring).unwrap();
+    ///
+    /// if device.spec().get_changes(&desired_spec).freq {
+    ///     println!("Got {} Hz instead", device.spec().freq);
+    /// }
+    /// ```
+    pub fn get_changes(&self, desired: &AudioSpecDesired) -> SpecChanges {
+        SpecChanges {
+            freq: desired.freq.map_or(false, |freq| freq != self.freq),
+            channels: desired.channels.map_or(false, |channels| channels != self.channels),
+            samples: desired.samples.map_or(false, |samples| samples != self.samples)
+        }
+    }
+
+    fn convert_from_ll(spec: ll::SDL_AudioSpec) -> AudioSpec {
+        AudioSpec {
+            freq: spec.freq,
+            format: AudioFormat::from_ll(spec.format).unwrap(),
+            channels: spec.channels,
+            silence: spec.silence,
+            samples: spec.samples,
+            size: spec.size
+        }
+    }
+}
+
+enum AudioDeviceID {
+    PlaybackDevice(ll::SDL_AudioDeviceID)
+}
+
+impl AudioDeviceID {
+    fn id(&self) -> ll::SDL_AudioDeviceID {
+        match self {
+            &AudioDeviceID::PlaybackDevice(id)  => id
+        }
+    }
+}
+
+impl Drop for AudioDeviceID {
+    fn drop(&mut self) {
+        //! Shut down audio processing and close the audio device.
+        unsafe { ll::SDL_CloseAudioDevice(self.id()) }
+    }
+}
+
+/// Opens a playback device using `SDL_OpenAudioDevice`.
+fn open_playback_device(device: Option<&str>, desired: &ll::SDL_AudioSpec, allowed_changes: AllowedChanges) -> SdlResult<(AudioDeviceID, AudioSpec)> {
+    use std::mem;
+    use std::ptr::null;
+
+    let device = match device {
+        Some(device) => Some(try!(CString::new(device).unwrap_or_sdlresult())),
+        None => None
+    };
+    let device_ptr = device.as_ref().map_or(null(), |s| s.as_ptr());
+
+    unsafe {
+        let mut obtained = mem::zeroed::<ll::SDL_AudioSpec>();
+        let iscapture_flag = 0;
+        let device_id = ll::SDL_OpenAudioDevice(device_ptr, iscapture_flag, desired, &mut obtained, allowed_changes.bits());
+        match device_id {
+            0 => Err(get_error()),
+            id => Ok((AudioDeviceID::PlaybackDevice(id), AudioSpec::convert_from_ll(obtained)))
+        }
+    }
+}
+
+/// Checks if `event` reports that the playback device `id` was lost.
+fn is_removed_event(id: ll::SDL_AudioDeviceID, event: &Event) -> bool {
+    match *event {
+        Event::AudioDeviceRemoved { which, iscapture: false, .. } => which == id,
+        _ => false
+    }
+}
+
+/// Wraps SDL_AudioDeviceID for push-style playback without a callback.
+///
+/// Samples are appended to the device's queue with `queue()` and are
+/// played back in order as the device consumes them. Needs the
+/// `sdl_2_0_4` feature.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioQueue, AudioSpecDesired};
+///
+/// let _sdl_context = sdl2::init().audio().unwrap();
+///
+/// let desired_spec = AudioSpecDesired {
+///     freq: Some(44100),
+///     channels: Some(1),
+///     samples: None
+/// };
+///
+/// let queue = AudioQueue::<i16>::open_queue(None, &desired_spec).unwrap();
+///
+/// // One second of a 441 Hz square wave
+/// let samples: Vec<i16> = (0..44100).map(|i| {
+///     if (i / 50) % 2 == 0 { 3000 } else { -3000 }
+/// }).collect();
+///
+/// queue.queue(&samples).unwrap();
+/// queue.resume();
+///
+/// sdl2::timer::delay(1000);
+/// ```
+#[cfg(feature = "sdl_2_0_4")]
+pub struct AudioQueue<Channel: AudioFormatNum> {
+    device_id: AudioDeviceID,
+    spec: AudioSpec,
+    paused: Cell<bool>,
+    auto_reopen: bool,
+    _marker: PhantomData<Channel>
+}
+
+#[cfg(feature = "sdl_2_0_4")]
+impl<Channel: AudioFormatNum> AudioQueue<Channel> {
+    /// Opens a new audio device for playback using `SDL_OpenAudioDevice`,
+    /// without a callback.
+    ///
+    /// The sample format is determined by `Channel`.
+    pub fn open_queue(device: Option<&str>, spec: &AudioSpecDesired) -> SdlResult<AudioQueue<Channel>> {
+        AudioQueue::open_queue_with_changes(device, spec, AllowedChanges::empty())
+    }
+
+    /// Like `open_queue()`, but lets SDL pick a different frequency or
+    /// channel count if the hardware prefers it.
+    ///
+    /// The queued samples must match the spec returned by `spec()`.
+    pub fn open_queue_with_changes(device: Option<&str>, spec: &AudioSpecDesired, allowed_changes: AllowedChanges) -> SdlResult<AudioQueue<Channel>> {
+        let desired = AudioSpecDesired::convert_queue_to_ll::<Channel>(spec.freq, spec.channels, spec.samples);
+        let (device_id, spec) = try!(open_playback_device(device, &desired, allowed_changes));
+
+        Ok(AudioQueue {
+            device_id: device_id,
+            spec: spec,
+            paused: Cell::new(true),
+            auto_reopen: false,
+            _marker: PhantomData
+        })
+    }
+
+    /// Sets whether `handle_event()` moves playback to the default device
+    /// when this device is lost.
+    pub fn set_auto_reopen(&mut self, auto_reopen: bool) {
+        self.auto_reopen = auto_reopen;
+    }
+
+    /// Checks if `event` reports that this device was lost, e.g. because
+    /// it was unplugged.
+    pub fn is_removed_event(&self, event: &Event) -> bool {
+        is_removed_event(self.device_id.id(), event)
+    }
+
+    /// Reopens the device on the default device if auto-reopen is on and
+    /// `event` reports that this device was lost.
+    ///
+    /// Returns `true` if the device was reopened. See `reopen_default()`.
+    pub fn handle_event(&mut self, event: &Event) -> SdlResult<bool> {
+        if self.auto_reopen && self.is_removed_event(event) {
+            try!(self.reopen_default());
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Moves playback to the current default device, keeping the obtained
+    /// spec and the paused state.
+    ///
+    /// Samples that were still queued on the old device can't be recovered
+    /// from SDL and are lost; queue more afterwards.
+    pub fn reopen_default(&mut self) -> SdlResult<()> {
+        let desired = AudioSpecDesired::convert_queue_to_ll::<Channel>(
+            Some(self.spec.freq), Some(self.spec.channels), Some(self.spec.samples));
+        let (device_id, _) = try!(open_playback_device(None, &desired, AllowedChanges::empty()));
+
+        self.device_id = device_id;
+        if !self.paused.get() {
+            self.resume();
+        }
+        Ok(())
+    }
+
+    /// Gets the audio spec obtained when the device was opened.
+    pub fn spec(&self) -> &AudioSpec { &self.spec }
+
+    /// Gets the playback state of the device using `SDL_GetAudioDeviceStatus`.
+    pub fn get_status(&self) -> AudioStatus {
+        unsafe {
+            let status = ll::SDL_GetAudioDeviceStatus(self.device_id.id());
+            FromPrimitive::from_i32(status as i32).unwrap()
+        }
+    }
+
+    /// Pauses playback of the audio device.
+    pub fn pause(&self) {
+        self.paused.set(true);
+        unsafe { ll::SDL_PauseAudioDevice(self.device_id.id(), 1) }
+    }
+
+    /// Starts playback of the audio device.
+    pub fn resume(&self) {
+        self.paused.set(false);
+        unsafe { ll::SDL_PauseAudioDevice(self.device_id.id(), 0) }
+    }
+
+    /// Appends samples to the end of the device's queue using `SDL_QueueAudio`.
+    ///
+    /// The samples are copied, so `data` can be reused right away.
+    pub fn queue(&self, data: &[Channel]) -> SdlResult<()> {
+        let len = ::std::mem::size_of_val(data);
+        if len > u32::MAX as usize {
+            return Err("Too many samples to queue at once".to_owned());
+        }
+
+        let ret = unsafe {
+            ll::SDL_QueueAudio(self.device_id.id(), data.as_ptr() as *const c_void, len as u32)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Gets the number of bytes still waiting in the queue using
+    /// `SDL_GetQueuedAudioSize`.
+    pub fn size(&self) -> u32 {
+        unsafe { ll::SDL_GetQueuedAudioSize(self.device_id.id()) }
+    }
+
+    /// Drops all samples that have not been played yet, using
+    /// `SDL_ClearQueuedAudio`.
+    pub fn clear(&self) {
+        unsafe { ll::SDL_ClearQueuedAudio(self.device_id.id()) }
+    }
+
+    /// Gets how long the samples still waiting in the queue take to play.
+    ///
+    /// This is the latency of anything that's queued now.
+    pub fn queued_duration(&self) -> Duration {
+        let frames = self.size() as u64 / self.get_bytes_per_frame() as u64;
+        let freq = self.spec.freq as u64;
+        Duration::new(frames / freq, ((frames % freq) * 1_000_000_000 / freq) as u32)
+    }
+
+    /// Queues samples from `data` until `target` worth of audio is queued,
+    /// and no more.
+    ///
+    /// Returns the number of samples taken from `data`, which is always a
+    /// whole number of frames. Call this regularly with fresh samples to keep
+    /// the latency close to `target` without the queue ever running dry.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sdl2::audio::{AudioQueue, AudioSpecDesired};
+    ///
+    /// let _sdl_context = sdl2::init().audio().unwrap();
+    /// let desired_spec = AudioSpecDesired { freq: Some(44100), channels: Some(1), samples: None };
+    /// let queue = AudioQueue::<f32>::open_queue(None, &desired_spec).unwrap();
+    /// queue.resume();
+    ///
+    /// let samples = vec![0.0; 44100];
+    /// let mut pos = 0;
+    /// while pos < samples.len() {
+    ///     pos += queue.top_up(&samples[pos..], Duration::from_millis(50)).unwrap();
+    ///     sdl2::timer::delay(10);
+    /// }
+    /// ```
+    pub fn top_up(&self, data: &[Channel], target: Duration) -> SdlResult<usize> {
+        let bytes_per_frame = self.get_bytes_per_frame() as u64;
+        let freq = self.spec.freq as u64;
+        let target_frames = target.as_secs() * freq + target.subsec_nanos() as u64 * freq / 1_000_000_000;
+        let queued_frames = self.size() as u64 / bytes_per_frame;
+        if queued_frames >= target_frames {
+            return Ok(0);
+        }
+
+        let channels = self.spec.channels as usize;
+        let frames = ::std::cmp::min((target_frames - queued_frames) as usize, data.len() / channels);
+        let count = frames * channels;
+        try!(self.queue(&data[..count]));
+        Ok(count)
+    }
+
+    fn get_bytes_per_frame(&self) -> usize {
+        ::std::mem::size_of::<Channel>() * self.spec.channels as usize
+    }
+}
+
+/// Wraps SDL_AudioDeviceID and owns the callback data used by the audio device.
+pub struct AudioDevice<CB: AudioCallback> {
+    device_id: AudioDeviceID,
+    spec: AudioSpec,
+    paused: Cell<bool>,
+    auto_reopen: bool,
+    /// Store the callback to keep it alive for the entire duration of `AudioDevice`.
+    userdata: Box<CB>
+}
+
+impl<CB: AudioCallback> AudioDevice<CB> {
+    /// Opens a new audio device given the desired parameters and callback.
+    /// Uses `SDL_OpenAudioDevice`.
+    pub fn open_playback<F>(device: Option<&str>, spec: AudioSpecDesired, get_callback: F) -> SdlResult<AudioDevice<CB>>
+    where F: FnOnce(AudioSpec) -> CB
+    {
+        AudioDevice::open_playback_with_changes(device, spec, AllowedChanges::empty(), get_callback)
+    }
+
+    /// Like `open_playback()`, but lets SDL pick a different frequency or
+    /// channel count if the hardware prefers it.
+    ///
+    /// The callback must produce audio for the spec it's created with.
+    pub fn open_playback_with_changes<F>(device: Option<&str>, spec: AudioSpecDesired, allowed_changes: AllowedChanges, get_callback: F) -> SdlResult<AudioDevice<CB>>
+    where F: FnOnce(AudioSpec) -> CB
+    {
+        use std::mem;
+
+        // SDL_OpenAudioDevice needs a userdata pointer, but we can't initialize the
+        // callback without the obtained AudioSpec.
+        // Create an uninitialized box that will be initialized after SDL_OpenAudioDevice.
+        let userdata: *mut CB = unsafe {
+            let b: Box<CB> = Box::new(mem::uninitialized());
+            mem::transmute(b)
+        };
+        let desired = AudioSpecDesired::convert_to_ll(spec.freq, spec.channels, spec.samples, userdata);
+
+        let (device_id, spec) = try!(open_playback_device(device, &desired, allowed_changes));
+        unsafe {
+            let mut userdata: Box<CB> = mem::transmute(userdata);
+
+            let garbage = mem::replace(&mut userdata as &mut CB, get_callback(spec));
+            mem::forget(garbage);
+
+            Ok(AudioDevice {
+                device_id: device_id,
+                spec: spec,
+                paused: Cell::new(true),
+                auto_reopen: false,
+                userdata: userdata
+            })
+        }
+    }
+
+    /// Sets whether `handle_event()` moves playback to the default device
+    /// when this device is lost.
+    ///
+    /// # Example
+    /// ```ignore
+    /// device.set_auto_reopen(true);
+    ///
+    /// for event in event_pump.poll_iter() {
+    ///     if try!(device.handle_event(&event)) {
+    ///         println!("Switched to the default audio device");
+    ///     }
+    /// }
+    /// ```
+    pub fn set_auto_reopen(&mut self, auto_reopen: bool) {
+        self.auto_reopen = auto_reopen;
+    }
+
+    /// Checks if `event` reports that this device was lost, e.g. because
+    /// it was unplugged.
+    pub fn is_removed_event(&self, event: &Event) -> bool {
+        is_removed_event(self.device_id.id(), event)
+    }
+
+    /// Reopens the device on the default device if auto-reopen is on and
+    /// `event` reports that this device was lost.
+    ///
+    /// Returns `true` if the device was reopened. See `reopen_default()`.
+    pub fn handle_event(&mut self, event: &Event) -> SdlResult<bool> {
+        if self.auto_reopen && self.is_removed_event(event) {
+            try!(self.reopen_default());
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Moves playback to the current default device, keeping the callback,
+    /// the obtained spec and the paused state.
+    ///
+    /// SDL converts to the new device's format if needed, so the callback
+    /// keeps producing audio for `spec()`.
+    pub fn reopen_default(&mut self) -> SdlResult<()> {
+        let userdata: *mut CB = &mut *self.userdata;
+        let desired = AudioSpecDesired::convert_to_ll(
+            Some(self.spec.freq), Some(self.spec.channels), Some(self.spec.samples), userdata);
+
+        // The new device starts out paused, so the callback is never run by
+        // both devices at once.
+        let (device_id, _) = try!(open_playback_device(None, &desired, AllowedChanges::empty()));
+        self.device_id = device_id;
+        if !self.paused.get() {
+            self.resume();
+        }
+        Ok(())
+    }
+
+    /// Gets the audio spec obtained when the device was opened.
+    pub fn spec(&self) -> &AudioSpec { &self.spec }
+
+    /// Gets the playback state of the device using `SDL_GetAudioDeviceStatus`.
+    pub fn get_status(&self) -> AudioStatus {
+        unsafe {
+            let status = ll::SDL_GetAudioDeviceStatus(self.device_id.id());
+            FromPrimitive::from_i32(status as i32).unwrap()
+        }
+    }
+
+    /// Pauses playback of the audio device.
+    ///
+    /// The callback isn't called while the device is paused, and the
+    /// callback data is kept, so playback continues where it left off after
+    /// `resume()`. This makes it cheap to silence the game when the window
+    /// loses focus:
+    ///
+    /// ```ignore
+    /// match event {
+    ///     Event::Window { win_event: WindowEvent::FocusLost, .. } => device.pause(),
+    ///     Event::Window { win_event: WindowEvent::FocusGained, .. } => device.resume(),
+    ///     _ => ()
+    /// }
+    /// ```
+    pub fn pause(&self) {
+        self.paused.set(true);
+        unsafe { ll::SDL_PauseAudioDevice(self.device_id.id(), 1) }
+    }
+
+    /// Starts playback of the audio device.
+    ///
+    /// Devices are paused when they're opened, so this has to be called
+    /// once before anything is heard.
+    pub fn resume(&self) {
+        self.paused.set(false);
+        unsafe { ll::SDL_PauseAudioDevice(self.device_id.id(), 0) }
+    }
+
+    /// Locks the audio device using `SDL_LockAudioDevice`.
+    ///
+    /// When the returned lock guard is dropped, `SDL_UnlockAudioDevice` is
+    /// called.
+    /// Use this method to read and mutate callback data.
+    ///
+    /// The callback doesn't run while the device is locked, so keep the
+    /// guard around only for as long as it takes to update the data;
+    /// holding it too long causes audible dropouts.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+    ///
+    /// struct SquareWave { phase: f32, volume: f32 }
+    ///
+    /// impl AudioCallback for SquareWave {
+    ///     type Channel = f32;
+    ///
+    ///     fn callback(&mut self, out: &mut [f32]) {
+    ///         for x in out.iter_mut() {
+    ///             *x = if self.phase < 0.5 { self.volume } else { -self.volume };
+    ///             self.phase = (self.phase + 0.01) % 1.0;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let _sdl_context = sdl2::init().audio().unwrap();
+    /// let desired_spec = AudioSpecDesired { freq: None, channels: None, samples: None };
+    /// let mut device = AudioDevice::open_playback(None, desired_spec, |_| {
+    ///     SquareWave { phase: 0.0, volume: 0.25 }
+    /// }).unwrap();
+    /// device.resume();
+    ///
+    /// {
+    ///     // The callback is paused until `lock` goes out of scope.
+    ///     let mut lock = device.lock();
+    ///     lock.volume = 0.5;
+    /// }
+    /// ```
+    pub fn lock<'a>(&'a mut self) -> AudioDeviceLockGuard<'a, CB> {
+        unsafe { ll::SDL_LockAudioDevice(self.device_id.id()) };
+        AudioDeviceLockGuard {
+            device:  self,
+            _nosend: PhantomData
+        }
+    }
+
+    /// Closes the audio device and saves the callback data from being dropped.
+    ///
+    /// Note that simply dropping `AudioDevice` will close the audio device,
+    /// but the callback data will be dropped.
+    pub fn close_and_get_callback(self) -> CB {
+        drop(self.device_id);
+        *self.userdata
+    }
+}
+
+/// Similar to `std::sync::MutexGuard`, but for use with `AudioDevice::lock()`.
+///
+/// Dereferences to the callback data. The guard can't be sent to another
+/// thread, since the device must be unlocked from the thread that locked it.
+pub struct AudioDeviceLockGuard<'a, CB> where CB: AudioCallback, CB: 'a {
+    device: &'a mut AudioDevice<CB>,
+    _nosend: PhantomData<*mut ()>
+}
+
+impl<'a, CB: AudioCallback> Deref for AudioDeviceLockGuard<'a, CB> {
+    type Target = CB;
+    fn deref(&self) -> &CB { &self.device.userdata }
+}
+
+impl<'a, CB: AudioCallback> DerefMut for AudioDeviceLockGuard<'a, CB> {
+    fn deref_mut(&mut self) -> &mut CB { &mut self.device.userdata }
+}
+
+impl<'a, CB: AudioCallback> Drop for AudioDeviceLockGuard<'a, CB> {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_UnlockAudioDevice(self.device.device_id.id()) }
+    }
+}
+
+/// The volume at which `mix()` adds samples unchanged.
+pub const MIX_MAXVOLUME: i32 = ll::SDL_MIX_MAXVOLUME;
+
+/// Mixes `src` into `dst` using `SDL_MixAudioFormat`.
+///
+/// The samples are added together, clipping at the limits of `T`. `volume`
+/// ranges from 0 to `MIX_MAXVOLUME` and only applies to `src`. If the slices
+/// differ in length, only the shorter length is mixed.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{mix, MIX_MAXVOLUME};
+///
+/// let music = vec![1000i16; 1024];
+/// let effect = vec![-500i16; 256];
+///
+/// let mut out = music.clone();
+/// mix(&mut out, &effect, MIX_MAXVOLUME / 2);
+/// ```
+pub fn mix<T: AudioFormatNum>(dst: &mut [T], src: &[T], volume: i32) {
+    use std::cmp::min;
+    use std::mem::size_of;
+
+    let len = min(dst.len(), src.len()) * size_of::<T>();
+    unsafe {
+        mix_raw(dst.as_mut_ptr() as *mut u8, src.as_ptr() as *const u8,
+                T::get_audio_format(), len, volume);
+    }
+}
+
+/// Mixes `src` into `dst`, both holding samples in `format`.
+///
+/// See `mix()`.
+pub fn mix_bytes(dst: &mut [u8], src: &[u8], format: AudioFormat, volume: i32) {
+    use std::cmp::min;
+    unsafe {
+        mix_raw(dst.as_mut_ptr(), src.as_ptr(), format, min(dst.len(), src.len()), volume);
+    }
+}
+
+unsafe fn mix_raw(dst: *mut u8, src: *const u8, format: AudioFormat, len: usize, volume: i32) {
+    let volume = volume.clamp(0, MIX_MAXVOLUME);
+    // SDL takes a 32-bit length, so mix huge buffers piece by piece.
+    let mut offset = 0;
+    while offset < len {
+        let chunk = ::std::cmp::min(len - offset, 1 << 30);
+        ll::SDL_MixAudioFormat(dst.add(offset), src.add(offset), format.to_ll(),
+                               chunk as u32, volume as c_int);
+        offset += chunk;
+    }
+}
+
+/// Converts audio data between formats, channel counts and sample rates.
+///
+/// Wraps `SDL_BuildAudioCVT` and `SDL_ConvertAudio`. The buffer sizing that
+/// SDL requires (`len_mult`) is handled internally.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioCVT, AudioFormat};
+///
+/// // Mono, 22050 Hz, signed 16-bit to stereo, 44100 Hz, 32-bit float
+/// let cvt = AudioCVT::new(AudioFormat::s16_sys(), 1, 22050,
+///                         AudioFormat::f32_sys(), 2, 44100).unwrap();
+///
+/// let samples: Vec<i16> = vec![0; 22050];
+/// let converted: Vec<f32> = cvt.convert_samples(&samples).unwrap();
+/// ```
+#[derive(Copy, Clone)]
+pub struct AudioCVT {
+    raw: ll::SDL_AudioCVT
+}
+
+impl AudioCVT {
+    pub fn new(src_format: AudioFormat, src_channels: u8, src_rate: i32,
+               dst_format: AudioFormat, dst_channels: u8, dst_rate: i32) -> SdlResult<AudioCVT>
+    {
+        use std::mem;
+        unsafe {
+            let mut raw: ll::SDL_AudioCVT = mem::zeroed();
+            let ret = ll::SDL_BuildAudioCVT(&mut raw,
+                                            src_format.to_ll(), src_channels, src_rate as c_int,
+                                            dst_format.to_ll(), dst_channels, dst_rate as c_int);
+            if ret == 1 || ret == 0 {
+                Ok(AudioCVT { raw: raw })
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+
+    pub fn convert(&self, mut src: Vec<u8>) -> Vec<u8> {
+        //! Convert audio data to a desired audio format.
+        //!
+        //! The `src` vector is adjusted to the capacity necessary to perform
+        //! the conversion in place; then it is passed to the SDL library.
+        //!
+        //! Certain conversions may cause buffer overflows. See AngryLawyer/rust-sdl2 issue #270.
+        use num::traits as num;
+        unsafe {
+            if self.raw.needed != 0 {
+                let mut raw = self.raw;
+
+                // calculate the size of the dst buffer
+                raw.len = num::cast(src.len()).expect("Buffer length overflow");
+                let dst_size = self.get_capacity(src.len());
+                let needed = dst_size - src.len();
+                src.reserve_exact(needed);
+
+                // perform the conversion in place
+                raw.buf = src.as_mut_ptr();
+                let ret = ll::SDL_ConvertAudio(&mut raw);
+                // There's no reason for SDL_ConvertAudio to fail.
+                // The only time it can fail is if buf is NULL, which it never is.
+                if ret != 0 { panic!(get_error()) }
+
+                // return original buffer back to caller
+                debug_assert!(raw.len_cvt > 0);
+                debug_assert!(raw.len_cvt as usize <= src.capacity());
+
+                src.set_len(raw.len_cvt as usize);
+                src
+            } else {
+                // The buffer remains unmodified
+                src
+            }
+        }
+    }
+
+    /// Converts typed samples to a desired audio format.
+    ///
+    /// `S` must match the source format and `D` the destination format the
+    /// converter was built with, otherwise an error is returned.
+    pub fn convert_samples<S, D>(&self, src: &[S]) -> SdlResult<Vec<D>>
+    where S: AudioFormatNum, D: AudioFormatNum
+    {
+        use std::mem::size_of;
+        use std::ptr;
+
+        if S::get_audio_format() != self.get_src_format() {
+            return Err(format!("Source samples are not in the {:?} format", self.get_src_format()));
+        }
+        if D::get_audio_format() != self.get_dst_format() {
+            return Err(format!("Destination samples are not in the {:?} format", self.get_dst_format()));
+        }
+
+        let src_len = ::std::mem::size_of_val(src);
+        let mut bytes = Vec::with_capacity(self.get_capacity(src_len));
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr() as *const u8, bytes.as_mut_ptr(), src_len);
+            bytes.set_len(src_len);
+        }
+        let bytes = self.convert(bytes);
+
+        let len = bytes.len() / size_of::<D>();
+        let mut dst: Vec<D> = Vec::with_capacity(len);
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), dst.as_mut_ptr() as *mut u8, len * size_of::<D>());
+            dst.set_len(len);
+        }
+        Ok(dst)
+    }
+
+    /// Checks if any conversion is needed. i.e. if the buffer that goes
+    /// into `convert()` is unchanged from the result.
+    pub fn is_conversion_needed(&self) -> bool { self.raw.needed != 0 }
+
+    /// Gets the format of the data that goes into `convert()`.
+    pub fn get_src_format(&self) -> AudioFormat {
+        AudioFormat::from_ll(self.raw.src_format).unwrap()
+    }
+
+    /// Gets the format of the data that comes out of `convert()`.
+    pub fn get_dst_format(&self) -> AudioFormat {
+        AudioFormat::from_ll(self.raw.dst_format).unwrap()
+    }
+
+    /// Gets the approximate length of the converted data, in bytes, for
+    /// `src_len` bytes of input.
+    pub fn get_converted_len(&self, src_len: usize) -> usize {
+        if self.is_conversion_needed() {
+            (src_len as f64 * self.raw.len_ratio) as usize
+        } else {
+            src_len
+        }
+    }
+
+    /// Gets the buffer capacity that can contain both the original and
+    /// converted data.
+    pub fn get_capacity(&self, src_len: usize) -> usize {
+        src_len.checked_mul(self.raw.len_mult as usize).expect("Integer overflow")
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{AudioCVT, AudioFormat, AudioFormatNum, AudioSpec, AudioSpecDesired, AudioSpecWAV};
+    use super::{mix, MIX_MAXVOLUME};
+    use rwops::RWops;
+
+    #[test]
+    fn test_silence() {
+        assert_eq!(u8::silence(), 0x80);
+        assert_eq!(i8::silence(), 0);
+        assert_eq!(u16::silence(), 0x8000);
+        assert_eq!(i16::silence(), 0);
+        assert_eq!(i32::silence(), 0);
+        assert_eq!(f32::silence(), 0.0);
+    }
+
+    #[test]
+    fn test_audio_cvt() {
+        use std::iter::repeat;
+
+        // 0,1,2,3, ...
+        let buffer: Vec<u8> = (0..255).collect();
+
+        // 0,0,1,1,2,2,3,3, ...
+        let new_buffer_expected: Vec<u8> = (0..255).flat_map(|v| repeat(v).take(2)).collect();
+
+        let cvt = AudioCVT::new(AudioFormat::U8, 1, 44100, AudioFormat::U8, 2, 44100).unwrap();
+        assert!(cvt.is_conversion_needed());
+        assert_eq!(cvt.get_capacity(255), 255*2);
+
+        let new_buffer = cvt.convert(buffer);
+        assert_eq!(new_buffer.len(), new_buffer_expected.len());
+        assert_eq!(new_buffer, new_buffer_expected);
+    }
+
+    #[test]
+    fn test_audio_cvt_samples() {
+        let cvt = AudioCVT::new(AudioFormat::s16_sys(), 1, 44100, AudioFormat::s16_sys(), 2, 44100).unwrap();
+        assert_eq!(cvt.get_src_format(), AudioFormat::s16_sys());
+        assert_eq!(cvt.get_converted_len(4), 8);
+
+        let samples: Vec<i16> = cvt.convert_samples(&[1i16, -2, 3]).unwrap();
+        assert_eq!(samples, vec![1, 1, -2, -2, 3, 3]);
+
+        assert!(cvt.convert_samples::<f32, i16>(&[0.0]).is_err());
+    }
+
+    #[test]
+    fn test_mix() {
+        let mut dst = [100i16, 32000, -32000, 7];
+        mix(&mut dst, &[50, 1000, -1000], MIX_MAXVOLUME);
+        assert_eq!(dst, [150, 32767, -32768, 7]);
+
+        let mut dst = [0.0f32; 2];
+        mix(&mut dst, &[0.5, 0.5], 0);
+        assert_eq!(dst, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_spec_changes() {
+        let obtained = AudioSpec {
+            freq: 48000,
+            format: AudioFormat::f32_sys(),
+            channels: 2,
+            silence: 0,
+            samples: 1024,
+            size: 8192
+        };
+
+        let changes = obtained.get_changes(&AudioSpecDesired { freq: Some(44100), channels: Some(2), samples: None });
+        assert!(changes.freq);
+        assert!(!changes.channels);
+        assert!(!changes.samples);
+        assert!(changes.any());
+
+        assert!(!obtained.get_changes(&AudioSpecDesired::default()).any());
+    }
+
+    #[test]
+    fn test_load_wav_samples() {
+        // A mono, 8000 Hz, 16-bit WAVE with three samples
+        let mut wav: Vec<u8> = vec![];
+        wav.extend(b"RIFF");
+        wav.extend(&[42, 0, 0, 0]);
+        wav.extend(b"WAVEfmt ");
+        wav.extend(&[16, 0, 0, 0, 1, 0, 1, 0, 0x40, 0x1f, 0, 0, 0x80, 0x3e, 0, 0, 2, 0, 16, 0]);
+        wav.extend(b"data");
+        wav.extend(&[6, 0, 0, 0, 1, 0, 0xfe, 0xff, 3, 0]);
+
+        let mut rw = RWops::from_bytes(&wav).unwrap();
+        let wav = AudioSpecWAV::load_wav_rw(&mut rw).unwrap();
+        assert_eq!(wav.freq, 8000);
+        assert_eq!(wav.channels, 1);
+        assert_eq!(wav.format, AudioFormat::S16LSB);
+
+        assert!(wav.get_samples::<u8>().is_err());
+        if cfg!(target_endian = "little") {
+            assert_eq!(wav.get_samples::<i16>().unwrap(), &[1, -2, 3][..]);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! A lock-free ring buffer for feeding an audio callback from another thread.
+use std::cell::UnsafeCell;
+use std::cmp::min;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{AudioCallback, AudioFormatNum};
+
+struct Shared<T> {
+    /// Its length is a power of two, so slots keep their place when the
+    /// counters below wrap around.
+    buf: Box<[UnsafeCell<T>]>,
+    capacity: usize,
+    /// Total number of samples read. Only written by the consumer.
+    head: AtomicUsize,
+    /// Total number of samples written. Only written by the producer.
+    tail: AtomicUsize,
+    underruns: AtomicUsize
+}
+
+// The producer only writes to slots the consumer isn't reading and vice
+// versa; `head` and `tail` hand slots over between the two.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+}
+
+/// The consuming end of a ring buffer, used as the callback of an
+/// `AudioDevice`.
+///
+/// The game thread pushes samples through the `RingBufferProducer` and the
+/// audio thread pulls them out in the callback, without any locking.
+/// When the producer doesn't keep up, the rest of the output is filled with
+/// silence and the underrun is counted.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioDevice, AudioSpecDesired, RingBuffer};
+///
+/// let _sdl_context = sdl2::init().audio().unwrap();
+///
+/// let desired_spec = AudioSpecDesired { freq: Some(44100), channels: Some(1), samples: None };
+/// let (ring, mut producer) = RingBuffer::<f32>::new(8192);
+/// let device = AudioDevice::open_playback(None, desired_spec, |_| ring).unwrap();
+/// device.resume();
+///
+/// loop {
+///     // Generate audio as room frees up in the buffer.
+///     let samples = vec![0.0; producer.free_len()];
+///     producer.push(&samples);
+///
+///     if producer.underruns() > 0 {
+///         println!("audio is skipping");
+///     }
+///     # break;
+/// }
+/// ```
+pub struct RingBuffer<T> {
+    shared: Arc<Shared<T>>
+}
+
+/// The producing end of a ring buffer. It can be sent to another thread.
+pub struct RingBufferProducer<T> {
+    shared: Arc<Shared<T>>
+}
+
+impl<T: AudioFormatNum> RingBuffer<T> {
+    /// Creates a ring buffer that holds up to `capacity` samples.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> (RingBuffer<T>, RingBufferProducer<T>) {
+        RingBuffer::with_start(capacity, 0)
+    }
+
+    fn with_start(capacity: usize, start: usize) -> (RingBuffer<T>, RingBufferProducer<T>) {
+        assert!(capacity > 0, "Ring buffer capacity must be non-zero");
+
+        let len = capacity.next_power_of_two();
+        let buf: Vec<UnsafeCell<T>> = (0..len).map(|_| UnsafeCell::new(T::silence())).collect();
+        let shared = Arc::new(Shared {
+            buf: buf.into_boxed_slice(),
+            capacity: capacity,
+            head: AtomicUsize::new(start),
+            tail: AtomicUsize::new(start),
+            underruns: AtomicUsize::new(0)
+        });
+
+        (RingBuffer { shared: shared.clone() }, RingBufferProducer { shared: shared })
+    }
+
+    /// Removes up to `out.len()` samples from the buffer and fills the rest
+    /// of `out` with silence.
+    ///
+    /// Returns the number of samples that came from the buffer.
+    pub fn pull(&mut self, out: &mut [T]) -> usize {
+        let shared = &*self.shared;
+        let mask = shared.buf.len() - 1;
+        let head = shared.head.load(Ordering::Relaxed);
+        let tail = shared.tail.load(Ordering::Acquire);
+        let count = min(out.len(), tail.wrapping_sub(head));
+
+        for (i, x) in out[..count].iter_mut().enumerate() {
+            let index = head.wrapping_add(i) & mask;
+            *x = unsafe { *shared.buf[index].get() };
+        }
+        shared.head.store(head.wrapping_add(count), Ordering::Release);
+
+        if count < out.len() {
+            for x in out[count..].iter_mut() {
+                *x = T::silence();
+            }
+            shared.underruns.fetch_add(1, Ordering::Relaxed);
+        }
+
+        count
+    }
+
+    /// Gets the number of samples waiting to be played.
+    pub fn len(&self) -> usize { self.shared.len() }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Gets the number of times the callback ran out of samples.
+    pub fn underruns(&self) -> usize {
+        self.shared.underruns.load(Ordering::Relaxed)
+    }
+}
+
+impl<T: AudioFormatNum + Send + 'static> AudioCallback for RingBuffer<T> {
+    type Channel = T;
+
+    fn callback(&mut self, out: &mut [T]) {
+        self.pull(out);
+    }
+}
+
+impl<T: AudioFormatNum> RingBufferProducer<T> {
+    /// Appends as many samples from `data` as there is room for.
+    ///
+    /// Returns the number of samples that were added.
+    pub fn push(&mut self, data: &[T]) -> usize {
+        let shared = &*self.shared;
+        let mask = shared.buf.len() - 1;
+        let tail = shared.tail.load(Ordering::Relaxed);
+        let head = shared.head.load(Ordering::Acquire);
+        let count = min(data.len(), shared.capacity - tail.wrapping_sub(head));
+
+        for (i, &x) in data[..count].iter().enumerate() {
+            let index = tail.wrapping_add(i) & mask;
+            unsafe { *shared.buf[index].get() = x; }
+        }
+        shared.tail.store(tail.wrapping_add(count), Ordering::Release);
+
+        count
+    }
+
+    /// Gets the number of samples waiting to be played.
+    pub fn len(&self) -> usize { self.shared.len() }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Gets the number of samples that can be pushed right now.
+    pub fn free_len(&self) -> usize { self.capacity() - self.len() }
+
+    pub fn capacity(&self) -> usize { self.shared.capacity }
+
+    /// Gets the number of times the callback ran out of samples.
+    pub fn underruns(&self) -> usize {
+        self.shared.underruns.load(Ordering::Relaxed)
+    }
+
+    /// Resets the underrun count to zero and returns the previous count.
+    pub fn take_underruns(&self) -> usize {
+        self.shared.underruns.swap(0, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RingBuffer;
+    use audio::AudioCallback;
+
+    #[test]
+    fn test_ring_buffer() {
+        let (mut ring, mut producer) = RingBuffer::<u8>::new(4);
+        assert_eq!(producer.push(&[1, 2, 3]), 3);
+        assert_eq!(producer.push(&[4, 5, 6]), 1);
+        assert_eq!(producer.free_len(), 0);
+
+        let mut out = [0; 3];
+        ring.callback(&mut out);
+        assert_eq!(out, [1, 2, 3]);
+        assert_eq!(ring.underruns(), 0);
+
+        // Wraps around the end of the buffer
+        assert_eq!(producer.push(&[7, 8]), 2);
+        let mut out = [0; 5];
+        ring.callback(&mut out);
+        assert_eq!(out, [4, 7, 8, 0x80, 0x80]);
+        assert_eq!(producer.take_underruns(), 1);
+        assert_eq!(producer.underruns(), 0);
+        assert!(producer.is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_counter_wrap() {
+        // A capacity that isn't a power of two, with the counters about to
+        // wrap around
+        let (mut ring, mut producer) = RingBuffer::<u8>::with_start(3, usize::MAX - 1);
+        let mut out = [0; 2];
+
+        for i in 0..8 {
+            assert_eq!(producer.push(&[i, i + 1, i + 2, i + 3]), 3);
+            ring.callback(&mut out);
+            assert_eq!(out, [i, i + 1]);
+            ring.callback(&mut out[..1]);
+            assert_eq!(out[0], i + 2);
+            assert!(ring.is_empty());
+        }
+        assert_eq!(ring.underruns(), 0);
+    }
+}
@@ -0,0 +1,111 @@
+//! Adapting iterators into audio callbacks.
+use super::{AudioCallback, AudioFormatNum};
+
+/// An `AudioCallback` that plays the samples of an iterator.
+///
+/// Procedural synths and decoders that produce samples one at a time can be
+/// hooked to a device without writing a callback by hand. Once the iterator
+/// runs out, the output is silence.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioDevice, AudioSpecDesired, IterSource};
+///
+/// let _sdl_context = sdl2::init().audio().unwrap();
+///
+/// let desired_spec = AudioSpecDesired { freq: Some(44100), channels: Some(2), samples: None };
+/// let device = AudioDevice::open_playback(None, desired_spec, |spec| {
+///     let step = 440.0 * 2.0 * ::std::f32::consts::PI / spec.freq as f32;
+///     // A 440 Hz sine wave, played on both channels
+///     let sine = (0..).map(move |i| (i as f32 * step).sin() * 0.25);
+///     IterSource::mono(sine, spec.channels)
+/// }).unwrap();
+///
+/// device.resume();
+/// sdl2::timer::delay(1000);
+/// ```
+pub struct IterSource<I> {
+    iter: I,
+    repeat: usize,
+    finished: bool
+}
+
+impl<T, I> IterSource<I>
+where T: AudioFormatNum, I: Iterator<Item = T>
+{
+    /// Plays one sample from `iter` on all `channels` at once.
+    ///
+    /// # Panics
+    /// Panics if `channels` is zero.
+    pub fn mono(iter: I, channels: u8) -> IterSource<I> {
+        assert!(channels > 0, "Channel count must be non-zero");
+        IterSource {
+            iter: iter,
+            repeat: channels as usize,
+            finished: false
+        }
+    }
+
+    /// Plays samples from `iter` that are already interleaved for the
+    /// device's channel count (e.g. `L R L R` for stereo).
+    pub fn interleaved(iter: I) -> IterSource<I> {
+        IterSource {
+            iter: iter,
+            repeat: 1,
+            finished: false
+        }
+    }
+
+    /// Checks if the iterator has run out of samples.
+    pub fn is_finished(&self) -> bool { self.finished }
+
+    /// Gets the wrapped iterator back.
+    pub fn into_inner(self) -> I { self.iter }
+}
+
+impl<T, I> AudioCallback for IterSource<I>
+where T: AudioFormatNum + 'static, I: Iterator<Item = T> + Send
+{
+    type Channel = T;
+
+    fn callback(&mut self, out: &mut [T]) {
+        for frame in out.chunks_mut(self.repeat) {
+            let sample = if self.finished {
+                T::silence()
+            } else {
+                match self.iter.next() {
+                    Some(sample) => sample,
+                    None => {
+                        self.finished = true;
+                        T::silence()
+                    }
+                }
+            };
+
+            for x in frame.iter_mut() {
+                *x = sample;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IterSource;
+    use audio::AudioCallback;
+
+    #[test]
+    fn test_iter_source() {
+        let mut source = IterSource::mono(vec![1i16, 2, 3].into_iter(), 2);
+        let mut out = [9; 8];
+        source.callback(&mut out);
+        assert_eq!(out, [1, 1, 2, 2, 3, 3, 0, 0]);
+        assert!(source.is_finished());
+
+        let mut source = IterSource::interleaved(vec![1u8, 2, 3].into_iter());
+        let mut out = [9; 2];
+        source.callback(&mut out);
+        assert_eq!(out, [1, 2]);
+        assert!(!source.is_finished());
+    }
+}
@@ -0,0 +1,138 @@
+//! Incremental audio conversion with `SDL_AudioStream`.
+//!
+//! Unlike `AudioCVT`, an `AudioStream` accepts data in arbitrarily sized
+//! pieces and keeps whatever it can't convert yet for the next call. This
+//! makes it suitable for resampling audio as it's being generated or decoded.
+//!
+//! Requires SDL 2.0.7 or later.
+use libc::{c_int, c_void};
+use std::mem::size_of;
+
+use get_error;
+use SdlResult;
+
+use super::{AudioFormat, AudioFormatNum};
+use sys::audio as ll;
+
+/// A stream that converts audio data between formats, channel counts and
+/// sample rates as it is fed.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioFormat, AudioStream};
+///
+/// // Resample mono 22050 Hz to mono 48000 Hz
+/// let mut stream = AudioStream::new(AudioFormat::f32_sys(), 1, 22050,
+///                                   AudioFormat::f32_sys(), 1, 48000).unwrap();
+///
+/// stream.put_samples(&[0.0f32; 1024]).unwrap();
+/// stream.flush().unwrap();
+///
+/// let mut out = [0.0f32; 4096];
+/// let count = stream.get_samples(&mut out).unwrap();
+/// println!("got {} samples", count);
+/// ```
+pub struct AudioStream {
+    raw: *mut ll::SDL_AudioStream
+}
+
+impl AudioStream {
+    /// Creates a new stream using `SDL_NewAudioStream`.
+    pub fn new(src_format: AudioFormat, src_channels: u8, src_rate: i32,
+               dst_format: AudioFormat, dst_channels: u8, dst_rate: i32) -> SdlResult<AudioStream>
+    {
+        let raw = unsafe {
+            ll::SDL_NewAudioStream(src_format.to_ll(), src_channels, src_rate as c_int,
+                                   dst_format.to_ll(), dst_channels, dst_rate as c_int)
+        };
+
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(AudioStream { raw: raw })
+        }
+    }
+
+    /// Adds data in the source format to the stream.
+    pub fn put(&mut self, buf: &[u8]) -> SdlResult<()> {
+        if buf.len() > c_int::MAX as usize {
+            return Err("Buffer is too large".to_owned());
+        }
+
+        let ret = unsafe {
+            ll::SDL_AudioStreamPut(self.raw, buf.as_ptr() as *const c_void, buf.len() as c_int)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Adds typed samples to the stream. `T` must match the source format.
+    pub fn put_samples<T: AudioFormatNum>(&mut self, samples: &[T]) -> SdlResult<()> {
+        use std::slice::from_raw_parts;
+        let bytes = unsafe {
+            from_raw_parts(samples.as_ptr() as *const u8, ::std::mem::size_of_val(samples))
+        };
+        self.put(bytes)
+    }
+
+    /// Reads converted data into `buf`.
+    ///
+    /// Returns the number of bytes read, which may be less than the size of
+    /// `buf` (or zero) if not enough converted data is available yet.
+    pub fn get(&mut self, buf: &mut [u8]) -> SdlResult<usize> {
+        let len = ::std::cmp::min(buf.len(), c_int::MAX as usize);
+        let ret = unsafe {
+            ll::SDL_AudioStreamGet(self.raw, buf.as_mut_ptr() as *mut c_void, len as c_int)
+        };
+        if ret < 0 {
+            Err(get_error())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    /// Reads converted samples into `buf`. `T` must match the destination
+    /// format.
+    ///
+    /// Returns the number of samples read.
+    pub fn get_samples<T: AudioFormatNum>(&mut self, buf: &mut [T]) -> SdlResult<usize> {
+        use std::slice::from_raw_parts_mut;
+        let bytes = unsafe {
+            from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, ::std::mem::size_of_val(buf))
+        };
+        // SDL only hands out whole sample frames, so this never splits a sample.
+        self.get(bytes).map(|len| len / size_of::<T>())
+    }
+
+    /// Gets the number of converted bytes that are ready to be read.
+    pub fn available(&self) -> usize {
+        unsafe { ll::SDL_AudioStreamAvailable(self.raw) as usize }
+    }
+
+    /// Converts any data that is still buffered, even if it's not enough to
+    /// fill a whole resampling window.
+    ///
+    /// Call this when there is no more input, e.g. at the end of a sound.
+    pub fn flush(&mut self) -> SdlResult<()> {
+        let ret = unsafe { ll::SDL_AudioStreamFlush(self.raw) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Drops all buffered data, both unconverted and converted.
+    pub fn clear(&mut self) {
+        unsafe { ll::SDL_AudioStreamClear(self.raw) }
+    }
+}
+
+impl Drop for AudioStream {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_FreeAudioStream(self.raw) }
+    }
+}
@@ -0,0 +1,290 @@
+//! Writing audio data to WAVE files.
+use std::io::{Seek, SeekFrom, Write};
+use std::path::Path;
+
+use get_error;
+use rwops::RWops;
+use SdlResult;
+
+use super::{AudioFormat, AudioFormatNum, AudioSpec};
+
+/// Size of the RIFF, `fmt ` and `data` headers written by `WavWriter`.
+const PCM_HEADER_LEN: u32 = 44;
+
+/// Float files also have `cbSize` in `fmt ` and a `fact` chunk.
+const FLOAT_HEADER_LEN: u32 = 58;
+
+/// The format tag of WAVE files with IEEE float samples.
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// Streams PCM samples to a RIFF/WAVE file.
+///
+/// The header is written up front and its sizes are filled in by `finish()`
+/// (or when the writer is dropped), so samples can be appended as they are
+/// captured or generated.
+///
+/// Only the formats that WAVE files can hold are supported: `U8`, `S16LSB`,
+/// `S32LSB` and `F32LSB`. Use `AudioCVT` to convert other formats first.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::audio::{AudioFormat, WavWriter};
+///
+/// let mut writer = WavWriter::create("tone.wav", AudioFormat::S16LSB, 1, 44100).unwrap();
+///
+/// let samples: Vec<i16> = (0..44100).map(|i| {
+///     ((i as f32 * 440.0 / 44100.0 * 6.2832).sin() * 8000.0) as i16
+/// }).collect();
+/// writer.write_samples(&samples).unwrap();
+/// writer.finish().unwrap();
+/// ```
+pub struct WavWriter<'a> {
+    rw: RWops<'a>,
+    format: AudioFormat,
+    channels: u8,
+    freq: i32,
+    data_len: u32,
+    finished: bool
+}
+
+impl WavWriter<'static> {
+    /// Creates (or truncates) the file at `path` and writes the header.
+    pub fn create<P: AsRef<Path>>(path: P, format: AudioFormat, channels: u8, freq: i32) -> SdlResult<WavWriter<'static>> {
+        let rw = try!(RWops::from_file(path, "wb"));
+        WavWriter::new(rw, format, channels, freq)
+    }
+
+    /// Creates a file matching the format, channel count and frequency of
+    /// `spec`, e.g. the spec obtained when opening a device.
+    pub fn create_for_spec<P: AsRef<Path>>(path: P, spec: &AudioSpec) -> SdlResult<WavWriter<'static>> {
+        WavWriter::create(path, spec.format, spec.channels, spec.freq)
+    }
+}
+
+impl<'a> WavWriter<'a> {
+    /// Writes the header to `rw`, which must be writable and seekable.
+    pub fn new(rw: RWops<'a>, format: AudioFormat, channels: u8, freq: i32) -> SdlResult<WavWriter<'a>> {
+        if get_format_tag(format).is_none() {
+            return Err(format!("WAVE files can't hold samples in the {:?} format", format));
+        }
+        if channels == 0 {
+            return Err("WAVE files need at least one channel".to_owned());
+        }
+        if freq <= 0 {
+            return Err(format!("Invalid frequency: {}", freq));
+        }
+
+        let mut writer = WavWriter {
+            rw: rw,
+            format: format,
+            channels: channels,
+            freq: freq,
+            data_len: 0,
+            finished: false
+        };
+        let header = writer.get_header();
+        try!(writer.write_raw(&header));
+        Ok(writer)
+    }
+
+    pub fn format(&self) -> AudioFormat { self.format }
+    pub fn channels(&self) -> u8 { self.channels }
+    pub fn freq(&self) -> i32 { self.freq }
+
+    /// Gets the number of bytes of sample data written so far.
+    pub fn data_len(&self) -> u32 { self.data_len }
+
+    /// Appends raw sample data in the writer's format.
+    pub fn write_bytes(&mut self, data: &[u8]) -> SdlResult<()> {
+        let data_len = self.data_len as u64 + data.len() as u64;
+        // Odd-length data is followed by a pad byte.
+        if data_len + data_len % 2 + self.header_len() as u64 > u32::MAX as u64 {
+            return Err("WAVE file would exceed 4 GB".to_owned());
+        }
+
+        try!(self.write_raw(data));
+        self.data_len = data_len as u32;
+        Ok(())
+    }
+
+    /// Appends samples. `T` must match the writer's format.
+    pub fn write_samples<T: AudioFormatNum>(&mut self, samples: &[T]) -> SdlResult<()> {
+        use std::slice::from_raw_parts;
+
+        if T::get_audio_format() != self.format {
+            return Err(format!("Samples are not in the {:?} format", self.format));
+        }
+
+        let bytes = unsafe {
+            from_raw_parts(samples.as_ptr() as *const u8, ::std::mem::size_of_val(samples))
+        };
+        self.write_bytes(bytes)
+    }
+
+    /// Fills in the sizes in the header and closes the file.
+    pub fn finish(mut self) -> SdlResult<()> {
+        self.finished = true;
+        self.update_header()
+    }
+
+    fn update_header(&mut self) -> SdlResult<()> {
+        // RIFF chunks are word-aligned.
+        if self.data_len % 2 == 1 {
+            try!(self.write_raw(&[0]));
+        }
+
+        let header = self.get_header();
+        try!(self.rw.seek(SeekFrom::Start(0)).map_err(|e| e.to_string()));
+        try!(self.write_raw(&header));
+        try!(self.rw.seek(SeekFrom::End(0)).map_err(|e| e.to_string()));
+        Ok(())
+    }
+
+    fn write_raw(&mut self, data: &[u8]) -> SdlResult<()> {
+        self.rw.write_all(data).map_err(|_| get_error())
+    }
+
+    fn header_len(&self) -> u32 {
+        match get_format_tag(self.format) {
+            Some(WAVE_FORMAT_IEEE_FLOAT) => FLOAT_HEADER_LEN,
+            _ => PCM_HEADER_LEN
+        }
+    }
+
+    fn get_header(&self) -> Vec<u8> {
+        let format_tag = get_format_tag(self.format).unwrap();
+        let bits = get_bits_per_sample(self.format);
+        let block_align = self.channels as u16 * (bits / 8);
+        let byte_rate = self.freq as u32 * block_align as u32;
+        let header_len = self.header_len();
+
+        let mut header = Vec::with_capacity(header_len as usize);
+        header.extend(b"RIFF");
+        push_u32(&mut header, header_len - 8 + self.data_len + self.data_len % 2);
+        header.extend(b"WAVE");
+
+        header.extend(b"fmt ");
+        push_u32(&mut header, if format_tag == WAVE_FORMAT_IEEE_FLOAT { 18 } else { 16 });
+        push_u16(&mut header, format_tag);
+        push_u16(&mut header, self.channels as u16);
+        push_u32(&mut header, self.freq as u32);
+        push_u32(&mut header, byte_rate);
+        push_u16(&mut header, block_align);
+        push_u16(&mut header, bits);
+
+        if format_tag == WAVE_FORMAT_IEEE_FLOAT {
+            // `cbSize`: no extra format information.
+            push_u16(&mut header, 0);
+
+            // Formats other than integer PCM need the number of sample frames.
+            header.extend(b"fact");
+            push_u32(&mut header, 4);
+            push_u32(&mut header, self.data_len / block_align as u32);
+        }
+
+        header.extend(b"data");
+        push_u32(&mut header, self.data_len);
+        header
+    }
+}
+
+impl<'a> Drop for WavWriter<'a> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // Errors can't be reported here; call `finish()` to handle them.
+            let _ = self.update_header();
+        }
+    }
+}
+
+/// Gets the WAVE format tag: 1 for integer PCM, 3 for IEEE floats.
+fn get_format_tag(format: AudioFormat) -> Option<u16> {
+    match format {
+        AudioFormat::U8 | AudioFormat::S16LSB | AudioFormat::S32LSB => Some(1),
+        AudioFormat::F32LSB => Some(WAVE_FORMAT_IEEE_FLOAT),
+        _ => None
+    }
+}
+
+fn get_bits_per_sample(format: AudioFormat) -> u16 {
+    (format as u16) & 0xff
+}
+
+fn push_u16(buf: &mut Vec<u8>, value: u16) {
+    buf.push(value as u8);
+    buf.push((value >> 8) as u8);
+}
+
+fn push_u32(buf: &mut Vec<u8>, value: u32) {
+    push_u16(buf, value as u16);
+    push_u16(buf, (value >> 16) as u16);
+}
+
+#[cfg(test)]
+mod test {
+    use super::WavWriter;
+    use audio::{AudioFormat, AudioSpecWAV};
+    use rwops::RWops;
+
+    #[test]
+    fn test_write_and_load() {
+        let mut buf = vec![0u8; 64];
+        {
+            let rw = RWops::from_bytes_mut(&mut buf).unwrap();
+            let mut writer = WavWriter::new(rw, AudioFormat::U8, 2, 8000).unwrap();
+            assert!(writer.write_samples(&[0i16]).is_err());
+            writer.write_samples(&[1u8, 2, 3, 4]).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut rw = RWops::from_bytes(&buf[..48]).unwrap();
+        let wav = AudioSpecWAV::load_wav_rw(&mut rw).unwrap();
+        assert_eq!(wav.format, AudioFormat::U8);
+        assert_eq!(wav.channels, 2);
+        assert_eq!(wav.freq, 8000);
+        assert_eq!(wav.get_buffer(), &[1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn test_write_odd_length() {
+        let mut buf = vec![0xffu8; 48];
+        {
+            let rw = RWops::from_bytes_mut(&mut buf).unwrap();
+            let mut writer = WavWriter::new(rw, AudioFormat::U8, 1, 8000).unwrap();
+            writer.write_samples(&[1u8, 2, 3]).unwrap();
+            writer.finish().unwrap();
+        }
+
+        // The RIFF size counts the pad byte, the `data` size doesn't.
+        assert_eq!(&buf[4..8], &[40, 0, 0, 0]);
+        assert_eq!(&buf[40..44], &[3, 0, 0, 0]);
+        assert_eq!(buf[47], 0);
+
+        let mut rw = RWops::from_bytes(&buf[..]).unwrap();
+        let wav = AudioSpecWAV::load_wav_rw(&mut rw).unwrap();
+        assert_eq!(wav.get_buffer(), &[1, 2, 3][..]);
+    }
+
+    #[test]
+    fn test_write_float() {
+        let mut buf = vec![0u8; 74];
+        {
+            let rw = RWops::from_bytes_mut(&mut buf).unwrap();
+            let mut writer = WavWriter::new(rw, AudioFormat::F32LSB, 2, 8000).unwrap();
+            writer.write_samples(&[0.5f32, -0.5, 0.25, -0.25]).unwrap();
+            writer.finish().unwrap();
+        }
+
+        assert_eq!(&buf[4..8], &[66, 0, 0, 0]);
+        // `fmt ` has `cbSize`, followed by `fact` with two sample frames.
+        assert_eq!(&buf[16..20], &[18, 0, 0, 0]);
+        assert_eq!(&buf[36..38], &[0, 0]);
+        assert_eq!(&buf[38..50], b"fact\x04\0\0\0\x02\0\0\0");
+        assert_eq!(&buf[50..58], b"data\x10\0\0\0");
+
+        let mut rw = RWops::from_bytes(&buf[..]).unwrap();
+        let wav = AudioSpecWAV::load_wav_rw(&mut rw).unwrap();
+        assert_eq!(wav.format, AudioFormat::F32LSB);
+        assert_eq!(wav.get_buffer().len(), 16);
+    }
+}
@@ -0,0 +1,152 @@
+/// Converts a raw axis position to the range -1.0 to 1.0.
+///
+/// Stick axes range from -32768 to 32767, so the value is clamped to keep
+/// both directions symmetric. Trigger axes end up between 0.0 and 1.0.
+pub fn normalize_axis(value: i16) -> f32 {
+    (value as f32 / 32767.0).max(-1.0)
+}
+
+/// How the deadzone of a stick is shaped.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DeadzoneShape {
+    /// Each axis has its own deadzone. Makes it easy to move along exactly
+    /// one axis, but snaps diagonal movement near the center to the axes.
+    Axial,
+    /// The deadzone is a circle around the center. Keeps the direction
+    /// of the stick intact, which suits aiming and movement.
+    Radial
+}
+
+/// Filters out the noise of sticks near their center, and rescales the
+/// remaining range so the output still starts at 0.0 and reaches 1.0.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{Axis, Deadzone, GameController};
+///
+/// let _sdl_context = sdl2::init().game_controller().unwrap();
+/// let controller = GameController::open(0).unwrap();
+/// let deadzone = Deadzone::radial(0.2);
+///
+/// let (x, y) = deadzone.apply_stick(controller.axis_f32(Axis::LeftX),
+///                                   controller.axis_f32(Axis::LeftY));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Deadzone {
+    shape: DeadzoneShape,
+    inner: f32,
+    outer: f32
+}
+
+impl Deadzone {
+    /// Creates a deadzone of the given shape, where positions below `inner`
+    /// count as 0.0 and positions above `outer` as 1.0.
+    ///
+    /// Both are limited to 0.0 to 1.0, and `outer` to at least `inner`. A NaN
+    /// `inner` means no deadzone, and a NaN `outer` means the full range.
+    pub fn new(shape: DeadzoneShape, inner: f32, outer: f32) -> Deadzone {
+        // `clamp` panics on a NaN bound, and would pass a NaN value through.
+        let inner = if inner.is_nan() { 0.0 } else { inner.clamp(0.0, 1.0) };
+        let outer = if outer.is_nan() { 1.0 } else { outer.clamp(inner, 1.0) };
+
+        Deadzone {
+            shape: shape,
+            inner: inner,
+            outer: outer
+        }
+    }
+
+    /// Creates an axial deadzone of size `inner`.
+    pub fn axial(inner: f32) -> Deadzone {
+        Deadzone::new(DeadzoneShape::Axial, inner, 1.0)
+    }
+
+    /// Creates a radial deadzone of size `inner`.
+    pub fn radial(inner: f32) -> Deadzone {
+        Deadzone::new(DeadzoneShape::Radial, inner, 1.0)
+    }
+
+    pub fn shape(&self) -> DeadzoneShape {
+        self.shape
+    }
+
+    pub fn inner(&self) -> f32 {
+        self.inner
+    }
+
+    pub fn outer(&self) -> f32 {
+        self.outer
+    }
+
+    /// Applies the deadzone to a single axis, between -1.0 and 1.0.
+    pub fn apply_axis(&self, value: f32) -> f32 {
+        let magnitude = self.rescale(value.abs());
+
+        if value < 0.0 { -magnitude } else { magnitude }
+    }
+
+    /// Applies the deadzone to the position of a stick, with both axes
+    /// between -1.0 and 1.0.
+    pub fn apply_stick(&self, x: f32, y: f32) -> (f32, f32) {
+        match self.shape {
+            DeadzoneShape::Axial => (self.apply_axis(x), self.apply_axis(y)),
+            DeadzoneShape::Radial => {
+                let magnitude = (x * x + y * y).sqrt();
+                if magnitude <= self.inner {
+                    return (0.0, 0.0);
+                }
+
+                let scale = self.rescale(magnitude) / magnitude;
+                (x * scale, y * scale)
+            }
+        }
+    }
+
+    fn rescale(&self, magnitude: f32) -> f32 {
+        if magnitude <= self.inner {
+            0.0
+        } else if magnitude >= self.outer {
+            1.0
+        } else {
+            (magnitude - self.inner) / (self.outer - self.inner)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{normalize_axis, Deadzone, DeadzoneShape};
+
+    #[test]
+    fn test_normalize_axis() {
+        assert_eq!(normalize_axis(0), 0.0);
+        assert_eq!(normalize_axis(32767), 1.0);
+        assert_eq!(normalize_axis(-32768), -1.0);
+    }
+
+    #[test]
+    fn test_deadzone() {
+        let axial = Deadzone::axial(0.2);
+        assert_eq!(axial.apply_axis(0.1), 0.0);
+        assert_eq!(axial.apply_axis(-1.0), -1.0);
+        assert!((axial.apply_axis(0.6) - 0.5).abs() < 1e-6);
+        assert_eq!(axial.apply_stick(0.6, 0.1).1, 0.0);
+
+        let radial = Deadzone::radial(0.2);
+        assert_eq!(radial.apply_stick(0.1, 0.1), (0.0, 0.0));
+        let (x, y) = radial.apply_stick(0.6, 0.0);
+        assert!((x - 0.5).abs() < 1e-6 && y == 0.0);
+        let (x, y) = radial.apply_stick(0.9, 0.9);
+        assert!((x - y).abs() < 1e-6 && (x * x + y * y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_deadzone_nan() {
+        let deadzone = Deadzone::axial(::std::f32::NAN);
+        assert_eq!((deadzone.inner(), deadzone.outer()), (0.0, 1.0));
+        assert_eq!(deadzone.apply_axis(0.5), 0.5);
+
+        let deadzone = Deadzone::new(DeadzoneShape::Radial, 0.2, ::std::f32::NAN);
+        assert_eq!((deadzone.inner(), deadzone.outer()), (0.2, 1.0));
+    }
+}
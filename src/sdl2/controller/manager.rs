@@ -0,0 +1,134 @@
+use std::collections::hash_map::{self, HashMap};
+
+use event::Event;
+use SdlResult;
+
+use super::{is_game_controller, GameController};
+
+/// A change to the set of controllers of a `Manager`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Change {
+    /// The controller with the given instance id was plugged in and opened.
+    Connected(i32),
+    /// The controller with the given instance id was unplugged and closed.
+    Disconnected(i32)
+}
+
+/// Opens and closes game controllers as they are plugged in and out.
+///
+/// Controllers are keyed by their joystick instance id, which is what the
+/// `which` field of all controller events refers to. `ControllerDeviceAdded`
+/// is the exception: it reports the device index, which is only valid until
+/// the next device is plugged in or out. The manager takes care of telling
+/// the two apart.
+///
+/// SDL sends a `ControllerDeviceAdded` event for every controller that is
+/// already plugged in when the controller subsystem is initialized, so
+/// feeding the manager all events is enough to find every controller.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{Axis, Change, Manager};
+///
+/// let mut sdl_context = sdl2::init().game_controller().unwrap();
+/// let mut controllers = Manager::new();
+///
+/// for event in sdl_context.event_pump().poll_iter() {
+///     match controllers.handle_event(&event) {
+///         Ok(Some(Change::Connected(id))) => println!("Controller {} connected", id),
+///         Ok(Some(Change::Disconnected(id))) => println!("Controller {} disconnected", id),
+///         Ok(None) => (),
+///         Err(e) => println!("Could not open controller: {}", e)
+///     }
+/// }
+///
+/// for (id, controller) in controllers.iter() {
+///     println!("Controller {}: {}", id, controller.get_axis(Axis::LeftX));
+/// }
+/// ```
+#[derive(Default)]
+pub struct Manager {
+    controllers: HashMap<i32, GameController>
+}
+
+impl Manager {
+    /// Creates a manager without any controllers.
+    pub fn new() -> Manager {
+        Manager {
+            controllers: HashMap::new()
+        }
+    }
+
+    /// Opens or closes controllers on `ControllerDeviceAdded` and
+    /// `ControllerDeviceRemoved` events, and returns what changed.
+    ///
+    /// Fails if a controller that was plugged in couldn't be opened.
+    pub fn handle_event(&mut self, event: &Event) -> SdlResult<Option<Change>> {
+        match *event {
+            Event::ControllerDeviceAdded { which, .. } => self.open(which),
+            Event::ControllerDeviceRemoved { which, .. } => {
+                match self.controllers.remove(&which) {
+                    Some(_) => Ok(Some(Change::Disconnected(which))),
+                    None => Ok(None)
+                }
+            },
+            _ => Ok(None)
+        }
+    }
+
+    /// Opens the controller at the device index `index`, unless it is open
+    /// already.
+    ///
+    /// Only needed for controllers that were plugged in before the
+    /// controller subsystem was initialized, if the `ControllerDeviceAdded`
+    /// events for them weren't passed to `handle_event()`.
+    pub fn open(&mut self, index: i32) -> SdlResult<Option<Change>> {
+        if !is_game_controller(index) {
+            return Ok(None);
+        }
+
+        let controller = try!(GameController::open(index));
+        let id = controller.get_instance_id();
+
+        // SDL hands out the same controller again if it is open already;
+        // dropping the duplicate just releases the extra reference.
+        match self.controllers.entry(id) {
+            hash_map::Entry::Occupied(_) => Ok(None),
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(controller);
+                Ok(Some(Change::Connected(id)))
+            }
+        }
+    }
+
+    /// Returns the controller with the given instance id.
+    pub fn get(&self, id: i32) -> Option<&GameController> {
+        self.controllers.get(&id)
+    }
+
+    /// Tests if the controller with the given instance id is connected.
+    pub fn contains(&self, id: i32) -> bool {
+        self.controllers.contains_key(&id)
+    }
+
+    /// Returns the instance ids of the connected controllers, in no
+    /// particular order.
+    pub fn instance_ids(&self) -> Vec<i32> {
+        self.controllers.keys().cloned().collect()
+    }
+
+    /// Iterates over the connected controllers and their instance ids, in
+    /// no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, i32, GameController> {
+        self.controllers.iter()
+    }
+
+    /// The number of connected controllers.
+    pub fn len(&self) -> usize {
+        self.controllers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.controllers.is_empty()
+    }
+}
@@ -0,0 +1,628 @@
+/*!
+Game controllers
+
+A game controller is a joystick with a known layout: the buttons and axes of
+an Xbox 360 style pad. SDL maps the raw axes and buttons of supported
+joysticks to that layout, so `Axis::LeftX` is the horizontal axis of the
+left stick no matter which pad is plugged in. Use the `joystick` module for
+devices without a mapping, and `add_mapping()` to add mappings.
+
+# Example
+```no_run
+use sdl2::controller::{self, Axis, Button, GameController};
+use sdl2::joystick;
+
+let _sdl_context = sdl2::init().game_controller().unwrap();
+
+let controller = (0..joystick::num_joysticks().unwrap())
+    .filter(|&id| controller::is_game_controller(id))
+    .filter_map(|id| GameController::open(id).ok())
+    .next()
+    .expect("No game controller found");
+
+println!("Using {}", controller.name());
+
+if controller.get_attached() && controller.get_button(Button::A) {
+    println!("Left stick at {}", controller.get_axis(Axis::LeftX));
+}
+```
+*/
+
+use libc::{c_char, c_void};
+use std::ffi::{CString, CStr};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use SdlResult;
+use get_error;
+use joystick;
+use rwops::RWops;
+use util::CStringExt;
+
+use sys::controller as ll;
+use sys::event::{SDL_QUERY, SDL_ENABLE};
+
+mod deadzone;
+mod manager;
+mod players;
+mod wizard;
+pub use self::deadzone::{normalize_axis, Deadzone, DeadzoneShape};
+pub use self::manager::{Manager, Change};
+pub use self::players::{PlayerEvent, PlayerSlots};
+pub use self::wizard::{MappingWizard, Target};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(i32)]
+pub enum Axis {
+    LeftX        = ll::SDL_CONTROLLER_AXIS_LEFTX,
+    LeftY        = ll::SDL_CONTROLLER_AXIS_LEFTY,
+    RightX       = ll::SDL_CONTROLLER_AXIS_RIGHTX,
+    RightY       = ll::SDL_CONTROLLER_AXIS_RIGHTY,
+    TriggerLeft  = ll::SDL_CONTROLLER_AXIS_TRIGGERLEFT,
+    TriggerRight = ll::SDL_CONTROLLER_AXIS_TRIGGERRIGHT,
+}
+
+impl Axis {
+    /// Return the Axis from a string description in the same format
+    /// used by the game controller mapping strings, like `"leftx"` or
+    /// `"righttrigger"`.
+    pub fn from_string(axis: &str) -> Option<Axis> {
+        let id = match CString::new(axis) {
+            Ok(axis) => unsafe { ll::SDL_GameControllerGetAxisFromString(axis.as_ptr()) },
+            // string contains a nul byte - it won't match anything.
+            Err(_) => ll::SDL_CONTROLLER_AXIS_INVALID
+        };
+
+        Axis::from_ll(id)
+    }
+
+    /// Return a string for a given axis in the same format using by
+    /// the game controller mapping strings
+    pub fn get_string(self) -> String {
+        let axis = self as ll::SDL_GameControllerAxis;
+
+        let string = unsafe { ll::SDL_GameControllerGetStringForAxis(axis) };
+
+        c_str_to_string(string)
+    }
+
+    pub fn from_ll(bitflags: ll::SDL_GameControllerAxis) -> Option<Axis> {
+        Some(match bitflags {
+            ll::SDL_CONTROLLER_AXIS_INVALID      => return None,
+            ll::SDL_CONTROLLER_AXIS_LEFTX        => Axis::LeftX,
+            ll::SDL_CONTROLLER_AXIS_LEFTY        => Axis::LeftY,
+            ll::SDL_CONTROLLER_AXIS_RIGHTX       => Axis::RightX,
+            ll::SDL_CONTROLLER_AXIS_RIGHTY       => Axis::RightY,
+            ll::SDL_CONTROLLER_AXIS_TRIGGERLEFT  => Axis::TriggerLeft,
+            ll::SDL_CONTROLLER_AXIS_TRIGGERRIGHT => Axis::TriggerRight,
+            // Added by a newer version of SDL.
+            _ => return None
+        })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(i32)]
+pub enum Button {
+    A             = ll::SDL_CONTROLLER_BUTTON_A,
+    B             = ll::SDL_CONTROLLER_BUTTON_B,
+    X             = ll::SDL_CONTROLLER_BUTTON_X,
+    Y             = ll::SDL_CONTROLLER_BUTTON_Y,
+    Back          = ll::SDL_CONTROLLER_BUTTON_BACK,
+    Guide         = ll::SDL_CONTROLLER_BUTTON_GUIDE,
+    Start         = ll::SDL_CONTROLLER_BUTTON_START,
+    LeftStick     = ll::SDL_CONTROLLER_BUTTON_LEFTSTICK,
+    RightStick    = ll::SDL_CONTROLLER_BUTTON_RIGHTSTICK,
+    LeftShoulder  = ll::SDL_CONTROLLER_BUTTON_LEFTSHOULDER,
+    RightShoulder = ll::SDL_CONTROLLER_BUTTON_RIGHTSHOULDER,
+    DPadUp        = ll::SDL_CONTROLLER_BUTTON_DPAD_UP,
+    DPadDown      = ll::SDL_CONTROLLER_BUTTON_DPAD_DOWN,
+    DPadLeft      = ll::SDL_CONTROLLER_BUTTON_DPAD_LEFT,
+    DPadRight     = ll::SDL_CONTROLLER_BUTTON_DPAD_RIGHT,
+}
+
+impl Button {
+    /// Return the Button from a string description in the same format
+    /// used by the game controller mapping strings, like `"a"` or
+    /// `"dpup"`.
+    pub fn from_string(button: &str) -> Option<Button> {
+        let id = match CString::new(button) {
+            Ok(button) => unsafe { ll::SDL_GameControllerGetButtonFromString(button.as_ptr()) },
+            // string contains a nul byte - it won't match anything.
+            Err(_) => ll::SDL_CONTROLLER_BUTTON_INVALID
+        };
+
+        Button::from_ll(id)
+    }
+
+    /// Return a string for a given button in the same format using by
+    /// the game controller mapping strings
+    pub fn get_string(self) -> String {
+        let button = self as ll::SDL_GameControllerButton;
+
+        let string = unsafe { ll::SDL_GameControllerGetStringForButton(button) };
+
+        c_str_to_string(string)
+    }
+
+    pub fn from_ll(bitflags: ll::SDL_GameControllerButton) -> Option<Button> {
+        Some(match bitflags {
+            ll::SDL_CONTROLLER_BUTTON_INVALID       => return None,
+            ll::SDL_CONTROLLER_BUTTON_A             => Button::A,
+            ll::SDL_CONTROLLER_BUTTON_B             => Button::B,
+            ll::SDL_CONTROLLER_BUTTON_X             => Button::X,
+            ll::SDL_CONTROLLER_BUTTON_Y             => Button::Y,
+            ll::SDL_CONTROLLER_BUTTON_BACK          => Button::Back,
+            ll::SDL_CONTROLLER_BUTTON_GUIDE         => Button::Guide,
+            ll::SDL_CONTROLLER_BUTTON_START         => Button::Start,
+            ll::SDL_CONTROLLER_BUTTON_LEFTSTICK     => Button::LeftStick,
+            ll::SDL_CONTROLLER_BUTTON_RIGHTSTICK    => Button::RightStick,
+            ll::SDL_CONTROLLER_BUTTON_LEFTSHOULDER  => Button::LeftShoulder,
+            ll::SDL_CONTROLLER_BUTTON_RIGHTSHOULDER => Button::RightShoulder,
+            ll::SDL_CONTROLLER_BUTTON_DPAD_UP       => Button::DPadUp,
+            ll::SDL_CONTROLLER_BUTTON_DPAD_DOWN     => Button::DPadDown,
+            ll::SDL_CONTROLLER_BUTTON_DPAD_LEFT     => Button::DPadLeft,
+            ll::SDL_CONTROLLER_BUTTON_DPAD_RIGHT    => Button::DPadRight,
+            // Added by a newer version of SDL.
+            _ => return None
+        })
+    }
+}
+
+/// A motion sensor of a game controller.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SensorType {
+    /// Reports the acceleration along the x, y and z axes in m/s², gravity
+    /// included.
+    Accelerometer,
+    /// Reports the rotation around the x, y and z axes in radians per
+    /// second.
+    Gyroscope,
+    Unknown(i32)
+}
+
+impl SensorType {
+    pub fn from_ll(sensor: ll::SDL_SensorType) -> SensorType {
+        match sensor {
+            ll::SDL_SENSOR_ACCEL => SensorType::Accelerometer,
+            ll::SDL_SENSOR_GYRO => SensorType::Gyroscope,
+            _ => SensorType::Unknown(sensor)
+        }
+    }
+
+    pub fn to_ll(self) -> ll::SDL_SensorType {
+        match self {
+            SensorType::Accelerometer => ll::SDL_SENSOR_ACCEL,
+            SensorType::Gyroscope => ll::SDL_SENSOR_GYRO,
+            SensorType::Unknown(sensor) => sensor
+        }
+    }
+}
+
+impl fmt::Display for Axis {
+    /// Formats the axis with its name in mapping strings.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_string())
+    }
+}
+
+impl FromStr for Axis {
+    type Err = String;
+
+    fn from_str(axis: &str) -> Result<Axis, String> {
+        Axis::from_string(axis).ok_or_else(|| format!("Unknown controller axis \"{}\"", axis))
+    }
+}
+
+impl fmt::Display for Button {
+    /// Formats the button with its name in mapping strings.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_string())
+    }
+}
+
+impl FromStr for Button {
+    type Err = String;
+
+    fn from_str(button: &str) -> Result<Button, String> {
+        Button::from_string(button).ok_or_else(|| format!("Unknown controller button \"{}\"", button))
+    }
+}
+
+/// Return true if the joystick at index `id` is a game controller, i.e.
+/// if SDL has a mapping for it.
+pub fn is_game_controller(id: i32) -> bool {
+    unsafe { ll::SDL_IsGameController(id) != 0 }
+}
+
+/// Return the name of the controller at index `id`
+pub fn name_for_index(id: i32) -> SdlResult<String> {
+    let name = unsafe { ll::SDL_GameControllerNameForIndex(id) };
+
+    c_str_to_string_or_err(name)
+}
+
+/// Force controller update when not using the event loop
+///
+/// Reads the current state of all opened controllers, so that
+/// `GameController::get_axis()` and `get_button()` return fresh values.
+/// Pumping the event loop does this too, so this is only needed by
+/// programs that poll controllers without pumping events.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{self, Button, GameController};
+///
+/// let _sdl_context = sdl2::init().game_controller().unwrap();
+/// let controller = GameController::open(0).unwrap();
+///
+/// // Read the controller once per frame instead of handling events.
+/// controller::set_event_state(false);
+///
+/// loop {
+///     controller::update();
+///     if controller.get_button(Button::Start) {
+///         break;
+///     }
+/// }
+/// ```
+pub fn update() {
+    unsafe { ll::SDL_GameControllerUpdate() };
+}
+
+/// If state is `true` controller events are processed, otherwise
+/// they're ignored.
+///
+/// Ignored events are never added to the event queue. Polling programs can
+/// turn events off to keep the queue from filling up, and call `update()`
+/// once per frame instead. Events are on by default.
+pub fn set_event_state(state: bool) {
+    unsafe { ll::SDL_GameControllerEventState(state as i32) };
+}
+
+/// Return `true` if controller events are processed.
+pub fn get_event_state() -> bool {
+    unsafe { ll::SDL_GameControllerEventState(SDL_QUERY as i32)
+             == SDL_ENABLE as i32 }
+}
+
+/// Possible return values for `add_mapping`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MappingStatus {
+    Added   = 1,
+    Updated = 0,
+}
+
+/// Add a new mapping from a mapping string
+///
+/// A mapping string has the form `GUID,name,mapping`, where `mapping`
+/// lists the controller's buttons and axes like `a:b0,b:b1,leftx:a0`. An
+/// existing mapping for the same GUID is replaced.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{self, MappingStatus};
+///
+/// let _sdl_context = sdl2::init().game_controller().unwrap();
+/// let status = controller::add_mapping("0300000000f000000300000000010000,My Pad,\
+///                                       a:b0,b:b1,x:b2,y:b3,leftx:a0,lefty:a1").unwrap();
+/// assert!(status == MappingStatus::Added || status == MappingStatus::Updated);
+/// ```
+pub fn add_mapping(mapping: &str) -> SdlResult<MappingStatus> {
+    let mapping = try!(CString::new(mapping).unwrap_or_sdlresult());
+
+    let result = unsafe { ll::SDL_GameControllerAddMapping(mapping.as_ptr()) };
+
+    match result {
+        1 => Ok(MappingStatus::Added),
+        0 => Ok(MappingStatus::Updated),
+        _ => Err(get_error()),
+    }
+}
+
+/// Loads controller mappings from a file, like the community maintained
+/// `gamecontrollerdb.txt`, with one mapping string per line.
+///
+/// Mappings for other platforms than the current one are skipped. Returns
+/// the number of mappings that were added or updated.
+///
+/// # Example
+/// ```no_run
+/// let _sdl_context = sdl2::init().game_controller().unwrap();
+/// let count = sdl2::controller::load_mappings("gamecontrollerdb.txt").unwrap();
+/// println!("Loaded {} controller mappings", count);
+/// ```
+pub fn load_mappings<P: AsRef<Path>>(path: P) -> SdlResult<u32> {
+    let mut file = try!(RWops::from_file(path, "rb"));
+    load_mappings_from_rw(&mut file)
+}
+
+/// Loads controller mappings from the data source. See `load_mappings()`.
+pub fn load_mappings_from_rw(src: &mut RWops) -> SdlResult<u32> {
+    let result = unsafe { ll::SDL_GameControllerAddMappingsFromRW(src.raw(), 0) };
+
+    if result < 0 {
+        Err(get_error())
+    } else {
+        Ok(result as u32)
+    }
+}
+
+/// Return the mapping string for the joystick with the given GUID, or an
+/// error if there is no mapping for it.
+pub fn mapping_for_guid(guid: joystick::Guid) -> SdlResult<String> {
+    let c_str = unsafe { ll::SDL_GameControllerMappingForGUID(guid.raw()) };
+
+    let mapping = c_str_to_string_or_err(c_str);
+    free_c_str(c_str);
+    mapping
+}
+
+/// Wrapper around the SDL_GameController object
+pub struct GameController {
+    raw: *mut ll::SDL_GameController,
+}
+
+impl GameController {
+
+    /// Attempt to open the controller number `id` and return
+    /// it. Controller IDs are the same as joystick IDs and the
+    /// maximum number can be retreived using the
+    /// `joystick::num_joysticks()` function.
+    ///
+    /// Fails if the joystick isn't a game controller, see
+    /// `is_game_controller()`.
+    pub fn open(id: i32) -> SdlResult<GameController> {
+        let controller = unsafe { ll::SDL_GameControllerOpen(id) };
+
+        if controller.is_null() {
+            Err(get_error())
+        } else {
+            Ok(GameController { raw: controller })
+        }
+    }
+
+    /// Return the name of the controller or an empty string if no
+    /// name is found.
+    pub fn name(&self) -> String {
+        let name = unsafe { ll::SDL_GameControllerName(self.raw) };
+
+        c_str_to_string(name)
+    }
+
+    /// Return a String describing the controller's button and axis
+    /// mappings, in the format used by `add_mapping()`.
+    pub fn mapping(&self) -> String {
+        let c_str = unsafe { ll::SDL_GameControllerMapping(self.raw) };
+
+        let mapping = c_str_to_string(c_str);
+        free_c_str(c_str);
+        mapping
+    }
+
+    /// Return true if the controller has been opened and currently
+    /// connected.
+    pub fn get_attached(&self) -> bool {
+        unsafe { ll::SDL_GameControllerGetAttached(self.raw) != 0 }
+    }
+
+    /// Return the instance id of the joystick underlying this
+    /// controller.
+    ///
+    /// This is the id reported by the `which` field of controller
+    /// events, except for `ControllerDeviceAdded` which reports the
+    /// device index instead.
+    pub fn get_instance_id(&self) -> i32 {
+        let result = unsafe {
+            let joystick = ll::SDL_GameControllerGetJoystick(self.raw);
+            ::sys::joystick::SDL_JoystickInstanceID(joystick)
+        };
+
+        if result < 0 {
+            // Should only fail if the controller is NULL.
+            panic!("{}", get_error())
+        } else {
+            result
+        }
+    }
+
+    /// Return the GUID of the joystick underlying this controller.
+    pub fn get_guid(&self) -> joystick::Guid {
+        let raw = unsafe {
+            let joystick = ll::SDL_GameControllerGetJoystick(self.raw);
+            ::sys::joystick::SDL_JoystickGetGUID(joystick)
+        };
+
+        joystick::Guid::from_raw(raw)
+    }
+
+    /// Get the position of the given `axis`
+    ///
+    /// Stick axes range from -32768 to 32767, with positive values to
+    /// the right and down. Trigger axes range from 0 when released to
+    /// 32767 when fully pressed.
+    pub fn get_axis(&self, axis: Axis) -> i16 {
+        // This interface is a bit messed up: 0 is a valid position
+        // but can also mean that an error occured.
+        // Fortunately, an error can only occur if the controller pointer is NULL.
+        // There should be no apparent reason for this to change in the future.
+
+        let axis = axis as ll::SDL_GameControllerAxis;
+
+        unsafe { ll::SDL_GameControllerGetAxis(self.raw, axis) }
+    }
+
+    /// Get the position of the given `axis` between -1.0 and 1.0, or
+    /// between 0.0 and 1.0 for triggers. See `normalize_axis()`.
+    pub fn axis_f32(&self, axis: Axis) -> f32 {
+        normalize_axis(self.get_axis(axis))
+    }
+
+    /// Get the position of the left stick with `deadzone` applied.
+    pub fn left_stick(&self, deadzone: &Deadzone) -> (f32, f32) {
+        deadzone.apply_stick(self.axis_f32(Axis::LeftX), self.axis_f32(Axis::LeftY))
+    }
+
+    /// Get the position of the right stick with `deadzone` applied.
+    pub fn right_stick(&self, deadzone: &Deadzone) -> (f32, f32) {
+        deadzone.apply_stick(self.axis_f32(Axis::RightX), self.axis_f32(Axis::RightY))
+    }
+
+    /// Returns `true` if the trigger `axis` is pressed further than
+    /// `threshold`, between 0.0 and 1.0. Useful to treat triggers as
+    /// buttons.
+    pub fn is_trigger_pressed(&self, axis: Axis, threshold: f32) -> bool {
+        self.axis_f32(axis) > threshold
+    }
+
+    /// Returns `true` if `button` is pressed.
+    pub fn get_button(&self, button: Button) -> bool {
+        // This interface is a bit messed up: 0 is a valid position
+        // but can also mean that an error occured.
+        // Fortunately, an error can only occur if the controller pointer is NULL.
+        // There should be no apparent reason for this to change in the future.
+
+        let button = button as ll::SDL_GameControllerButton;
+
+        unsafe { ll::SDL_GameControllerGetButton(self.raw, button) != 0 }
+    }
+}
+
+impl GameController {
+    /// Return the USB vendor id of the controller, if known. See
+    /// `Joystick::vendor_id()`.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vendor_id(&self) -> Option<u16> {
+        let id = unsafe { ::sys::joystick::SDL_JoystickGetVendor(self.joystick()) };
+
+        if id == 0 { None } else { Some(id) }
+    }
+
+    /// Return the USB product id of the controller, if known.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn product_id(&self) -> Option<u16> {
+        let id = unsafe { ::sys::joystick::SDL_JoystickGetProduct(self.joystick()) };
+
+        if id == 0 { None } else { Some(id) }
+    }
+
+    /// Return the product version of the controller, if known.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn product_version(&self) -> Option<u16> {
+        let version = unsafe { ::sys::joystick::SDL_JoystickGetProductVersion(self.joystick()) };
+
+        if version == 0 { None } else { Some(version) }
+    }
+
+    /// Return the serial number of the controller, if it reports one.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn serial(&self) -> Option<String> {
+        let serial = unsafe { ::sys::joystick::SDL_JoystickGetSerial(self.joystick()) };
+
+        if serial.is_null() {
+            None
+        } else {
+            Some(c_str_to_string(serial))
+        }
+    }
+
+    /// Return `true` if the controller has a sensor of the given type.
+    ///
+    /// For a PlayStation 4 or Nintendo Switch style controller, the axes
+    /// of the sensors point right (x), up (y) and towards the player (z),
+    /// with the controller held flat.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn has_sensor(&self, sensor: SensorType) -> bool {
+        unsafe { ll::SDL_GameControllerHasSensor(self.raw, sensor.to_ll()) != 0 }
+    }
+
+    /// Turn a sensor on or off.
+    ///
+    /// Sensors are off by default, since they drain the battery of
+    /// wireless controllers. While on, the sensor reports
+    /// `Event::ControllerSensorUpdated` events.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn set_sensor_enabled(&mut self, sensor: SensorType, enabled: bool) -> SdlResult<()> {
+        let result = unsafe {
+            ll::SDL_GameControllerSetSensorEnabled(self.raw, sensor.to_ll(), enabled as ll::SDL_bool)
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Return `true` if the sensor is on.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn is_sensor_enabled(&self, sensor: SensorType) -> bool {
+        unsafe { ll::SDL_GameControllerIsSensorEnabled(self.raw, sensor.to_ll()) != 0 }
+    }
+
+    /// Get the latest x, y and z values of a sensor that is on.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn sensor_data(&self, sensor: SensorType) -> SdlResult<[f32; 3]> {
+        let mut data = [0.0; 3];
+        let result = unsafe {
+            ll::SDL_GameControllerGetSensorData(self.raw, sensor.to_ll(), data.as_mut_ptr(), 3)
+        };
+
+        if result == 0 {
+            Ok(data)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[cfg(feature = "sdl_2_0_6")]
+    fn joystick(&self) -> *mut ::sys::joystick::SDL_Joystick {
+        unsafe { ll::SDL_GameControllerGetJoystick(self.raw) }
+    }
+
+    pub fn raw(&self) -> *mut ll::SDL_GameController {
+        self.raw
+    }
+}
+
+impl Drop for GameController {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_GameControllerClose(self.raw) }
+    }
+}
+
+/// Convert C string `c_str` to a String. Return an empty string if
+/// c_str is NULL.
+fn c_str_to_string(c_str: *const c_char) -> String {
+    if c_str.is_null() {
+        String::new()
+    } else {
+        let bytes = unsafe { CStr::from_ptr(c_str).to_bytes() };
+
+        String::from_utf8_lossy(bytes).to_string()
+    }
+}
+
+/// Free a C string that was allocated by SDL. Does nothing if `c_str`
+/// is NULL.
+fn free_c_str(c_str: *mut c_char) {
+    if !c_str.is_null() {
+        unsafe { ::sys::event::SDL_free(c_str as *mut c_void) };
+    }
+}
+
+/// Convert C string `c_str` to a String. Return an SDL error if
+/// `c_str` is NULL.
+fn c_str_to_string_or_err(c_str: *const c_char) -> SdlResult<String> {
+    if c_str.is_null() {
+        Err(get_error())
+    } else {
+        let bytes = unsafe { CStr::from_ptr(c_str).to_bytes() };
+
+        Ok(String::from_utf8_lossy(bytes).to_string())
+    }
+}
@@ -0,0 +1,268 @@
+use event::Event;
+use joystick::Guid;
+
+use super::{Button, Change, Manager};
+
+/// A change to the players of `PlayerSlots`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PlayerEvent {
+    /// The controller with the given instance id joined as `player`.
+    Joined { player: usize, which: i32 },
+    /// The controller of `player` was unplugged. The slot is kept for it.
+    Disconnected { player: usize },
+    /// A controller of the same model was plugged back in for `player`.
+    Reconnected { player: usize, which: i32 },
+    /// `player` left the game and freed the slot.
+    Left { player: usize }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Slot {
+    guid: Guid,
+    /// `None` while the controller is unplugged.
+    which: Option<i32>
+}
+
+/// Assigns game controllers to numbered player slots for local
+/// multiplayer.
+///
+/// Controllers join by pressing the join button. When a controller is
+/// unplugged its slot is kept, and the next controller of the same model
+/// (the same GUID) that is plugged in takes it over, so players keep their
+/// number when a cable comes loose or a battery runs out.
+///
+/// Feed the slots the changes reported by a `Manager` and the events
+/// that contain button presses.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{Button, Manager, PlayerEvent, PlayerSlots};
+///
+/// let mut sdl_context = sdl2::init().game_controller().unwrap();
+/// let mut controllers = Manager::new();
+/// let mut players = PlayerSlots::new(4, Button::Start);
+///
+/// for event in sdl_context.event_pump().poll_iter() {
+///     if let Ok(Some(change)) = controllers.handle_event(&event) {
+///         players.handle_change(change, &controllers);
+///     }
+///
+///     if let Some(PlayerEvent::Joined { player, .. }) = players.handle_event(&event) {
+///         println!("Player {} joined", player + 1);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PlayerSlots {
+    slots: Vec<Option<Slot>>,
+    join_button: Button
+}
+
+impl PlayerSlots {
+    /// Creates `max_players` empty slots, which controllers join by
+    /// pressing `join_button`.
+    pub fn new(max_players: usize, join_button: Button) -> PlayerSlots {
+        PlayerSlots {
+            slots: vec![None; max_players],
+            join_button: join_button
+        }
+    }
+
+    /// Updates the slots when a controller was plugged in or out.
+    pub fn handle_change(&mut self, change: Change, controllers: &Manager) -> Option<PlayerEvent> {
+        match change {
+            Change::Connected(which) => {
+                let guid = match controllers.get(which) {
+                    Some(controller) => controller.get_guid(),
+                    None => return None
+                };
+
+                self.reconnect(which, guid)
+            },
+            Change::Disconnected(which) => self.disconnect(which)
+        }
+    }
+
+    /// Lets a controller join when its join button is pressed.
+    pub fn handle_event(&mut self, event: &Event) -> Option<PlayerEvent> {
+        match *event {
+            Event::ControllerButtonDown { which, button, .. } if button == self.join_button => {
+                self.join_controller(which)
+            },
+            _ => None
+        }
+    }
+
+    /// Assigns the controller with instance id `which` and the given
+    /// `guid` to the first free slot.
+    ///
+    /// Does nothing if the controller already has a slot or all slots are
+    /// taken.
+    pub fn join(&mut self, which: i32, guid: Guid) -> Option<PlayerEvent> {
+        if self.player_for(which).is_some() {
+            return None;
+        }
+
+        let player = self.slots.iter().position(|slot| slot.is_none())?;
+
+        self.slots[player] = Some(Slot {
+            guid: guid,
+            which: Some(which)
+        });
+        Some(PlayerEvent::Joined { player: player, which: which })
+    }
+
+    fn join_controller(&mut self, which: i32) -> Option<PlayerEvent> {
+        let guid = guid_for_instance(which)?;
+        self.join(which, guid)
+    }
+
+    /// Gives the slot kept for an unplugged controller of the same model
+    /// to the controller with instance id `which`.
+    pub fn reconnect(&mut self, which: i32, guid: Guid) -> Option<PlayerEvent> {
+        if self.player_for(which).is_some() {
+            return None;
+        }
+
+        for (player, slot) in self.slots.iter_mut().enumerate() {
+            if let Some(ref mut slot) = *slot {
+                if slot.which.is_none() && slot.guid == guid {
+                    slot.which = Some(which);
+                    return Some(PlayerEvent::Reconnected { player: player, which: which });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Keeps the slot of the controller with instance id `which` for when
+    /// it is plugged back in.
+    pub fn disconnect(&mut self, which: i32) -> Option<PlayerEvent> {
+        let player = self.player_for(which)?;
+
+        if let Some(ref mut slot) = self.slots[player] {
+            slot.which = None;
+        }
+        Some(PlayerEvent::Disconnected { player: player })
+    }
+
+    /// Frees the slot of `player`.
+    pub fn leave(&mut self, player: usize) -> Option<PlayerEvent> {
+        match self.slots.get_mut(player) {
+            Some(slot) if slot.is_some() => {
+                *slot = None;
+                Some(PlayerEvent::Left { player: player })
+            },
+            _ => None
+        }
+    }
+
+    /// Returns the player using the controller with instance id `which`.
+    pub fn player_for(&self, which: i32) -> Option<usize> {
+        self.slots.iter().position(|slot| {
+            match *slot {
+                Some(ref slot) => slot.which == Some(which),
+                None => false
+            }
+        })
+    }
+
+    /// Returns the instance id of the controller of `player`, if it is
+    /// plugged in.
+    pub fn controller_for(&self, player: usize) -> Option<i32> {
+        match self.slots.get(player) {
+            Some(Some(slot)) => slot.which,
+            _ => None
+        }
+    }
+
+    /// Tests if `player` has joined, whether its controller is plugged in
+    /// or not.
+    pub fn has_joined(&self, player: usize) -> bool {
+        match self.slots.get(player) {
+            Some(slot) => slot.is_some(),
+            None => false
+        }
+    }
+
+    /// The number of players that have joined.
+    pub fn num_players(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// The number of slots.
+    pub fn max_players(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Tests if all slots are taken.
+    pub fn is_full(&self) -> bool {
+        self.slots.iter().all(|slot| slot.is_some())
+    }
+}
+
+/// Looks up the GUID of the opened controller with instance id `which`.
+///
+/// Button events only come from opened controllers, so this works without
+/// the manager.
+#[cfg(feature = "sdl_2_0_4")]
+fn guid_for_instance(which: i32) -> Option<Guid> {
+    unsafe {
+        let joystick = ::sys::joystick::SDL_JoystickFromInstanceID(which);
+        if joystick.is_null() {
+            None
+        } else {
+            Some(Guid::from_raw(::sys::joystick::SDL_JoystickGetGUID(joystick)))
+        }
+    }
+}
+
+/// Looks up the GUID of the opened controller with instance id `which`.
+///
+/// Opening a joystick that is already open returns the same device, so
+/// the joysticks are searched by opening each one briefly.
+#[cfg(not(feature = "sdl_2_0_4"))]
+fn guid_for_instance(which: i32) -> Option<Guid> {
+    use joystick::{self, Joystick};
+
+    let count = joystick::num_joysticks().ok()?;
+    (0..count).filter_map(|index| Joystick::open(index).ok())
+        .find(|joystick| joystick.get_instance_id() == which)
+        .map(|joystick| joystick.get_guid())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PlayerEvent, PlayerSlots};
+    use controller::Button;
+    use joystick::Guid;
+    use sys::joystick::SDL_JoystickGUID;
+
+    fn guid(model: u8) -> Guid {
+        Guid::from_raw(SDL_JoystickGUID { data: [model; 16] })
+    }
+
+    #[test]
+    fn test_slots() {
+        let mut players = PlayerSlots::new(2, Button::Start);
+
+        assert_eq!(players.join(10, guid(1)), Some(PlayerEvent::Joined { player: 0, which: 10 }));
+        assert_eq!(players.join(10, guid(1)), None);
+        assert_eq!(players.join(11, guid(2)), Some(PlayerEvent::Joined { player: 1, which: 11 }));
+        assert!(players.is_full());
+        assert_eq!(players.join(12, guid(1)), None);
+
+        assert_eq!(players.disconnect(10), Some(PlayerEvent::Disconnected { player: 0 }));
+        assert_eq!(players.controller_for(0), None);
+        assert!(players.has_joined(0));
+
+        // Only a controller of the same model takes the slot over.
+        assert_eq!(players.reconnect(13, guid(2)), None);
+        assert_eq!(players.reconnect(14, guid(1)), Some(PlayerEvent::Reconnected { player: 0, which: 14 }));
+        assert_eq!(players.player_for(14), Some(0));
+
+        assert_eq!(players.leave(1), Some(PlayerEvent::Left { player: 1 }));
+        assert_eq!(players.num_players(), 1);
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+
+use event::Event;
+use joystick::{Guid, HatState, Joystick};
+
+use super::{Axis, Button};
+
+/// A button or axis of a game controller the `MappingWizard` asks for.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Target {
+    Button(Button),
+    Axis(Axis)
+}
+
+impl Target {
+    /// The name of the target in mapping strings, like `"a"` or `"leftx"`.
+    pub fn get_string(self) -> String {
+        match self {
+            Target::Button(button) => button.get_string(),
+            Target::Axis(axis) => axis.get_string()
+        }
+    }
+}
+
+/// An axis has to move this far from where it rests to count.
+const AXIS_THRESHOLD: i32 = 16384;
+
+/// A raw joystick input, in the format of mapping strings.
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Input {
+    Button(u8),
+    Hat(u8, u8),
+    Axis(u8),
+    HalfAxis(u8, bool)
+}
+
+impl Input {
+    fn to_mapping(&self) -> String {
+        match *self {
+            Input::Button(idx) => format!("b{}", idx),
+            Input::Hat(idx, mask) => format!("h{}.{}", idx, mask),
+            Input::Axis(idx) => format!("a{}", idx),
+            Input::HalfAxis(idx, positive) => format!("{}a{}", if positive { "+" } else { "-" }, idx)
+        }
+    }
+
+    fn uses_axis(&self, axis: u8) -> bool {
+        match *self {
+            Input::Axis(idx) | Input::HalfAxis(idx, _) => idx == axis,
+            _ => false
+        }
+    }
+}
+
+/// Walks the user through the buttons and axes of an unknown joystick to
+/// create a game controller mapping for it.
+///
+/// The wizard prompts for one `Target` at a time: the application shows
+/// the prompt, and the wizard binds whatever button, hat or axis of the
+/// joystick is used next. Targets the joystick doesn't have can be
+/// skipped. Once all targets are done, `mapping()` returns a mapping
+/// string for `controller::add_mapping()`.
+///
+/// Axes should be moved right or down when prompted for a stick, and
+/// pressed all the way for a trigger.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{self, MappingWizard};
+/// use sdl2::joystick::Joystick;
+///
+/// let mut sdl_context = sdl2::init().joystick().game_controller().unwrap();
+/// let joystick = Joystick::open(0).unwrap();
+/// let mut wizard = MappingWizard::for_joystick(&joystick);
+///
+/// while let Some(target) = wizard.current() {
+///     println!("Press {}", target.get_string());
+///
+///     let event = sdl_context.event_pump().wait_event();
+///     wizard.handle_event(&event);
+/// }
+///
+/// controller::add_mapping(&wizard.mapping().unwrap()).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct MappingWizard {
+    which: i32,
+    guid: Guid,
+    name: String,
+    targets: Vec<Target>,
+    current: usize,
+    bindings: Vec<(Target, Input)>,
+    /// An input that was just bound and has to be released before the
+    /// next one counts.
+    held: Option<Input>,
+    /// The resting position of each axis, sampled before prompting: analog
+    /// triggers rest at one end instead of the center. Axes that weren't
+    /// sampled rest at the center.
+    axis_rest: HashMap<u8, i16>
+}
+
+impl MappingWizard {
+    /// Creates a wizard for the opened `joystick`, prompting for all
+    /// buttons and axes of a game controller.
+    ///
+    /// The current positions of the joystick's axes are taken as their
+    /// resting positions, so the joystick shouldn't be touched until the
+    /// first prompt.
+    pub fn for_joystick(joystick: &Joystick) -> MappingWizard {
+        let mut wizard = MappingWizard::new(joystick.get_instance_id(), joystick.get_guid(), &joystick.name());
+        for axis in 0..joystick.get_num_axes() {
+            if let Ok(value) = joystick.get_axis(axis) {
+                wizard.set_axis_rest(axis as u8, value);
+            }
+        }
+        wizard
+    }
+
+    /// Creates a wizard for the joystick with instance id `which`, whose
+    /// mapping will be stored for `guid` and `name`.
+    pub fn new(which: i32, guid: Guid, name: &str) -> MappingWizard {
+        let targets = vec![
+            Target::Button(Button::A),
+            Target::Button(Button::B),
+            Target::Button(Button::X),
+            Target::Button(Button::Y),
+            Target::Button(Button::Back),
+            Target::Button(Button::Guide),
+            Target::Button(Button::Start),
+            Target::Button(Button::LeftStick),
+            Target::Button(Button::RightStick),
+            Target::Button(Button::LeftShoulder),
+            Target::Button(Button::RightShoulder),
+            Target::Button(Button::DPadUp),
+            Target::Button(Button::DPadDown),
+            Target::Button(Button::DPadLeft),
+            Target::Button(Button::DPadRight),
+            Target::Axis(Axis::LeftX),
+            Target::Axis(Axis::LeftY),
+            Target::Axis(Axis::RightX),
+            Target::Axis(Axis::RightY),
+            Target::Axis(Axis::TriggerLeft),
+            Target::Axis(Axis::TriggerRight)
+        ];
+
+        MappingWizard::with_targets(which, guid, name, targets)
+    }
+
+    /// Creates a wizard that prompts for the given targets, in order.
+    pub fn with_targets(which: i32, guid: Guid, name: &str, targets: Vec<Target>) -> MappingWizard {
+        MappingWizard {
+            which: which,
+            guid: guid,
+            // Commas separate the fields of mapping strings.
+            name: name.replace(',', " "),
+            targets: targets,
+            current: 0,
+            bindings: Vec::new(),
+            held: None,
+            axis_rest: HashMap::new()
+        }
+    }
+
+    /// Sets the resting position of an axis, e.g. -32768 for an analog
+    /// trigger. Axes rest at 0 unless set; `for_joystick()` sets all of them.
+    pub fn set_axis_rest(&mut self, axis_idx: u8, value: i16) {
+        self.axis_rest.insert(axis_idx, value);
+    }
+
+    /// Returns the target to prompt for, or `None` when all are done.
+    pub fn current(&self) -> Option<Target> {
+        self.targets.get(self.current).cloned()
+    }
+
+    /// Returns the number of targets done and the total number of
+    /// targets.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.current, self.targets.len())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.targets.len()
+    }
+
+    /// Binds the current target to the joystick input in `event`, if any.
+    ///
+    /// Returns `true` if the wizard moved on to the next target.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let target = match self.current() {
+            Some(target) => target,
+            None => return false
+        };
+
+        let input = match *event {
+            Event::JoyButtonDown { which, button_idx, .. } if which == self.which => {
+                Input::Button(button_idx)
+            },
+            Event::JoyButtonUp { which, button_idx, .. } if which == self.which => {
+                self.release(&Input::Button(button_idx));
+                return false;
+            },
+            Event::JoyHatMotion { which, hat_idx, state, .. } if which == self.which => {
+                if state == HatState::Centered {
+                    if let Some(Input::Hat(idx, _)) = self.held {
+                        if idx == hat_idx {
+                            self.held = None;
+                        }
+                    }
+                    return false;
+                }
+                Input::Hat(hat_idx, state.to_raw())
+            },
+            Event::JoyAxisMotion { which, axis_idx, value, .. } if which == self.which => {
+                let rest = self.axis_rest.get(&axis_idx).cloned().unwrap_or(0) as i32;
+                let offset = value as i32 - rest;
+
+                if offset.abs() < AXIS_THRESHOLD {
+                    if self.held.as_ref().map_or(false, |held| held.uses_axis(axis_idx)) {
+                        self.held = None;
+                    }
+                    return false;
+                }
+
+                match target {
+                    // A trigger on an axis that rests at the center only
+                    // uses half of it.
+                    Target::Axis(Axis::TriggerLeft) |
+                    Target::Axis(Axis::TriggerRight) if rest.abs() < AXIS_THRESHOLD => {
+                        Input::HalfAxis(axis_idx, offset > 0)
+                    },
+                    Target::Axis(_) => Input::Axis(axis_idx),
+                    // An axis used as a button, like an analog trigger.
+                    Target::Button(_) => Input::HalfAxis(axis_idx, offset > 0)
+                }
+            },
+            _ => return false
+        };
+
+        if self.held.is_some() || self.is_bound(&input) {
+            return false;
+        }
+
+        self.held = Some(input.clone());
+        self.bindings.push((target, input));
+        self.current += 1;
+        true
+    }
+
+    /// Leaves the current target unbound, for joysticks that don't have
+    /// it.
+    pub fn skip(&mut self) {
+        if !self.is_finished() {
+            self.current += 1;
+        }
+    }
+
+    /// Undoes the last binding or skip, to prompt for it again.
+    pub fn back(&mut self) {
+        if self.current > 0 {
+            self.current -= 1;
+            let target = self.targets[self.current];
+            self.bindings.retain(|&(t, _)| t != target);
+            self.held = None;
+        }
+    }
+
+    /// Starts over.
+    ///
+    /// The resting positions of the axes are kept.
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.bindings.clear();
+        self.held = None;
+    }
+
+    /// Returns the mapping string once all targets are done.
+    pub fn mapping(&self) -> Option<String> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        let mut mapping = format!("{},{},", self.guid, self.name);
+        for (target, input) in self.bindings.iter() {
+            mapping.push_str(&format!("{}:{},", target.get_string(), input.to_mapping()));
+        }
+
+        Some(mapping)
+    }
+
+    fn release(&mut self, input: &Input) {
+        if self.held.as_ref() == Some(input) {
+            self.held = None;
+        }
+    }
+
+    fn is_bound(&self, input: &Input) -> bool {
+        self.bindings.iter().any(|(_, bound)| {
+            match (bound, input) {
+                (Input::Axis(a), Input::Axis(b)) |
+                (Input::Axis(a), Input::HalfAxis(b, _)) |
+                (Input::HalfAxis(a, _), Input::Axis(b)) => a == b,
+                _ => bound == input
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MappingWizard, Target};
+    use controller::{Axis, Button};
+    use event::Event;
+    use joystick::Guid;
+    use sys::joystick::SDL_JoystickGUID;
+
+    #[test]
+    fn test_wizard() {
+        let guid = Guid::from_raw(SDL_JoystickGUID { data: [0; 16] });
+        let targets = vec![Target::Button(Button::A),
+                           Target::Button(Button::Start),
+                           Target::Axis(Axis::LeftX)];
+        let mut wizard = MappingWizard::with_targets(3, guid, "Pad, USB", targets);
+
+        assert!(wizard.handle_event(&Event::JoyButtonDown { timestamp: 0, which: 3, button_idx: 1 }));
+        // The same button can't be bound twice.
+        assert!(!wizard.handle_event(&Event::JoyButtonUp { timestamp: 0, which: 3, button_idx: 1 }));
+        assert!(!wizard.handle_event(&Event::JoyButtonDown { timestamp: 0, which: 3, button_idx: 1 }));
+        // Nor events of other joysticks.
+        assert!(!wizard.handle_event(&Event::JoyButtonDown { timestamp: 0, which: 4, button_idx: 2 }));
+
+        wizard.skip();
+        assert_eq!(wizard.current(), Some(Target::Axis(Axis::LeftX)));
+        assert!(!wizard.handle_event(&Event::JoyAxisMotion { timestamp: 0, which: 3, axis_idx: 0, value: 1000 }));
+        assert!(wizard.handle_event(&Event::JoyAxisMotion { timestamp: 0, which: 3, axis_idx: 0, value: 30000 }));
+
+        assert!(wizard.is_finished());
+        assert_eq!(wizard.mapping().unwrap(),
+                   format!("{},Pad  USB,a:b1,leftx:a0,", guid));
+    }
+
+    #[test]
+    fn test_wizard_trigger_as_button() {
+        let guid = Guid::from_raw(SDL_JoystickGUID { data: [0; 16] });
+        let targets = vec![Target::Button(Button::LeftShoulder),
+                           Target::Button(Button::RightShoulder)];
+        let mut wizard = MappingWizard::with_targets(3, guid, "Pad", targets);
+        let trigger = |value| Event::JoyAxisMotion { timestamp: 0, which: 3, axis_idx: 2, value: value };
+
+        // An analog trigger rests at -32768 and is pressed all the way.
+        wizard.set_axis_rest(2, -32768);
+        assert!(!wizard.handle_event(&trigger(-20000)));
+        assert!(wizard.handle_event(&trigger(32767)));
+
+        // Releasing it passes the center on its way back, which mustn't
+        // bind the next target.
+        assert!(!wizard.handle_event(&trigger(0)));
+        assert!(!wizard.handle_event(&trigger(-32768)));
+        assert_eq!(wizard.current(), Some(Target::Button(Button::RightShoulder)));
+
+        assert!(wizard.handle_event(&Event::JoyButtonDown { timestamp: 0, which: 3, button_idx: 5 }));
+        assert_eq!(wizard.mapping().unwrap(),
+                   format!("{},Pad,leftshoulder:+a2,rightshoulder:b5,", guid));
+    }
+
+    #[test]
+    fn test_wizard_triggers() {
+        let guid = Guid::from_raw(SDL_JoystickGUID { data: [0; 16] });
+        let targets = vec![Target::Axis(Axis::TriggerLeft),
+                           Target::Axis(Axis::TriggerRight)];
+        let mut wizard = MappingWizard::with_targets(3, guid, "Pad", targets);
+        let axis = |axis_idx, value| Event::JoyAxisMotion { timestamp: 0, which: 3, axis_idx: axis_idx, value: value };
+
+        // A trigger with an axis of its own uses all of it.
+        wizard.set_axis_rest(4, -32768);
+        assert!(wizard.handle_event(&axis(4, 32767)));
+        assert!(!wizard.handle_event(&axis(4, -32768)));
+
+        // Triggers sharing an axis that rests at the center use half of it.
+        assert!(wizard.handle_event(&axis(2, -32768)));
+        assert_eq!(wizard.mapping().unwrap(),
+                   format!("{},Pad,lefttrigger:a4,righttrigger:-a2,", guid));
+    }
+}
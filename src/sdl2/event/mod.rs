@@ -0,0 +1,2001 @@
+/*!
+Event Handling
+ */
+
+use std::cmp;
+use std::ffi::CStr;
+use std::mem;
+use libc::{c_char, c_int, c_void, uint32_t};
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+use libc::c_long;
+use num::FromPrimitive;
+use std::ptr;
+use std::slice;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use controller;
+use controller::{Axis, Button};
+#[cfg(feature = "sdl_2_0_14")]
+use controller::SensorType;
+use joystick;
+use joystick::HatState;
+use keyboard;
+use keyboard::Mod;
+use sys::keycode::SDL_Keymod;
+use keyboard::{Keycode, KeyboardState};
+use mouse;
+use mouse::{Mouse, MouseState, MouseWheelDirection};
+use keyboard::Scancode;
+use get_error;
+use SdlResult;
+use Sdl;
+
+use sys::event as ll;
+
+#[cfg(feature = "serde")]
+pub mod recorder;
+mod quit;
+mod router;
+
+pub use self::quit::{QuitRequest, QuitInterceptor};
+pub use self::router::Router;
+
+/// Types of events that can be delivered.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum EventType {
+    First = ll::SDL_FIRSTEVENT,
+
+    Quit = ll::SDL_QUIT,
+    AppTerminating = ll::SDL_APP_TERMINATING,
+    AppLowMemory = ll::SDL_APP_LOWMEMORY,
+    AppWillEnterBackground = ll::SDL_APP_WILLENTERBACKGROUND,
+    AppDidEnterBackground = ll::SDL_APP_DIDENTERBACKGROUND,
+    AppWillEnterForeground = ll::SDL_APP_WILLENTERFOREGROUND,
+    AppDidEnterForeground = ll::SDL_APP_DIDENTERFOREGROUND,
+
+    Window = ll::SDL_WINDOWEVENT,
+    SysWm = ll::SDL_SYSWMEVENT,
+
+    KeyDown = ll::SDL_KEYDOWN,
+    KeyUp = ll::SDL_KEYUP,
+    TextEditing = ll::SDL_TEXTEDITING,
+    TextInput = ll::SDL_TEXTINPUT,
+
+    MouseMotion = ll::SDL_MOUSEMOTION,
+    MouseButtonDown = ll::SDL_MOUSEBUTTONDOWN,
+    MouseButtonUp = ll::SDL_MOUSEBUTTONUP,
+    MouseWheel = ll::SDL_MOUSEWHEEL,
+
+    JoyAxisMotion = ll::SDL_JOYAXISMOTION,
+    JoyBallMotion = ll::SDL_JOYBALLMOTION,
+    JoyHatMotion = ll::SDL_JOYHATMOTION,
+    JoyButtonDown = ll::SDL_JOYBUTTONDOWN,
+    JoyButtonUp = ll::SDL_JOYBUTTONUP,
+    JoyDeviceAdded = ll::SDL_JOYDEVICEADDED,
+    JoyDeviceRemoved = ll::SDL_JOYDEVICEREMOVED,
+
+    ControllerAxisMotion = ll::SDL_CONTROLLERAXISMOTION,
+    ControllerButtonDown = ll::SDL_CONTROLLERBUTTONDOWN,
+    ControllerButtonUp = ll::SDL_CONTROLLERBUTTONUP,
+    ControllerDeviceAdded = ll::SDL_CONTROLLERDEVICEADDED,
+    ControllerDeviceRemoved = ll::SDL_CONTROLLERDEVICEREMOVED,
+    ControllerDeviceRemapped = ll::SDL_CONTROLLERDEVICEREMAPPED,
+    #[cfg(feature = "sdl_2_0_14")]
+    ControllerSensorUpdated = ll::SDL_CONTROLLERSENSORUPDATE,
+
+    FingerDown = ll::SDL_FINGERDOWN,
+    FingerUp = ll::SDL_FINGERUP,
+    FingerMotion = ll::SDL_FINGERMOTION,
+    DollarGesture = ll::SDL_DOLLARGESTURE,
+    DollarRecord = ll::SDL_DOLLARRECORD,
+    MultiGesture = ll::SDL_MULTIGESTURE,
+
+    ClipboardUpdate = ll::SDL_CLIPBOARDUPDATE,
+    DropFile = ll::SDL_DROPFILE,
+
+    AudioDeviceAdded = ll::SDL_AUDIODEVICEADDED,
+    AudioDeviceRemoved = ll::SDL_AUDIODEVICEREMOVED,
+
+    User = ll::SDL_USEREVENT,
+    Last = ll::SDL_LASTEVENT,
+}
+
+impl FromPrimitive for EventType {
+    fn from_i64(n: i64) -> Option<EventType> {
+        use self::EventType::*;
+
+        Some( match n as ll::SDL_EventType {
+            ll::SDL_FIRSTEVENT => First,
+
+            ll::SDL_QUIT => Quit,
+            ll::SDL_APP_TERMINATING => AppTerminating,
+            ll::SDL_APP_LOWMEMORY => AppLowMemory,
+            ll::SDL_APP_WILLENTERBACKGROUND => AppWillEnterBackground,
+            ll::SDL_APP_DIDENTERBACKGROUND => AppDidEnterBackground,
+            ll::SDL_APP_WILLENTERFOREGROUND => AppWillEnterForeground,
+            ll::SDL_APP_DIDENTERFOREGROUND => AppDidEnterForeground,
+
+            ll::SDL_WINDOWEVENT => Window,
+            ll::SDL_SYSWMEVENT => SysWm,
+
+            ll::SDL_KEYDOWN => KeyDown,
+            ll::SDL_KEYUP => KeyUp,
+            ll::SDL_TEXTEDITING => TextEditing,
+            ll::SDL_TEXTINPUT => TextInput,
+
+            ll::SDL_MOUSEMOTION => MouseMotion,
+            ll::SDL_MOUSEBUTTONDOWN => MouseButtonDown,
+            ll::SDL_MOUSEBUTTONUP => MouseButtonUp,
+            ll::SDL_MOUSEWHEEL => MouseWheel,
+
+            ll::SDL_JOYAXISMOTION => JoyAxisMotion,
+            ll::SDL_JOYBALLMOTION => JoyBallMotion,
+            ll::SDL_JOYHATMOTION => JoyHatMotion,
+            ll::SDL_JOYBUTTONDOWN => JoyButtonDown,
+            ll::SDL_JOYBUTTONUP => JoyButtonUp,
+            ll::SDL_JOYDEVICEADDED => JoyDeviceAdded,
+            ll::SDL_JOYDEVICEREMOVED => JoyDeviceRemoved,
+
+            ll::SDL_CONTROLLERAXISMOTION => ControllerAxisMotion,
+            ll::SDL_CONTROLLERBUTTONDOWN => ControllerButtonDown,
+            ll::SDL_CONTROLLERBUTTONUP => ControllerButtonUp,
+            ll::SDL_CONTROLLERDEVICEADDED => ControllerDeviceAdded,
+            ll::SDL_CONTROLLERDEVICEREMOVED => ControllerDeviceRemoved,
+            ll::SDL_CONTROLLERDEVICEREMAPPED => ControllerDeviceRemapped,
+            #[cfg(feature = "sdl_2_0_14")]
+            ll::SDL_CONTROLLERSENSORUPDATE => ControllerSensorUpdated,
+
+            ll::SDL_FINGERDOWN => FingerDown,
+            ll::SDL_FINGERUP => FingerUp,
+            ll::SDL_FINGERMOTION => FingerMotion,
+            ll::SDL_DOLLARGESTURE => DollarGesture,
+            ll::SDL_DOLLARRECORD => DollarRecord,
+            ll::SDL_MULTIGESTURE => MultiGesture,
+
+            ll::SDL_CLIPBOARDUPDATE => ClipboardUpdate,
+            ll::SDL_DROPFILE => DropFile,
+
+            ll::SDL_AUDIODEVICEADDED => AudioDeviceAdded,
+            ll::SDL_AUDIODEVICEREMOVED => AudioDeviceRemoved,
+
+            ll::SDL_USEREVENT => User,
+            ll::SDL_LASTEVENT => Last,
+
+            _ => return None,
+        })
+    }
+
+    fn from_u64(n: u64) -> Option<EventType> { FromPrimitive::from_i64(n as i64) }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// An enum of window events.
+pub enum WindowEvent {
+    None,
+    /// The window has been shown.
+    Shown,
+    /// The window has been hidden.
+    Hidden,
+    /// The window has been exposed and should be redrawn.
+    Exposed,
+    /// The window has been moved to `(x, y)`.
+    Moved(i32, i32),
+    /// The window has been resized to `(width, height)`.
+    ///
+    /// This event is always preceded by `SizeChanged`.
+    Resized(i32, i32),
+    /// The window size has changed to `(width, height)`, either as a
+    /// result of an API call or through the system or user changing the
+    /// window size.
+    SizeChanged(i32, i32),
+    /// The window has been minimized.
+    Minimized,
+    /// The window has been maximized.
+    Maximized,
+    /// The window has been restored to normal size and position.
+    Restored,
+    /// The window has gained mouse focus.
+    Enter,
+    /// The window has lost mouse focus.
+    Leave,
+    /// The window has gained keyboard focus.
+    FocusGained,
+    /// The window has lost keyboard focus.
+    FocusLost,
+    /// The window manager requests that the window be closed.
+    Close,
+}
+
+impl WindowEvent {
+    fn from_ll(id: u8, data1: i32, data2: i32) -> WindowEvent {
+        match id {
+            1  => WindowEvent::Shown,
+            2  => WindowEvent::Hidden,
+            3  => WindowEvent::Exposed,
+            4  => WindowEvent::Moved(data1, data2),
+            5  => WindowEvent::Resized(data1, data2),
+            6  => WindowEvent::SizeChanged(data1, data2),
+            7  => WindowEvent::Minimized,
+            8  => WindowEvent::Maximized,
+            9  => WindowEvent::Restored,
+            10 => WindowEvent::Enter,
+            11 => WindowEvent::Leave,
+            12 => WindowEvent::FocusGained,
+            13 => WindowEvent::FocusLost,
+            14 => WindowEvent::Close,
+            _  => WindowEvent::None
+        }
+    }
+
+    fn to_ll(self) -> (u8, i32, i32) {
+        match self {
+            WindowEvent::None              => (0, 0, 0),
+            WindowEvent::Shown             => (1, 0, 0),
+            WindowEvent::Hidden            => (2, 0, 0),
+            WindowEvent::Exposed           => (3, 0, 0),
+            WindowEvent::Moved(x, y)       => (4, x, y),
+            WindowEvent::Resized(w, h)     => (5, w, h),
+            WindowEvent::SizeChanged(w, h) => (6, w, h),
+            WindowEvent::Minimized         => (7, 0, 0),
+            WindowEvent::Maximized         => (8, 0, 0),
+            WindowEvent::Restored          => (9, 0, 0),
+            WindowEvent::Enter             => (10, 0, 0),
+            WindowEvent::Leave             => (11, 0, 0),
+            WindowEvent::FocusGained       => (12, 0, 0),
+            WindowEvent::FocusLost         => (13, 0, 0),
+            WindowEvent::Close             => (14, 0, 0),
+        }
+    }
+}
+
+/// A platform-specific window manager message.
+///
+/// Only the messages of the windowing system the crate is compiled for are
+/// decoded; see `Event::SysWm`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SysWmMessage {
+    /// A message received by the window procedure of a Windows window.
+    #[cfg(windows)]
+    Windows { hwnd: usize, msg: u32, wparam: usize, lparam: isize },
+
+    /// An X11 `XEvent`, as the raw words of the union.
+    #[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+    X11 { event: [c_long; 24] },
+
+    /// A message of a windowing system that isn't decoded, identified by
+    /// its `SDL_SYSWM_TYPE`.
+    Other { subsystem: i32 }
+}
+
+impl SysWmMessage {
+    unsafe fn from_ll(msg: *const ll::SDL_SysWMmsg) -> SysWmMessage {
+        use sys::syswm;
+
+        if msg.is_null() {
+            return SysWmMessage::Other { subsystem: syswm::SDL_SYSWM_UNKNOWN };
+        }
+
+        let msg = &*msg;
+
+        match msg.subsystem {
+            #[cfg(windows)]
+            syswm::SDL_SYSWM_WINDOWS => SysWmMessage::Windows {
+                hwnd: msg.msg.hwnd as usize,
+                msg: msg.msg.msg,
+                wparam: msg.msg.wParam,
+                lparam: msg.msg.lParam
+            },
+            #[cfg(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android"))))]
+            syswm::SDL_SYSWM_X11 => SysWmMessage::X11 {
+                event: msg.msg.event
+            },
+            subsystem => SysWmMessage::Other { subsystem: subsystem }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Different event types.
+pub enum Event {
+    Quit { timestamp: u32 },
+    AppTerminating { timestamp: u32 },
+    AppLowMemory { timestamp: u32 },
+    AppWillEnterBackground { timestamp: u32 },
+    AppDidEnterBackground { timestamp: u32 },
+    AppWillEnterForeground { timestamp: u32 },
+    AppDidEnterForeground { timestamp: u32 },
+
+    Window {
+        timestamp: u32 ,
+        window_id: u32,
+        win_event: WindowEvent
+    },
+    /// A platform-specific window manager message that SDL doesn't
+    /// translate into any other event.
+    ///
+    /// These are disabled by default; enable them with
+    /// `EventPump::enable_event(EventType::SysWm)`.
+    SysWm {
+        timestamp: u32,
+        msg: SysWmMessage
+    },
+
+    /// `repeat` is set if the key press was generated by keyboard
+    /// auto-repeat. See `EventPump::set_key_repeat_filtered()`.
+    KeyDown {
+        timestamp: u32 ,
+        window_id: u32,
+        keycode: Option<Keycode>,
+        scancode: Option<Scancode>,
+        keymod: Mod,
+        repeat: bool
+    },
+    KeyUp {
+        timestamp: u32 ,
+        window_id: u32,
+        keycode: Option<Keycode>,
+        scancode: Option<Scancode>,
+        keymod: Mod,
+        repeat: bool
+    },
+
+    /// An input method changed the text being composed.
+    ///
+    /// `start` is the position of the cursor and `length` the number of
+    /// selected characters after it, both counted in characters of `text`.
+    /// See `keyboard::Composition`.
+    TextEditing {
+        timestamp: u32,
+        window_id: u32,
+        text: String,
+        start: i32,
+        length: i32
+    },
+
+    /// Text was entered, either typed directly or committed by an input
+    /// method.
+    TextInput {
+        timestamp: u32,
+        window_id: u32,
+        text: String
+    },
+
+    MouseMotion {
+        timestamp: u32,
+        window_id: u32,
+        which: u32,
+        mousestate: MouseState,
+        x: i32,
+        y: i32,
+        xrel: i32,
+        yrel: i32
+    },
+
+    MouseButtonDown {
+        timestamp: u32,
+        window_id: u32,
+        which: u32,
+        mouse_btn: Mouse,
+        x: i32,
+        y: i32
+    },
+    MouseButtonUp {
+        timestamp: u32,
+        window_id: u32,
+        which: u32,
+        mouse_btn: Mouse,
+        x: i32,
+        y: i32
+    },
+
+    /// The mouse wheel was scrolled.
+    ///
+    /// `x` is the amount scrolled horizontally, positive to the right, and
+    /// `y` the amount scrolled vertically, positive away from the user. With
+    /// the `sdl_2_0_18` feature, `precise_x` and `precise_y` hold the same
+    /// amounts with fractional parts, as reported by smooth-scrolling
+    /// trackpads. `direction` is always `Normal` without the `sdl_2_0_4`
+    /// feature.
+    MouseWheel {
+        timestamp: u32,
+        window_id: u32,
+        which: u32,
+        x: i32,
+        y: i32,
+        direction: MouseWheelDirection,
+        #[cfg(feature = "sdl_2_0_18")]
+        precise_x: f32,
+        #[cfg(feature = "sdl_2_0_18")]
+        precise_y: f32
+    },
+
+    JoyAxisMotion {
+        timestamp: u32,
+        which: i32,
+        axis_idx: u8,
+        value: i16
+    },
+
+    JoyBallMotion {
+        timestamp: u32,
+        which: i32,
+        ball_idx: u8,
+        xrel: i16,
+        yrel: i16
+    },
+
+    JoyHatMotion {
+        timestamp: u32,
+        which: i32,
+        hat_idx: u8,
+        state: HatState
+    },
+
+    JoyButtonDown {
+        timestamp: u32,
+        which: i32,
+        button_idx: u8
+    },
+    JoyButtonUp {
+        timestamp: u32,
+        which: i32,
+        button_idx: u8
+    },
+
+    JoyDeviceAdded {
+        timestamp: u32,
+        which: i32
+    },
+    JoyDeviceRemoved {
+        timestamp: u32,
+        which: i32
+    },
+
+    /// `which` is the joystick instance id of the controller.
+    ControllerAxisMotion {
+        timestamp: u32,
+        which: i32,
+        axis: Axis,
+        value: i16
+    },
+
+    /// `which` is the joystick instance id of the controller.
+    ControllerButtonDown {
+        timestamp: u32,
+        which: i32,
+        button: Button
+    },
+    /// `which` is the joystick instance id of the controller.
+    ControllerButtonUp {
+        timestamp: u32,
+        which: i32,
+        button: Button
+    },
+
+    /// A controller was plugged in.
+    ///
+    /// `which` is the joystick device index, suitable for
+    /// `GameController::open()`.
+    ControllerDeviceAdded {
+        timestamp: u32,
+        which: i32
+    },
+    /// An opened controller was unplugged.
+    ///
+    /// `which` is the joystick instance id of the controller.
+    ControllerDeviceRemoved {
+        timestamp: u32,
+        which: i32
+    },
+    /// The mapping of an opened controller was updated.
+    ///
+    /// `which` is the joystick instance id of the controller.
+    ControllerDeviceRemapped {
+        timestamp: u32,
+        which: i32
+    },
+    /// A sensor that was turned on with
+    /// `GameController::set_sensor_enabled()` reported new `data`.
+    #[cfg(feature = "sdl_2_0_14")]
+    ControllerSensorUpdated {
+        timestamp: u32,
+        which: i32,
+        sensor: SensorType,
+        data: [f32; 3]
+    },
+
+    FingerDown {
+        timestamp: u32,
+        touch_id: i64,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+        dx: f32,
+        dy: f32,
+        pressure: f32
+    },
+    FingerUp {
+        timestamp: u32,
+        touch_id: i64,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+        dx: f32,
+        dy: f32,
+        pressure: f32
+    },
+    FingerMotion {
+        timestamp: u32,
+        touch_id: i64,
+        finger_id: i64,
+        x: f32,
+        y: f32,
+        dx: f32,
+        dy: f32,
+        pressure: f32
+    },
+
+    DollarGesture {
+        timestamp: u32,
+        touch_id: i64,
+        gesture_id: i64,
+        num_fingers: u32,
+        error: f32,
+        x: f32,
+        y: f32
+    },
+    DollarRecord {
+        timestamp: u32,
+        touch_id: i64,
+        gesture_id: i64,
+        num_fingers: u32,
+        error: f32,
+        x: f32,
+        y: f32
+    },
+
+    MultiGesture {
+        timestamp: u32,
+        touch_id: i64,
+        d_theta: f32,
+        d_dist: f32,
+        x: f32,
+        y: f32,
+        num_fingers: u16
+    },
+
+    ClipboardUpdate {
+        timestamp: u32
+    },
+
+    DropFile {
+        timestamp: u32,
+        filename: String
+    },
+
+    /// `which` is the device index, for use with
+    /// `audio::get_audio_device_name()`.
+    AudioDeviceAdded {
+        timestamp: u32,
+        which: u32,
+        iscapture: bool
+    },
+    /// `which` is the id of the opened device that was lost. Check it with
+    /// `AudioDevice::is_removed_event()`.
+    AudioDeviceRemoved {
+        timestamp: u32,
+        which: u32,
+        iscapture: bool
+    },
+
+    User {
+        timestamp: u32,
+        window_id: u32,
+        type_: u32,
+        code: i32
+    },
+
+    Unknown {
+        timestamp: u32,
+        type_: u32
+    }
+}
+
+impl ::std::fmt::Debug for Event {
+    fn fmt(&self, out: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        out.write_str(match *self {
+            Event::Quit{..} => "Event::Quit",
+            Event::AppTerminating{..} => "Event::AppTerminating",
+            Event::AppLowMemory{..} => "Event::AppLowMemory",
+            Event::AppWillEnterBackground{..} => "Event::AppWillEnterBackground",
+            Event::AppDidEnterBackground{..} => "Event::AppDidEnterBackground",
+            Event::AppWillEnterForeground{..} => "Event::AppWillEnterForeground",
+            Event::AppDidEnterForeground{..} => "Event::AppDidEnterForeground",
+            Event::Window{..} => "Event::Window",
+            Event::SysWm{..} => "Event::SysWm",
+            Event::KeyDown{..} => "Event::KeyDown",
+            Event::KeyUp{..} => "Event::KeyUp",
+            Event::TextEditing{..} => "Event::TextEditing",
+            Event::TextInput{..} => "Event::TextInput",
+            Event::MouseMotion{..} => "Event::MouseMotion",
+            Event::MouseButtonDown{..} => "Event::MouseButtonDown",
+            Event::MouseButtonUp{..} => "Event::MouseButtonUp",
+            Event::MouseWheel{..} => "Event::MouseWheel",
+            Event::JoyAxisMotion{..} => "Event::JoyAxisMotion",
+            Event::JoyBallMotion{..} => "Event::JoyBallMotion",
+            Event::JoyHatMotion{..} => "Event::JoyHatMotion",
+            Event::JoyButtonDown{..} => "Event::JoyButtonDown",
+            Event::JoyButtonUp{..} => "Event::JoyButtonUp",
+            Event::JoyDeviceAdded{..} => "Event::JoyDeviceAdded",
+            Event::JoyDeviceRemoved{..} => "Event::JoyDeviceRemoved",
+            Event::ControllerAxisMotion{..} => "Event::ControllerAxisMotion",
+            Event::ControllerButtonDown{..} => "Event::ControllerButtonDown",
+            Event::ControllerButtonUp{..} => "Event::ControllerButtonUp",
+            Event::ControllerDeviceAdded{..} => "Event::ControllerDeviceAdded",
+            Event::ControllerDeviceRemoved{..} => "Event::ControllerDeviceRemoved",
+            Event::ControllerDeviceRemapped{..} => "Event::ControllerDeviceRemapped",
+            #[cfg(feature = "sdl_2_0_14")]
+            Event::ControllerSensorUpdated{..} => "Event::ControllerSensorUpdated",
+            Event::FingerDown{..} => "Event::FingerDown",
+            Event::FingerUp{..} => "Event::FingerUp",
+            Event::FingerMotion{..} => "Event::FingerMotion",
+            Event::DollarGesture{..} => "Event::DollarGesture",
+            Event::DollarRecord{..} => "Event::DollarRecord",
+            Event::MultiGesture{..} => "Event::MultiGesture",
+            Event::ClipboardUpdate{..} => "Event::ClipboardUpdate",
+            Event::DropFile{..} => "Event::DropFile",
+            Event::AudioDeviceAdded{..} => "Event::AudioDeviceAdded",
+            Event::AudioDeviceRemoved{..} => "Event::AudioDeviceRemoved",
+            Event::User{..} => "Event::User",
+            Event::Unknown{..} => "Event::Unknown",
+        })
+    }
+}
+
+// TODO: Remove this when from_utf8 is updated in Rust
+impl Event {
+    /// Returns the time the event was created at, in milliseconds since SDL
+    /// was initialized.
+    pub fn timestamp(&self) -> u32 {
+        match *self {
+            Event::Quit { timestamp, .. } |
+            Event::AppTerminating { timestamp, .. } |
+            Event::AppLowMemory { timestamp, .. } |
+            Event::AppWillEnterBackground { timestamp, .. } |
+            Event::AppDidEnterBackground { timestamp, .. } |
+            Event::AppWillEnterForeground { timestamp, .. } |
+            Event::AppDidEnterForeground { timestamp, .. } |
+            Event::Window { timestamp, .. } |
+            Event::SysWm { timestamp, .. } |
+            Event::KeyDown { timestamp, .. } |
+            Event::KeyUp { timestamp, .. } |
+            Event::TextEditing { timestamp, .. } |
+            Event::TextInput { timestamp, .. } |
+            Event::MouseMotion { timestamp, .. } |
+            Event::MouseButtonDown { timestamp, .. } |
+            Event::MouseButtonUp { timestamp, .. } |
+            Event::MouseWheel { timestamp, .. } |
+            Event::JoyAxisMotion { timestamp, .. } |
+            Event::JoyBallMotion { timestamp, .. } |
+            Event::JoyHatMotion { timestamp, .. } |
+            Event::JoyButtonDown { timestamp, .. } |
+            Event::JoyButtonUp { timestamp, .. } |
+            Event::JoyDeviceAdded { timestamp, .. } |
+            Event::JoyDeviceRemoved { timestamp, .. } |
+            Event::ControllerAxisMotion { timestamp, .. } |
+            Event::ControllerButtonDown { timestamp, .. } |
+            Event::ControllerButtonUp { timestamp, .. } |
+            Event::ControllerDeviceAdded { timestamp, .. } |
+            Event::ControllerDeviceRemoved { timestamp, .. } |
+            Event::ControllerDeviceRemapped { timestamp, .. } |
+            Event::FingerDown { timestamp, .. } |
+            Event::FingerUp { timestamp, .. } |
+            Event::FingerMotion { timestamp, .. } |
+            Event::DollarGesture { timestamp, .. } |
+            Event::DollarRecord { timestamp, .. } |
+            Event::MultiGesture { timestamp, .. } |
+            Event::ClipboardUpdate { timestamp, .. } |
+            Event::DropFile { timestamp, .. } |
+            Event::AudioDeviceAdded { timestamp, .. } |
+            Event::AudioDeviceRemoved { timestamp, .. } |
+            Event::User { timestamp, .. } |
+            Event::Unknown { timestamp, .. } => timestamp,
+            #[cfg(feature = "sdl_2_0_14")]
+            Event::ControllerSensorUpdated { timestamp, .. } => timestamp
+        }
+    }
+
+    /// Returns the time the event was created at, as the time elapsed since
+    /// SDL was initialized.
+    ///
+    /// Use `EventPump::ticks_to_instant()` to compare it with an `Instant`.
+    pub fn timestamp_duration(&self) -> Duration {
+        Duration::from_millis(self.timestamp() as u64)
+    }
+
+    /// Returns the id of the window the event belongs to, if any.
+    ///
+    /// Window, keyboard, text, mouse and user events belong to a window.
+    /// The id is 0 if there was no window with keyboard or mouse focus.
+    pub fn window_id(&self) -> Option<u32> {
+        match *self {
+            Event::Window { window_id, .. } |
+            Event::KeyDown { window_id, .. } |
+            Event::KeyUp { window_id, .. } |
+            Event::TextEditing { window_id, .. } |
+            Event::TextInput { window_id, .. } |
+            Event::MouseMotion { window_id, .. } |
+            Event::MouseButtonDown { window_id, .. } |
+            Event::MouseButtonUp { window_id, .. } |
+            Event::MouseWheel { window_id, .. } |
+            Event::User { window_id, .. } => Some(window_id),
+            _ => None
+        }
+    }
+
+    /// Tests if this is a window event.
+    pub fn is_window(&self) -> bool {
+        match *self {
+            Event::Window {..} => true,
+            _ => false
+        }
+    }
+
+    /// Tests if this is a keyboard event, including text input and editing.
+    pub fn is_keyboard(&self) -> bool {
+        match *self {
+            Event::KeyDown {..} |
+            Event::KeyUp {..} |
+            Event::TextEditing {..} |
+            Event::TextInput {..} => true,
+            _ => false
+        }
+    }
+
+    /// Tests if this is a mouse event.
+    pub fn is_mouse(&self) -> bool {
+        match *self {
+            Event::MouseMotion {..} |
+            Event::MouseButtonDown {..} |
+            Event::MouseButtonUp {..} |
+            Event::MouseWheel {..} => true,
+            _ => false
+        }
+    }
+
+    /// Tests if this is a joystick event.
+    ///
+    /// Game controllers generate joystick events as well as controller
+    /// events.
+    pub fn is_joystick(&self) -> bool {
+        match *self {
+            Event::JoyAxisMotion {..} |
+            Event::JoyBallMotion {..} |
+            Event::JoyHatMotion {..} |
+            Event::JoyButtonDown {..} |
+            Event::JoyButtonUp {..} |
+            Event::JoyDeviceAdded {..} |
+            Event::JoyDeviceRemoved {..} => true,
+            _ => false
+        }
+    }
+
+    /// Tests if this is a game controller event.
+    pub fn is_controller(&self) -> bool {
+        match *self {
+            Event::ControllerAxisMotion {..} |
+            Event::ControllerButtonDown {..} |
+            Event::ControllerButtonUp {..} |
+            Event::ControllerDeviceAdded {..} |
+            Event::ControllerDeviceRemoved {..} |
+            Event::ControllerDeviceRemapped {..} => true,
+            #[cfg(feature = "sdl_2_0_14")]
+            Event::ControllerSensorUpdated {..} => true,
+            _ => false
+        }
+    }
+
+    fn to_ll(self) -> Option<ll::SDL_Event> {
+        use sys::keyboard::SDL_Keysym;
+
+        fn keysym(keycode: Option<Keycode>, scancode: Option<Scancode>, keymod: Mod) -> SDL_Keysym {
+            SDL_Keysym {
+                scancode: scancode.map_or(0, |s| s as u32),
+                sym: keycode.map_or(0, |k| k as i32),
+                _mod: keymod.bits() as u16,
+                unused: 0
+            }
+        }
+
+        fn text_to_ll(text: &str) -> [c_char; 32] {
+            let mut buf = [0; 32];
+
+            // Leave room for the nul terminator, and never split a UTF-8 sequence.
+            let mut len = cmp::min(text.len(), buf.len() - 1);
+            while !text.is_char_boundary(len) { len -= 1; }
+
+            for (dst, &src) in buf.iter_mut().zip(text.as_bytes()[..len].iter()) {
+                *dst = src as c_char;
+            }
+            buf
+        }
+
+        fn dollar(type_: u32, touch_id: i64, gesture_id: i64, num_fingers: u32, error: f32, x: f32, y: f32) -> ll::SDL_DollarGestureEvent {
+            ll::SDL_DollarGestureEvent {
+                type_: type_, timestamp: 0,
+                touchId: touch_id, gestureId: gesture_id,
+                numFingers: num_fingers, error: error, x: x, y: y
+            }
+        }
+
+        let mut ret: ll::SDL_Event = unsafe { mem::zeroed() };
+
+        // The timestamp is ignored; SDL stamps events as they are pushed.
+        unsafe { match self {
+            Event::Quit {..} => {
+                ptr::write(ret.quit(), ll::SDL_QuitEvent { type_: ll::SDL_QUIT, timestamp: 0 });
+            }
+            Event::AppTerminating {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_TERMINATING, timestamp: 0 });
+            }
+            Event::AppLowMemory {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_LOWMEMORY, timestamp: 0 });
+            }
+            Event::AppWillEnterBackground {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_WILLENTERBACKGROUND, timestamp: 0 });
+            }
+            Event::AppDidEnterBackground {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_DIDENTERBACKGROUND, timestamp: 0 });
+            }
+            Event::AppWillEnterForeground {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_WILLENTERFOREGROUND, timestamp: 0 });
+            }
+            Event::AppDidEnterForeground {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_APP_DIDENTERFOREGROUND, timestamp: 0 });
+            }
+
+            Event::Window { window_id, win_event, .. } => {
+                let (event, data1, data2) = win_event.to_ll();
+                ptr::write(ret.window(), ll::SDL_WindowEvent {
+                    type_: ll::SDL_WINDOWEVENT, timestamp: 0,
+                    windowID: window_id,
+                    event: event, padding1: 0, padding2: 0, padding3: 0,
+                    data1: data1, data2: data2
+                });
+            }
+
+            Event::KeyDown { window_id, keycode, scancode, keymod, repeat, .. } => {
+                ptr::write(ret.key(), ll::SDL_KeyboardEvent {
+                    type_: ll::SDL_KEYDOWN, timestamp: 0,
+                    windowID: window_id,
+                    state: 1, repeat: repeat as u8, padding2: 0, padding3: 0,
+                    keysym: keysym(keycode, scancode, keymod)
+                });
+            }
+            Event::KeyUp { window_id, keycode, scancode, keymod, repeat, .. } => {
+                ptr::write(ret.key(), ll::SDL_KeyboardEvent {
+                    type_: ll::SDL_KEYUP, timestamp: 0,
+                    windowID: window_id,
+                    state: 0, repeat: repeat as u8, padding2: 0, padding3: 0,
+                    keysym: keysym(keycode, scancode, keymod)
+                });
+            }
+
+            Event::TextEditing { window_id, text, start, length, .. } => {
+                ptr::write(ret.edit(), ll::SDL_TextEditingEvent {
+                    type_: ll::SDL_TEXTEDITING, timestamp: 0,
+                    windowID: window_id,
+                    text: text_to_ll(&text),
+                    start: start, length: length
+                });
+            }
+            Event::TextInput { window_id, text, .. } => {
+                ptr::write(ret.text(), ll::SDL_TextInputEvent {
+                    type_: ll::SDL_TEXTINPUT, timestamp: 0,
+                    windowID: window_id,
+                    text: text_to_ll(&text)
+                });
+            }
+
+            Event::MouseMotion { window_id, which, mousestate, x, y, xrel, yrel, .. } => {
+                ptr::write(ret.motion(), ll::SDL_MouseMotionEvent {
+                    type_: ll::SDL_MOUSEMOTION, timestamp: 0,
+                    windowID: window_id, which: which,
+                    state: mousestate.to_flags(),
+                    x: x, y: y, xrel: xrel, yrel: yrel
+                });
+            }
+            Event::MouseButtonDown { window_id, which, mouse_btn, x, y, .. } => {
+                ptr::write(ret.button(), ll::SDL_MouseButtonEvent {
+                    type_: ll::SDL_MOUSEBUTTONDOWN, timestamp: 0,
+                    windowID: window_id, which: which,
+                    button: mouse::unwrap_mouse(mouse_btn), state: 1, padding1: 0, padding2: 0,
+                    x: x, y: y
+                });
+            }
+            Event::MouseButtonUp { window_id, which, mouse_btn, x, y, .. } => {
+                ptr::write(ret.button(), ll::SDL_MouseButtonEvent {
+                    type_: ll::SDL_MOUSEBUTTONUP, timestamp: 0,
+                    windowID: window_id, which: which,
+                    button: mouse::unwrap_mouse(mouse_btn), state: 0, padding1: 0, padding2: 0,
+                    x: x, y: y
+                });
+            }
+            Event::MouseWheel {
+                window_id, which, x, y,
+                #[cfg(feature = "sdl_2_0_4")] direction,
+                #[cfg(feature = "sdl_2_0_18")] precise_x,
+                #[cfg(feature = "sdl_2_0_18")] precise_y,
+                ..
+            } => {
+                ptr::write(ret.wheel(), ll::SDL_MouseWheelEvent {
+                    type_: ll::SDL_MOUSEWHEEL, timestamp: 0,
+                    windowID: window_id, which: which,
+                    x: x, y: y,
+                    #[cfg(feature = "sdl_2_0_4")]
+                    direction: direction.to_ll(),
+                    #[cfg(feature = "sdl_2_0_18")]
+                    preciseX: precise_x,
+                    #[cfg(feature = "sdl_2_0_18")]
+                    preciseY: precise_y
+                });
+            }
+
+            Event::JoyAxisMotion { which, axis_idx, value, .. } => {
+                ptr::write(ret.jaxis(), ll::SDL_JoyAxisEvent {
+                    type_: ll::SDL_JOYAXISMOTION, timestamp: 0,
+                    which: which,
+                    axis: axis_idx, padding1: 0, padding2: 0, padding3: 0,
+                    value: value, padding4: 0
+                });
+            }
+            Event::JoyBallMotion { which, ball_idx, xrel, yrel, .. } => {
+                ptr::write(ret.jball(), ll::SDL_JoyBallEvent {
+                    type_: ll::SDL_JOYBALLMOTION, timestamp: 0,
+                    which: which,
+                    ball: ball_idx, padding1: 0, padding2: 0, padding3: 0,
+                    xrel: xrel, yrel: yrel
+                });
+            }
+            Event::JoyHatMotion { which, hat_idx, state, .. } => {
+                ptr::write(ret.jhat(), ll::SDL_JoyHatEvent {
+                    type_: ll::SDL_JOYHATMOTION, timestamp: 0,
+                    which: which,
+                    hat: hat_idx, value: state as u8, padding1: 0, padding2: 0
+                });
+            }
+            Event::JoyButtonDown { which, button_idx, .. } => {
+                ptr::write(ret.jbutton(), ll::SDL_JoyButtonEvent {
+                    type_: ll::SDL_JOYBUTTONDOWN, timestamp: 0,
+                    which: which,
+                    button: button_idx, state: 1, padding1: 0, padding2: 0
+                });
+            }
+            Event::JoyButtonUp { which, button_idx, .. } => {
+                ptr::write(ret.jbutton(), ll::SDL_JoyButtonEvent {
+                    type_: ll::SDL_JOYBUTTONUP, timestamp: 0,
+                    which: which,
+                    button: button_idx, state: 0, padding1: 0, padding2: 0
+                });
+            }
+            Event::JoyDeviceAdded { which, .. } => {
+                ptr::write(ret.jdevice(), ll::SDL_JoyDeviceEvent { type_: ll::SDL_JOYDEVICEADDED, timestamp: 0, which: which });
+            }
+            Event::JoyDeviceRemoved { which, .. } => {
+                ptr::write(ret.jdevice(), ll::SDL_JoyDeviceEvent { type_: ll::SDL_JOYDEVICEREMOVED, timestamp: 0, which: which });
+            }
+
+            Event::ControllerAxisMotion { which, axis, value, .. } => {
+                ptr::write(ret.caxis(), ll::SDL_ControllerAxisEvent {
+                    type_: ll::SDL_CONTROLLERAXISMOTION, timestamp: 0,
+                    which: which,
+                    axis: axis as u8, padding1: 0, padding2: 0, padding3: 0,
+                    value: value, padding4: 0
+                });
+            }
+            Event::ControllerButtonDown { which, button, .. } => {
+                ptr::write(ret.cbutton(), ll::SDL_ControllerButtonEvent {
+                    type_: ll::SDL_CONTROLLERBUTTONDOWN, timestamp: 0,
+                    which: which,
+                    button: button as u8, state: 1, padding1: 0, padding2: 0
+                });
+            }
+            Event::ControllerButtonUp { which, button, .. } => {
+                ptr::write(ret.cbutton(), ll::SDL_ControllerButtonEvent {
+                    type_: ll::SDL_CONTROLLERBUTTONUP, timestamp: 0,
+                    which: which,
+                    button: button as u8, state: 0, padding1: 0, padding2: 0
+                });
+            }
+            Event::ControllerDeviceAdded { which, .. } => {
+                ptr::write(ret.cdevice(), ll::SDL_ControllerDeviceEvent { type_: ll::SDL_CONTROLLERDEVICEADDED, timestamp: 0, which: which });
+            }
+            Event::ControllerDeviceRemoved { which, .. } => {
+                ptr::write(ret.cdevice(), ll::SDL_ControllerDeviceEvent { type_: ll::SDL_CONTROLLERDEVICEREMOVED, timestamp: 0, which: which });
+            }
+            Event::ControllerDeviceRemapped { which, .. } => {
+                ptr::write(ret.cdevice(), ll::SDL_ControllerDeviceEvent { type_: ll::SDL_CONTROLLERDEVICEREMAPPED, timestamp: 0, which: which });
+            }
+            #[cfg(feature = "sdl_2_0_14")]
+            Event::ControllerSensorUpdated { which, sensor, data, .. } => {
+                ptr::write(ret.csensor(), ll::SDL_ControllerSensorEvent {
+                    type_: ll::SDL_CONTROLLERSENSORUPDATE, timestamp: 0,
+                    which: which, sensor: sensor.to_ll(), data: data
+                });
+            }
+
+            Event::FingerDown { touch_id, finger_id, x, y, dx, dy, pressure, .. } |
+            Event::FingerUp { touch_id, finger_id, x, y, dx, dy, pressure, .. } |
+            Event::FingerMotion { touch_id, finger_id, x, y, dx, dy, pressure, .. } => {
+                let type_ = match self {
+                    Event::FingerDown {..} => ll::SDL_FINGERDOWN,
+                    Event::FingerUp {..} => ll::SDL_FINGERUP,
+                    _ => ll::SDL_FINGERMOTION
+                };
+                ptr::write(ret.tfinger(), ll::SDL_TouchFingerEvent {
+                    type_: type_, timestamp: 0,
+                    touchId: touch_id, fingerId: finger_id,
+                    x: x, y: y, dx: dx, dy: dy, pressure: pressure
+                });
+            }
+            Event::DollarGesture { touch_id, gesture_id, num_fingers, error, x, y, .. } => {
+                ptr::write(ret.dgesture(), dollar(ll::SDL_DOLLARGESTURE, touch_id, gesture_id, num_fingers, error, x, y));
+            }
+            Event::DollarRecord { touch_id, gesture_id, num_fingers, error, x, y, .. } => {
+                ptr::write(ret.dgesture(), dollar(ll::SDL_DOLLARRECORD, touch_id, gesture_id, num_fingers, error, x, y));
+            }
+            Event::MultiGesture { touch_id, d_theta, d_dist, x, y, num_fingers, .. } => {
+                ptr::write(ret.mgesture(), ll::SDL_MultiGestureEvent {
+                    type_: ll::SDL_MULTIGESTURE, timestamp: 0,
+                    touchId: touch_id,
+                    dTheta: d_theta, dDist: d_dist, x: x, y: y,
+                    numFingers: num_fingers, padding: 0
+                });
+            }
+
+            Event::ClipboardUpdate {..} => {
+                ptr::write(ret.common(), ll::SDL_CommonEvent { type_: ll::SDL_CLIPBOARDUPDATE, timestamp: 0 });
+            }
+
+            Event::AudioDeviceAdded { which, iscapture, .. } => {
+                ptr::write(ret.adevice(), ll::SDL_AudioDeviceEvent {
+                    type_: ll::SDL_AUDIODEVICEADDED, timestamp: 0,
+                    which: which, iscapture: iscapture as u8,
+                    padding1: 0, padding2: 0, padding3: 0
+                });
+            }
+            Event::AudioDeviceRemoved { which, iscapture, .. } => {
+                ptr::write(ret.adevice(), ll::SDL_AudioDeviceEvent {
+                    type_: ll::SDL_AUDIODEVICEREMOVED, timestamp: 0,
+                    which: which, iscapture: iscapture as u8,
+                    padding1: 0, padding2: 0, padding3: 0
+                });
+            }
+
+            Event::User { window_id, type_, code, .. } => {
+                ptr::write(ret.user(), ll::SDL_UserEvent {
+                    type_: type_ as uint32_t,
+                    timestamp: 0,
+                    windowID: window_id,
+                    code: code as i32,
+                    data1: ptr::null_mut(),
+                    data2: ptr::null_mut(),
+                });
+            }
+
+            // The message of a system event is owned by the windowing system,
+            // the file name of a drop event must be allocated by SDL,
+            // and unknown events have no known layout.
+            Event::SysWm {..} | Event::DropFile {..} | Event::Unknown {..} => {
+                return None
+            }
+        }}                      // close unsafe & match
+
+        Some(ret)
+    }
+
+    fn from_ll(mut raw: ll::SDL_Event) -> Event {
+        let raw_type = raw.type_();
+        let raw_type = if raw_type.is_null() {
+            panic!("Event payload is null")
+        } else {
+            unsafe { *raw_type }
+        };
+
+        // if event type has not been defined, treat it as a UserEvent
+        let event_type: EventType = FromPrimitive::from_usize(raw_type as usize).unwrap_or(EventType::User);
+        unsafe { match event_type {
+            EventType::Quit => {
+                let ref event = *raw.quit();
+                Event::Quit { timestamp: event.timestamp }
+            }
+            EventType::AppTerminating => {
+                let ref event = *raw.common();
+                Event::AppTerminating { timestamp: event.timestamp }
+            }
+            EventType::AppLowMemory => {
+                let ref event = *raw.common();
+                Event::AppLowMemory { timestamp: event.timestamp }
+            }
+            EventType::AppWillEnterBackground => {
+                let ref event = *raw.common();
+                Event::AppWillEnterBackground { timestamp: event.timestamp }
+            }
+            EventType::AppDidEnterBackground => {
+                let ref event = *raw.common();
+                Event::AppDidEnterBackground { timestamp: event.timestamp }
+            }
+            EventType::AppWillEnterForeground => {
+                let ref event = *raw.common();
+                Event::AppWillEnterForeground { timestamp: event.timestamp }
+            }
+            EventType::AppDidEnterForeground => {
+                let ref event = *raw.common();
+                Event::AppDidEnterForeground { timestamp: event.timestamp }
+            }
+
+            EventType::Window => {
+                let ref event = *raw.window();
+
+                Event::Window {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    win_event: WindowEvent::from_ll(event.event, event.data1, event.data2)
+                }
+            }
+            EventType::SysWm => {
+                let ref event = *raw.syswm();
+
+                Event::SysWm {
+                    timestamp: event.timestamp,
+                    msg: SysWmMessage::from_ll(event.msg)
+                }
+            }
+
+            EventType::KeyDown => {
+                let ref event = *raw.key();
+
+                Event::KeyDown {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    keycode: FromPrimitive::from_i32(event.keysym.sym),
+                    scancode: FromPrimitive::from_u32(event.keysym.scancode),
+                    keymod: keyboard::Mod::from_bits(event.keysym._mod as SDL_Keymod).unwrap(),
+                    repeat: event.repeat != 0
+                }
+            }
+            EventType::KeyUp => {
+                let ref event = *raw.key();
+
+                Event::KeyUp {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    keycode: FromPrimitive::from_i32(event.keysym.sym),
+                    scancode: FromPrimitive::from_u32(event.keysym.scancode),
+                    keymod: keyboard::Mod::from_bits(event.keysym._mod as SDL_Keymod).unwrap(),
+                    repeat: event.repeat != 0
+                }
+            }
+            EventType::TextEditing => {
+                let ref event = *raw.edit();
+
+                let text = text_from_ll(&event.text);
+                Event::TextEditing {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    text: text,
+                    start: event.start,
+                    length: event.length
+                }
+            }
+            EventType::TextInput => {
+                let ref event = *raw.text();
+
+                let text = text_from_ll(&event.text);
+                Event::TextInput {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    text: text
+                }
+            }
+
+            EventType::MouseMotion => {
+                let ref event = *raw.motion();
+
+                Event::MouseMotion {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    mousestate: mouse::MouseState::from_flags(event.state),
+                    x: event.x,
+                    y: event.y,
+                    xrel: event.xrel,
+                    yrel: event.yrel
+                }
+            }
+            EventType::MouseButtonDown => {
+                let ref event = *raw.button();
+
+                Event::MouseButtonDown {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    mouse_btn: mouse::wrap_mouse(event.button),
+                    x: event.x,
+                    y: event.y
+                }
+            }
+            EventType::MouseButtonUp => {
+                let ref event = *raw.button();
+
+                Event::MouseButtonUp {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    mouse_btn: mouse::wrap_mouse(event.button),
+                    x: event.x,
+                    y: event.y
+                }
+            }
+            EventType::MouseWheel => {
+                let ref event = *raw.wheel();
+
+                Event::MouseWheel {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    x: event.x,
+                    y: event.y,
+                    #[cfg(feature = "sdl_2_0_4")]
+                    direction: MouseWheelDirection::from_ll(event.direction),
+                    #[cfg(not(feature = "sdl_2_0_4"))]
+                    direction: MouseWheelDirection::Normal,
+                    #[cfg(feature = "sdl_2_0_18")]
+                    precise_x: event.preciseX,
+                    #[cfg(feature = "sdl_2_0_18")]
+                    precise_y: event.preciseY
+                }
+            }
+
+            EventType::JoyAxisMotion => {
+                let ref event = *raw.jaxis();
+                Event::JoyAxisMotion {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    axis_idx: event.axis,
+                    value: event.value
+                }
+            }
+            EventType::JoyBallMotion => {
+                let ref event = *raw.jball();
+                Event::JoyBallMotion {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    ball_idx: event.ball,
+                    xrel: event.xrel,
+                    yrel: event.yrel
+                }
+            }
+            EventType::JoyHatMotion => {
+                let ref event = *raw.jhat();
+                Event::JoyHatMotion {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    hat_idx: event.hat,
+                    state: joystick::HatState::from_raw(event.value),
+                }
+            }
+            EventType::JoyButtonDown => {
+                let ref event = *raw.jbutton();
+                Event::JoyButtonDown {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    button_idx: event.button
+                }
+            }
+            EventType::JoyButtonUp => {
+                let ref event = *raw.jbutton();
+                Event::JoyButtonUp {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    button_idx: event.button
+                }
+            }
+            EventType::JoyDeviceAdded => {
+                let ref event = *raw.jdevice();
+                Event::JoyDeviceAdded {
+                    timestamp: event.timestamp,
+                    which: event.which
+                }
+            }
+            EventType::JoyDeviceRemoved => {
+                let ref event = *raw.jdevice();
+                Event::JoyDeviceRemoved {
+                    timestamp: event.timestamp,
+                    which: event.which
+                }
+            }
+
+            EventType::ControllerAxisMotion => {
+                let ref event = *raw.caxis();
+                let axis = controller::Axis::from_ll(event.axis as ::sys::controller::SDL_GameControllerAxis).unwrap();
+
+                Event::ControllerAxisMotion {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    axis: axis,
+                    value: event.value
+                }
+            }
+            EventType::ControllerButtonDown => {
+                let ref event = *raw.cbutton();
+                let button = controller::Button::from_ll(event.button as ::sys::controller::SDL_GameControllerButton).unwrap();
+
+                Event::ControllerButtonDown {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    button: button
+                }
+            }
+            EventType::ControllerButtonUp => {
+                let ref event = *raw.cbutton();
+                let button = controller::Button::from_ll(event.button as ::sys::controller::SDL_GameControllerButton).unwrap();
+
+                Event::ControllerButtonUp {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    button: button
+                }
+            }
+            EventType::ControllerDeviceAdded => {
+                let ref event = *raw.cdevice();
+                Event::ControllerDeviceAdded {
+                    timestamp: event.timestamp,
+                    which: event.which
+                }
+            }
+            EventType::ControllerDeviceRemoved => {
+                let ref event = *raw.cdevice();
+                Event::ControllerDeviceRemoved {
+                    timestamp: event.timestamp,
+                    which: event.which
+                }
+            }
+            EventType::ControllerDeviceRemapped => {
+                let ref event = *raw.cdevice();
+                Event::ControllerDeviceRemapped {
+                    timestamp: event.timestamp,
+                    which: event.which
+                }
+            }
+            #[cfg(feature = "sdl_2_0_14")]
+            EventType::ControllerSensorUpdated => {
+                let ref event = *raw.csensor();
+                Event::ControllerSensorUpdated {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    sensor: controller::SensorType::from_ll(event.sensor),
+                    data: event.data
+                }
+            }
+
+            EventType::FingerDown => {
+                let ref event = *raw.tfinger();
+                Event::FingerDown {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    finger_id: event.fingerId,
+                    x: event.x,
+                    y: event.y,
+                    dx: event.dx,
+                    dy: event.dy,
+                    pressure: event.pressure
+                }
+            }
+            EventType::FingerUp => {
+                let ref event = *raw.tfinger();
+                Event::FingerUp {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    finger_id: event.fingerId,
+                    x: event.x,
+                    y: event.y,
+                    dx: event.dx,
+                    dy: event.dy,
+                    pressure: event.pressure
+                }
+            }
+            EventType::FingerMotion => {
+                let ref event = *raw.tfinger();
+                Event::FingerMotion {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    finger_id: event.fingerId,
+                    x: event.x,
+                    y: event.y,
+                    dx: event.dx,
+                    dy: event.dy,
+                    pressure: event.pressure
+                }
+            }
+            EventType::DollarGesture => {
+                let ref event = *raw.dgesture();
+                Event::DollarGesture {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    gesture_id: event.gestureId,
+                    num_fingers: event.numFingers,
+                    error: event.error,
+                    x: event.x,
+                    y: event.y
+                }
+            }
+            EventType::DollarRecord => {
+                let ref event = *raw.dgesture();
+                Event::DollarRecord {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    gesture_id: event.gestureId,
+                    num_fingers: event.numFingers,
+                    error: event.error,
+                    x: event.x,
+                    y: event.y
+                }
+            }
+            EventType::MultiGesture => {
+                let ref event = *raw.mgesture();
+                Event::MultiGesture {
+                    timestamp: event.timestamp,
+                    touch_id: event.touchId,
+                    d_theta: event.dTheta,
+                    d_dist: event.dDist,
+                    x: event.x,
+                    y: event.y,
+                    num_fingers: event.numFingers
+                }
+            }
+
+            EventType::ClipboardUpdate => {
+                let ref event = *raw.common();
+                Event::ClipboardUpdate {
+                    timestamp: event.timestamp
+                }
+            }
+            EventType::DropFile => {
+                let ref event = *raw.drop();
+
+                let buf = CStr::from_ptr(event.file).to_bytes();
+                let text = String::from_utf8_lossy(buf).into_owned();
+                ll::SDL_free(event.file as *mut c_void);
+
+                Event::DropFile {
+                    timestamp: event.timestamp,
+                    filename: text
+                }
+            }
+
+            EventType::AudioDeviceAdded => {
+                let ref event = *raw.adevice();
+                Event::AudioDeviceAdded {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    iscapture: event.iscapture != 0
+                }
+            }
+            EventType::AudioDeviceRemoved => {
+                let ref event = *raw.adevice();
+                Event::AudioDeviceRemoved {
+                    timestamp: event.timestamp,
+                    which: event.which,
+                    iscapture: event.iscapture != 0
+                }
+            }
+
+            EventType::First => panic!("Unused event, EventType::First, was encountered"),
+            EventType::Last => panic!("Unusable event, EventType::Last, was encountered"),
+
+            // If we have no other match and the event type is >= 32768
+            // this is a user event
+            EventType::User => {
+                if raw_type < 32768 {
+                    // The type is unknown to us.
+                    // It's a newer SDL2 type.
+                    let ref event = *raw.common();
+
+                    Event::Unknown {
+                        timestamp: event.timestamp,
+                        type_: event.type_
+                    }
+                } else {
+                    let ref event = *raw.user();
+
+                    Event::User {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        type_: raw_type,
+                        code: event.code
+                    }
+                }
+            }
+        }}                      // close unsafe & match
+    }
+}
+
+/// Converts a nul-terminated text buffer embedded in an event.
+///
+/// The bytes are decoded in place, so the returned `String` is the only
+/// allocation.
+fn text_from_ll(text: &[c_char]) -> String {
+    let text = unsafe { slice::from_raw_parts(text.as_ptr() as *const u8, text.len()) };
+    let len = text.iter().position(|&b| b == 0).unwrap_or(text.len());
+
+    String::from_utf8_lossy(&text[..len]).into_owned()
+}
+
+/// Whether consecutive mouse motion events are merged when polled.
+static COALESCE_MOUSE_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Merges the mouse motion events directly following `raw` in the queue into
+/// it, if mouse motion coalescing is enabled.
+///
+/// Only the front of the queue is ever looked at, so events are never
+/// reordered.
+unsafe fn coalesce_mouse_motion(mut raw: ll::SDL_Event) -> ll::SDL_Event {
+    if !COALESCE_MOUSE_MOTION.load(Ordering::Relaxed) || *raw.type_() != ll::SDL_MOUSEMOTION {
+        return raw;
+    }
+
+    let mut next: ll::SDL_Event = mem::zeroed();
+
+    loop {
+        let peeked = ll::SDL_PeepEvents(&mut next, 1, ll::SDL_PEEKEVENT,
+                                        ll::SDL_FIRSTEVENT, ll::SDL_LASTEVENT);
+        if peeked != 1 || *next.type_() != ll::SDL_MOUSEMOTION {
+            break;
+        }
+
+        {
+            let motion = &mut *raw.motion();
+            let next_motion = &*next.motion();
+
+            if next_motion.windowID != motion.windowID || next_motion.which != motion.which {
+                break;
+            }
+
+            motion.timestamp = next_motion.timestamp;
+            motion.state = next_motion.state;
+            motion.x = next_motion.x;
+            motion.y = next_motion.y;
+            motion.xrel += next_motion.xrel;
+            motion.yrel += next_motion.yrel;
+        }
+
+        // Remove the event that was just merged.
+        ll::SDL_PeepEvents(&mut next, 1, ll::SDL_GETEVENT,
+                           ll::SDL_MOUSEMOTION, ll::SDL_MOUSEMOTION);
+    }
+
+    raw
+}
+
+/// Whether key presses generated by keyboard auto-repeat are dropped when polled.
+static FILTER_KEY_REPEAT: AtomicBool = AtomicBool::new(false);
+
+unsafe fn is_filtered(raw: &mut ll::SDL_Event) -> bool {
+    FILTER_KEY_REPEAT.load(Ordering::Relaxed) &&
+        *raw.type_() == ll::SDL_KEYDOWN &&
+        (*raw.key()).repeat != 0
+}
+
+unsafe fn poll_event() -> Option<Event> {
+    let mut raw = mem::uninitialized();
+
+    loop {
+        let has_pending = ll::SDL_PollEvent(&mut raw) == 1;
+
+        if !has_pending { return None }
+        if !is_filtered(&mut raw) { return Some(Event::from_ll(coalesce_mouse_motion(raw))) }
+    }
+}
+
+unsafe fn wait_event() -> Event {
+    let mut raw = mem::uninitialized();
+
+    loop {
+        let success = ll::SDL_WaitEvent(&mut raw) == 1;
+
+        if !success { panic!(get_error()) }
+        if !is_filtered(&mut raw) { return Event::from_ll(coalesce_mouse_motion(raw)) }
+    }
+}
+
+unsafe fn wait_event_timeout(timeout: u32) -> Option<Event> {
+    use sys::timer::SDL_GetTicks;
+
+    let mut raw = mem::uninitialized();
+    let start = SDL_GetTicks();
+    let mut remaining = timeout;
+
+    loop {
+        let success = ll::SDL_WaitEventTimeout(&mut raw, remaining as c_int) == 1;
+
+        if !success { return None }
+        if !is_filtered(&mut raw) { return Some(Event::from_ll(coalesce_mouse_motion(raw))) }
+
+        // Only wait for what is left of the timeout after a filtered event.
+        let elapsed = SDL_GetTicks().wrapping_sub(start);
+        if elapsed >= timeout { return None }
+        remaining = timeout - elapsed;
+    }
+}
+
+/// A thread-safe type that encapsulates SDL event-pumping functions.
+pub struct EventPump<'sdl> {
+    _sdl:    PhantomData<&'sdl ()>,
+
+    // Prevents the event pump from moving to other threads.
+    // SDL events can only be pumped on the main thread.
+    _nosend: PhantomData<*mut ()>
+}
+impl<'sdl> EventPump<'sdl> {
+    /// Query if an event type is enabled.
+    pub fn is_event_enabled(&self, event_type: EventType) -> bool {
+        let result = unsafe { ll::SDL_EventState(event_type as u32, ll::SDL_QUERY) };
+
+        result != ll::SDL_DISABLE
+    }
+
+    /// Enable an event type. Returns if the event type was enabled before the call.
+    pub fn enable_event(&mut self, event_type: EventType) -> bool {
+        let result = unsafe { ll::SDL_EventState(event_type as u32, ll::SDL_ENABLE) };
+
+        result != ll::SDL_DISABLE
+    }
+
+    /// Disable an event type. Returns if the event type was enabled before the call.
+    pub fn disable_event(&mut self, event_type: EventType) -> bool {
+        let result = unsafe { ll::SDL_EventState(event_type as u32, ll::SDL_DISABLE) };
+
+        result != ll::SDL_DISABLE
+    }
+
+    /// Converts an SDL tick count, such as `Event::timestamp()`, to an
+    /// `Instant`.
+    ///
+    /// This makes it possible to measure input latency, e.g. with
+    /// `pump.ticks_to_instant(event.timestamp()).elapsed()`. Tick counts
+    /// have millisecond precision and wrap around after about 49 days, so
+    /// only recent tick counts convert correctly.
+    pub fn ticks_to_instant(&self, ticks: u32) -> Instant {
+        use sys::timer::SDL_GetTicks;
+
+        let now = Instant::now();
+        let age = unsafe { SDL_GetTicks() }.wrapping_sub(ticks);
+
+        now.checked_sub(Duration::from_millis(age as u64)).unwrap_or(now)
+    }
+
+    /// Query if consecutive mouse motion events are merged.
+    pub fn is_mouse_motion_coalesced(&self) -> bool {
+        COALESCE_MOUSE_MOTION.load(Ordering::Relaxed)
+    }
+
+    /// Sets whether consecutive mouse motion events are merged.
+    ///
+    /// When enabled, a run of `MouseMotion` events for the same window and
+    /// mouse is delivered as a single event. It carries the position, button
+    /// state and timestamp of the last event in the run and the sum of their
+    /// relative motions. Disabled by default.
+    ///
+    /// This affects `poll_event()`, `wait_event()`, `wait_event_timeout()`
+    /// and the iterators built on them, but not `peek_events()`.
+    pub fn set_mouse_motion_coalesced(&mut self, on: bool) {
+        COALESCE_MOUSE_MOTION.store(on, Ordering::Relaxed);
+    }
+
+    /// Query if key presses generated by keyboard auto-repeat are dropped.
+    pub fn is_key_repeat_filtered(&self) -> bool {
+        FILTER_KEY_REPEAT.load(Ordering::Relaxed)
+    }
+
+    /// Sets whether key presses generated by keyboard auto-repeat are dropped.
+    ///
+    /// When enabled, `KeyDown` events with `repeat` set are never returned,
+    /// so every `KeyDown` is matched by exactly one `KeyUp`. Disabled by
+    /// default.
+    ///
+    /// Like mouse motion coalescing, this affects the polling and waiting
+    /// functions of the event pump, but not `peek_events()`.
+    pub fn set_key_repeat_filtered(&mut self, on: bool) {
+        FILTER_KEY_REPEAT.store(on, Ordering::Relaxed);
+    }
+
+    /// Returns a snapshot of the keyboard state.
+    ///
+    /// The state reflects all events pumped so far. It stays the same as
+    /// long as it is borrowed, since the event pump can't be used meanwhile.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::keyboard::Scancode;
+    ///
+    /// let mut sdl_context = sdl2::init().video().unwrap();
+    /// let mut event_pump = sdl_context.event_pump();
+    ///
+    /// loop {
+    ///     event_pump.pump_events();
+    ///
+    ///     let keyboard = event_pump.keyboard_state();
+    ///     if keyboard.is_scancode_pressed(Scancode::Escape) {
+    ///         break;
+    ///     }
+    ///     for scancode in keyboard.pressed_scancodes() {
+    ///         println!("{:?} is held down", scancode);
+    ///     }
+    /// }
+    /// ```
+    pub fn keyboard_state(&self) -> KeyboardState<'_> {
+        KeyboardState::from_event_pump(self)
+    }
+
+    /// Returns the button state and the position of the mouse in the window
+    /// with mouse focus, as of the last time the event loop was pumped.
+    pub fn mouse_state(&self) -> (MouseState, i32, i32) {
+        mouse::get_mouse_state()
+    }
+
+    /// Returns the button state and the mouse motion accumulated since the
+    /// last call, as of the last time the event loop was pumped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let mut sdl_context = sdl2::init().video().unwrap();
+    /// let mut event_pump = sdl_context.event_pump();
+    ///
+    /// sdl2::mouse::set_relative_mouse_mode(true).unwrap();
+    ///
+    /// let (mut yaw, mut pitch) = (0.0, 0.0);
+    /// loop {
+    ///     event_pump.pump_events();
+    ///
+    ///     let (_, dx, dy) = event_pump.relative_mouse_state();
+    ///     yaw += dx as f32 * 0.1;
+    ///     pitch += dy as f32 * 0.1;
+    /// }
+    /// ```
+    pub fn relative_mouse_state(&mut self) -> (MouseState, i32, i32) {
+        mouse::get_relative_mouse_state()
+    }
+
+    /// Polls for currently pending events.
+    ///
+    /// If no events are pending, `None` is returned.
+    pub fn poll_event(&mut self) -> Option<Event> {
+        unsafe { poll_event() }
+    }
+
+    /// Returns a polling iterator that calls `poll_event()`.
+    /// The iterator will terminate once there are no more pending events.
+    ///
+    /// Each step pumps the event loop, so events that arrive while the loop
+    /// body runs are returned by the same iterator.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let mut sdl_context = sdl2::init().everything().unwrap();
+    ///
+    /// for event in sdl_context.event_pump().poll_iter() {
+    ///     use sdl2::event::Event;
+    ///     match event {
+    ///         Event::KeyDown {..} => { /*...*/ },
+    ///         _ => ()
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_iter(&mut self) -> EventPollIterator {
+        EventPollIterator {
+            _marker: PhantomData
+        }
+    }
+
+    /// Moves all pending events into `events`, replacing its contents.
+    ///
+    /// Unlike collecting `poll_iter()` into a new `Vec` every frame, the
+    /// buffer is reused, so once it has grown to fit a frame's worth of
+    /// events, polling does not allocate. The only events that allocate are
+    /// those carrying text (`TextEditing`, `TextInput` and `DropFile`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// let mut sdl_context = sdl2::init().everything().unwrap();
+    /// let mut events = Vec::new();
+    ///
+    /// loop {
+    ///     sdl_context.event_pump().poll_into(&mut events);
+    ///
+    ///     for event in events.iter() {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_into(&mut self, events: &mut Vec<Event>) {
+        events.clear();
+        events.extend(self.poll_iter());
+    }
+
+    /// Pumps the event loop, gathering events from the input devices.
+    pub fn pump_events(&mut self) {
+        unsafe { ll::SDL_PumpEvents(); };
+    }
+
+    /// Waits indefinitely for the next available event.
+    pub fn wait_event(&mut self) -> Event {
+        unsafe { wait_event() }
+    }
+
+    /// Waits until the specified timeout (in milliseconds) for the next available event.
+    pub fn wait_event_timeout(&mut self, timeout: u32) -> Option<Event> {
+        unsafe { wait_event_timeout(timeout) }
+    }
+
+    /// Returns a waiting iterator that calls `wait_event()`.
+    ///
+    /// Note: The iterator will never terminate.
+    ///
+    /// This suits applications that only need to redraw in response to
+    /// input, since the thread sleeps while there are no events.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let mut sdl_context = sdl2::init().everything().unwrap();
+    ///
+    /// for event in sdl_context.event_pump().wait_iter() {
+    ///     use sdl2::event::Event;
+    ///     match event {
+    ///         Event::Quit {..} => break,
+    ///         _ => { /* update and redraw */ }
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_iter(&mut self) -> EventWaitIterator {
+        EventWaitIterator {
+            _marker: PhantomData
+        }
+    }
+
+    /// Returns a waiting iterator that calls `wait_event_timeout()`.
+    ///
+    /// Note: The iterator will never terminate, unless waiting for an event
+    /// exceeds the specified timeout.
+    pub fn wait_timeout_iter(&mut self, timeout: u32) -> EventWaitTimeoutIterator {
+        EventWaitTimeoutIterator {
+            _marker: PhantomData,
+            timeout: timeout
+        }
+    }
+
+    /// Obtains the SDL event pump.
+    pub fn new(_sdl: &'sdl mut Sdl) -> EventPump<'sdl> {
+        // Called on the main SDL thread.
+        EventPump {
+            _sdl: PhantomData,
+            _nosend: PhantomData,
+        }
+    }
+}
+
+/// An iterator that calls `EventPump::poll_event()`.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EventPollIterator<'a> {
+    _marker: PhantomData<&'a ()>
+}
+
+impl<'a> Iterator for EventPollIterator<'a> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> { unsafe { poll_event() } }
+}
+
+/// An iterator that calls `EventPump::wait_event()`.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EventWaitIterator<'a> {
+    _marker: PhantomData<&'a ()>
+}
+
+impl<'a> Iterator for EventWaitIterator<'a> {
+    type Item = Event;
+    fn next(&mut self) -> Option<Event> { unsafe { Some(wait_event()) } }
+}
+
+/// An iterator that calls `EventPump::wait_event_timeout()`.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EventWaitTimeoutIterator<'a> {
+    _marker: PhantomData<&'a ()>,
+    timeout: u32
+}
+
+impl<'a> Iterator for EventWaitTimeoutIterator<'a> {
+    type Item = Event;
+    fn next(&mut self) -> Option<Event> { unsafe { wait_event_timeout(self.timeout) } }
+}
+
+/// Removes all events in the event queue that match the specified event type.
+pub fn flush_event(event_type: EventType) {
+    unsafe { ll::SDL_FlushEvent(event_type as uint32_t) };
+}
+
+/// Removes all events in the event queue that match the specified type range.
+pub fn flush_events(min_type: u32, max_type: u32) {
+    unsafe { ll::SDL_FlushEvents(min_type, max_type) };
+}
+
+/// Reads the events at the front of the event queue, until the maximum amount
+/// of events is read.
+///
+/// The events will _not_ be removed from the queue.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::event::{Event, peek_events};
+///
+/// // Read up to 1024 events
+/// let events: Vec<Event> = peek_events(1024);
+///
+/// // Print each one
+/// for event in events {
+///     println!("{:?}", event);
+/// }
+/// ```
+pub fn peek_events<B>(max_amount: u32) -> B
+where B: FromIterator<Event>
+{
+    unsafe {
+        let mut events = Vec::with_capacity(max_amount as usize);
+
+        let result = {
+            let events_ptr = events.as_mut_ptr();
+
+            ll::SDL_PeepEvents(
+                events_ptr,
+                max_amount as c_int,
+                ll::SDL_PEEKEVENT,
+                ll::SDL_FIRSTEVENT,
+                ll::SDL_LASTEVENT
+            )
+        };
+
+        if result < 0 {
+            // The only error possible is "Couldn't lock event queue"
+            panic!(get_error());
+        } else {
+            events.set_len(result as usize);
+
+            events.into_iter().map(|event_raw| {
+                Event::from_ll(event_raw)
+            }).collect()
+        }
+    }
+}
+
+/// Pushes an event to the event queue.
+///
+/// `Event::SysWm`, `Event::DropFile` and `Event::Unknown` cannot be pushed.
+pub fn push_event(event: Event) -> SdlResult<()> {
+    match event.to_ll() {
+        Some(mut raw_event) => {
+            let ok = unsafe { ll::SDL_PushEvent(&mut raw_event) == 1 };
+            if ok { Ok(()) }
+            else { Err(get_error()) }
+        },
+        None => {
+            Err(format!("Cannot push unsupported event type to the queue"))
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use libc::{c_int, c_void};
+use std::ptr;
+use std::sync::Mutex;
+
+use super::{EventPump, WindowEvent};
+use sys::event as ll;
+
+/// A request to quit, as seen by `EventPump::intercept_quit()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum QuitRequest {
+    /// The application is asked to quit (`Event::Quit`).
+    ///
+    /// SDL sends this when the last window is closed, when the user
+    /// chooses Quit from the application menu (Cmd-Q on Mac OS X), or when
+    /// the process receives SIGINT or SIGTERM.
+    Application,
+
+    /// The window with the given id is asked to close
+    /// (`WindowEvent::Close`).
+    Window(u32)
+}
+
+type QuitCallback = Mutex<Box<dyn FnMut(QuitRequest) -> bool + Send>>;
+
+/// Intercepts quit requests until it is dropped.
+///
+/// See `EventPump::intercept_quit()`.
+pub struct QuitInterceptor {
+    _callback: Box<QuitCallback>,
+    previous_filter: Option<ll::SDL_EventFilter>,
+    previous_userdata: *mut c_void
+}
+
+impl Drop for QuitInterceptor {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_SetEventFilter(self.previous_filter, self.previous_userdata) };
+    }
+}
+
+extern "C" fn c_quit_filter(userdata: *mut c_void, event: *mut ll::SDL_Event) -> c_int {
+    unsafe {
+        let event = &mut *event;
+
+        let request = match *event.type_() {
+            ll::SDL_QUIT => QuitRequest::Application,
+            ll::SDL_WINDOWEVENT => {
+                let window_event = &*event.window();
+
+                match WindowEvent::from_ll(window_event.event, 0, 0) {
+                    WindowEvent::Close => QuitRequest::Window(window_event.windowID),
+                    _ => return 1
+                }
+            },
+            _ => return 1
+        };
+
+        let callback = &*(userdata as *const QuitCallback);
+
+        // Never drop a quit request because of a poisoned lock.
+        match callback.lock() {
+            Ok(mut callback) => (*callback)(request) as c_int,
+            Err(_) => 1
+        }
+    }
+}
+
+impl<'sdl> EventPump<'sdl> {
+    /// Lets `confirm` decide whether quit requests reach the event queue.
+    ///
+    /// `confirm` is called with every `Event::Quit` and every
+    /// `WindowEvent::Close` before it is added to the queue. If it returns
+    /// `false`, the event is dropped, which makes it possible to ask the
+    /// user about unsaved changes first. All other events pass through
+    /// untouched.
+    ///
+    /// `confirm` may be called on any thread that pushes events, although
+    /// events from the operating system are usually pushed while the main
+    /// thread pumps the event loop.
+    ///
+    /// Interception stops when the returned value is dropped. Until then,
+    /// it replaces any event filter set with `SDL_SetEventFilter`, which no
+    /// longer sees any events; the previous filter is restored afterwards.
+    ///
+    /// Setting a filter also runs it over the events already queued: quit
+    /// requests waiting in the queue are passed to `confirm` right away and
+    /// removed if it returns `false`. Restoring the previous filter likewise
+    /// removes the queued events that it rejects.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::QuitRequest;
+    ///
+    /// let mut sdl_context = sdl2::init().video().unwrap();
+    /// let unsaved_changes = true;
+    ///
+    /// let _interceptor = sdl_context.event_pump().intercept_quit(move |request| {
+    ///     match request {
+    ///         QuitRequest::Application => !unsaved_changes,
+    ///         QuitRequest::Window(_) => true
+    ///     }
+    /// });
+    /// ```
+    pub fn intercept_quit<F>(&mut self, confirm: F) -> QuitInterceptor
+    where F: FnMut(QuitRequest) -> bool + Send + 'static
+    {
+        let callback: Box<QuitCallback> = Box::new(Mutex::new(Box::new(confirm)));
+        let mut previous_filter = None;
+        let mut previous_userdata = ptr::null_mut();
+
+        unsafe {
+            if ll::SDL_GetEventFilter(&mut previous_filter, &mut previous_userdata) == 0 {
+                previous_filter = None;
+                previous_userdata = ptr::null_mut();
+            }
+
+            let userdata = &*callback as *const QuitCallback as *mut c_void;
+            ll::SDL_SetEventFilter(Some(c_quit_filter), userdata);
+        }
+
+        QuitInterceptor {
+            _callback: callback,
+            previous_filter: previous_filter,
+            previous_userdata: previous_userdata
+        }
+    }
+}
@@ -0,0 +1,208 @@
+/*!
+Event recording and replay
+
+An `EventRecorder` captures the events of every frame and writes them out,
+one frame per line. An `EventPlayer` reads such a recording back and pushes
+each frame's events into the SDL event queue, so they come out of the
+`EventPump` frame by frame, in the order they were recorded. This is useful
+for reproducing bugs deterministically and for input-driven tests.
+
+Replayed events differ from the recorded ones in two ways: SDL gives them
+new timestamps when they are pushed, and `Event::SysWm`, `Event::DropFile`
+and `Event::Unknown` events are recorded but not replayed, since they can't
+be pushed (see `push_event()`).
+
+Requires the `serde` feature.
+
+# Example
+```no_run
+use sdl2::event::Event;
+use sdl2::event::recorder::{EventRecorder, EventPlayer};
+
+let mut sdl_context = sdl2::init().everything().unwrap();
+
+// Record a session...
+let mut recorder = EventRecorder::create("session.events").unwrap();
+
+'recording: loop {
+    for event in recorder.poll_frame(&mut sdl_context.event_pump()).unwrap() {
+        if let Event::Quit {..} = event { break 'recording }
+    }
+    // ...
+}
+
+// ...and play it back.
+let mut player = EventPlayer::open("session.events").unwrap();
+
+while player.replay_frame(&mut sdl_context.event_pump()).unwrap() {
+    for event in sdl_context.event_pump().poll_iter() {
+        // ...
+    }
+}
+```
+*/
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde_json;
+
+use super::{Event, EventPump, push_event};
+use SdlResult;
+
+/// Writes the events of each frame to a stream.
+pub struct EventRecorder<W: Write> {
+    writer: W
+}
+
+impl EventRecorder<BufWriter<File>> {
+    /// Creates a recorder that writes to the file at `path`, truncating it
+    /// if it already exists.
+    pub fn create<P: AsRef<Path>>(path: P) -> SdlResult<EventRecorder<BufWriter<File>>> {
+        match File::create(path) {
+            Ok(file) => Ok(EventRecorder::new(BufWriter::new(file))),
+            Err(e) => Err(format!("Could not create event recording: {}", e))
+        }
+    }
+}
+
+impl<W: Write> EventRecorder<W> {
+    /// Creates a recorder that writes to `writer`.
+    pub fn new(writer: W) -> EventRecorder<W> {
+        EventRecorder {
+            writer: writer
+        }
+    }
+
+    /// Records the events of a single frame.
+    ///
+    /// Frames without any events are recorded too, so that a replay keeps
+    /// the same timing relative to the frame count.
+    pub fn record_frame(&mut self, events: &[Event]) -> SdlResult<()> {
+        let line = match serde_json::to_string(events) {
+            Ok(line) => line,
+            Err(e) => return Err(format!("Could not serialize events: {}", e))
+        };
+
+        match writeln!(self.writer, "{}", line) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("Could not write event recording: {}", e))
+        }
+    }
+
+    /// Polls all pending events, records them as one frame and returns them.
+    pub fn poll_frame(&mut self, event_pump: &mut EventPump) -> SdlResult<Vec<Event>> {
+        let events: Vec<Event> = event_pump.poll_iter().collect();
+        try!(self.record_frame(&events));
+        Ok(events)
+    }
+
+    /// Flushes the recording and returns the underlying writer.
+    pub fn into_inner(mut self) -> SdlResult<W> {
+        match self.writer.flush() {
+            Ok(()) => Ok(self.writer),
+            Err(e) => Err(format!("Could not write event recording: {}", e))
+        }
+    }
+}
+
+/// Reads back the frames written by an `EventRecorder`.
+pub struct EventPlayer<R: BufRead> {
+    reader: R,
+    line: String
+}
+
+impl EventPlayer<BufReader<File>> {
+    /// Creates a player that reads the recording at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> SdlResult<EventPlayer<BufReader<File>>> {
+        match File::open(path) {
+            Ok(file) => Ok(EventPlayer::new(BufReader::new(file))),
+            Err(e) => Err(format!("Could not open event recording: {}", e))
+        }
+    }
+}
+
+impl<R: BufRead> EventPlayer<R> {
+    /// Creates a player that reads from `reader`.
+    pub fn new(reader: R) -> EventPlayer<R> {
+        EventPlayer {
+            reader: reader,
+            line: String::new()
+        }
+    }
+
+    /// Reads the events of the next recorded frame.
+    ///
+    /// Returns `None` once the end of the recording is reached.
+    pub fn next_frame(&mut self) -> SdlResult<Option<Vec<Event>>> {
+        self.line.clear();
+
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => Ok(None),
+            Ok(_) => match serde_json::from_str(&self.line) {
+                Ok(events) => Ok(Some(events)),
+                Err(e) => Err(format!("Could not deserialize events: {}", e))
+            },
+            Err(e) => Err(format!("Could not read event recording: {}", e))
+        }
+    }
+
+    /// Pushes the events of the next recorded frame to the event queue.
+    ///
+    /// Returns `false` once the end of the recording is reached.
+    /// Events that cannot be pushed (see `push_event()`) are skipped.
+    pub fn replay_frame(&mut self, _event_pump: &mut EventPump) -> SdlResult<bool> {
+        match try!(self.next_frame()) {
+            Some(events) => {
+                for event in events {
+                    match event {
+                        Event::SysWm {..} | Event::DropFile {..} | Event::Unknown {..} => (),
+                        event => try!(push_event(event))
+                    }
+                }
+                Ok(true)
+            },
+            None => Ok(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EventRecorder, EventPlayer};
+    use event::{Event, WindowEvent};
+    use keyboard::{Keycode, Scancode, LSHIFTMOD};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_record_and_read_back() {
+        let frames = vec![
+            vec![
+                Event::Window { timestamp: 1, window_id: 1, win_event: WindowEvent::Resized(640, 480) },
+                Event::KeyDown {
+                    timestamp: 2, window_id: 1,
+                    keycode: Some(Keycode::A), scancode: Some(Scancode::A),
+                    keymod: LSHIFTMOD, repeat: false
+                },
+            ],
+            vec![],
+            vec![
+                Event::TextInput { timestamp: 3, window_id: 1, text: "A".to_owned() },
+                Event::Quit { timestamp: 4 },
+            ],
+        ];
+
+        let mut recorder = EventRecorder::new(Vec::new());
+        for events in frames.iter() {
+            recorder.record_frame(events).unwrap();
+        }
+        let recording = recorder.into_inner().unwrap();
+
+        let mut player = EventPlayer::new(Cursor::new(recording));
+        for events in frames.iter() {
+            assert!(player.next_frame().unwrap().as_ref() == Some(events));
+        }
+        assert!(player.next_frame().unwrap().is_none());
+    }
+}
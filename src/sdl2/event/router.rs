@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use super::{Event, EventPump};
+
+/// Dispatches events to handlers registered per window.
+///
+/// Events that belong to a window (window, keyboard, text, mouse and user
+/// events) are passed to the handler registered for that window's id.
+/// Everything else, as well as events for windows without a handler, goes to
+/// the fallback handler, if there is one.
+///
+/// Keyboard and text events are reported for the window with keyboard focus,
+/// and mouse events for the window with mouse focus, which don't have to be
+/// the same window. Either can be 0 when no window has focus, in which case
+/// the event goes to the fallback handler.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::event::Router;
+///
+/// let mut sdl_context = sdl2::init().video().unwrap();
+/// let main_window = sdl_context.window("Main", 800, 600).build().unwrap();
+/// let tool_window = sdl_context.window("Tools", 200, 600).build().unwrap();
+///
+/// let mut router = Router::new();
+///
+/// router.add_handler(main_window.get_id(), |event| println!("main: {:?}", event));
+/// router.add_handler(tool_window.get_id(), |event| println!("tools: {:?}", event));
+/// router.set_fallback(|event| println!("other: {:?}", event));
+///
+/// router.dispatch_pending(&mut sdl_context.event_pump());
+/// ```
+pub struct Router<'a> {
+    handlers: HashMap<u32, Box<dyn FnMut(Event) + 'a>>,
+    fallback: Option<Box<dyn FnMut(Event) + 'a>>
+}
+
+impl<'a> Router<'a> {
+    /// Creates a router without any handlers.
+    pub fn new() -> Router<'a> {
+        Router {
+            handlers: HashMap::new(),
+            fallback: None
+        }
+    }
+
+    /// Registers the handler for the window identified by `window_id`,
+    /// replacing any previous handler for it.
+    pub fn add_handler<F: FnMut(Event) + 'a>(&mut self, window_id: u32, handler: F) {
+        self.handlers.insert(window_id, Box::new(handler));
+    }
+
+    /// Removes the handler for the window identified by `window_id`.
+    ///
+    /// Returns `false` if there was no handler for the window.
+    pub fn remove_handler(&mut self, window_id: u32) -> bool {
+        self.handlers.remove(&window_id).is_some()
+    }
+
+    /// Sets the handler for events that aren't routed to any window.
+    pub fn set_fallback<F: FnMut(Event) + 'a>(&mut self, handler: F) {
+        self.fallback = Some(Box::new(handler));
+    }
+
+    /// Passes `event` to the handler it is routed to.
+    ///
+    /// The event is dropped if it isn't routed to any handler.
+    pub fn dispatch(&mut self, event: Event) {
+        let handler = match event.window_id() {
+            Some(window_id) => self.handlers.get_mut(&window_id),
+            None => None
+        };
+
+        match handler {
+            Some(handler) => handler(event),
+            None => if let Some(ref mut fallback) = self.fallback {
+                fallback(event)
+            }
+        }
+    }
+
+    /// Polls all pending events and dispatches them.
+    pub fn dispatch_pending(&mut self, event_pump: &mut EventPump) {
+        for event in event_pump.poll_iter() {
+            self.dispatch(event);
+        }
+    }
+}
+
+impl<'a> Default for Router<'a> {
+    fn default() -> Router<'a> {
+        Router::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Router;
+    use event::{Event, WindowEvent};
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_dispatch() {
+        let routed = RefCell::new(Vec::new());
+
+        {
+            let mut router = Router::new();
+            router.add_handler(1, |event| routed.borrow_mut().push((1, event)));
+            router.add_handler(2, |event| routed.borrow_mut().push((2, event)));
+
+            // Events without a matching handler are dropped without a fallback.
+            router.dispatch(Event::Quit { timestamp: 0 });
+
+            router.set_fallback(|event| routed.borrow_mut().push((0, event)));
+            assert!(router.remove_handler(2));
+            assert!(!router.remove_handler(2));
+
+            router.dispatch(Event::Window { timestamp: 0, window_id: 1, win_event: WindowEvent::Close });
+            router.dispatch(Event::Window { timestamp: 0, window_id: 2, win_event: WindowEvent::Close });
+            router.dispatch(Event::Quit { timestamp: 0 });
+        }
+
+        assert!(routed.into_inner() == vec![
+            (1, Event::Window { timestamp: 0, window_id: 1, win_event: WindowEvent::Close }),
+            (0, Event::Window { timestamp: 0, window_id: 2, win_event: WindowEvent::Close }),
+            (0, Event::Quit { timestamp: 0 }),
+        ]);
+    }
+}
@@ -1,2 +1,87 @@
-#[allow(unused)]
+//! $1 gesture recording and template persistence.
+//!
+//! Recorded and recognized gestures are delivered through the event queue as
+//! `Event::DollarRecord` and `Event::DollarGesture`. Pinch and rotate motions
+//! are delivered as `Event::MultiGesture`.
+use get_error;
+use clear_error;
+use rwops::RWops;
+use touch::TouchDevice;
+use SdlResult;
+
 use sys::gesture as ll;
+
+pub type GestureId = ll::SDL_GestureID;
+
+/// Begins recording a $1 gesture template.
+///
+/// If `touch` is `None`, the gesture is recorded on all touch devices.
+/// Once the user completes the gesture, an `Event::DollarRecord` containing
+/// the new gesture id is pushed to the event queue.
+pub fn record_gesture(touch: Option<TouchDevice>) -> SdlResult<()> {
+    let touch_id = touch.unwrap_or(-1);
+    let result = unsafe { ll::SDL_RecordGesture(touch_id) };
+
+    if result == 1 {
+        Ok(())
+    } else {
+        Err(format!("Touch device {} was not found", touch_id))
+    }
+}
+
+/// Saves all currently loaded $1 gesture templates to `dst`.
+///
+/// Returns the number of templates that were written.
+pub fn save_all_dollar_templates(dst: &mut RWops) -> SdlResult<u32> {
+    // A return value of 0 means either "nothing to save" or an error.
+    // The only way to tell them apart is to check if an error was set.
+    clear_error();
+
+    let result = unsafe { ll::SDL_SaveAllDollarTemplates(dst.raw()) };
+
+    if result > 0 {
+        Ok(result as u32)
+    } else {
+        let err = get_error();
+
+        if err.is_empty() {
+            Ok(0)
+        } else {
+            Err(err)
+        }
+    }
+}
+
+/// Saves the $1 gesture template identified by `gesture_id` to `dst`.
+pub fn save_dollar_template(gesture_id: GestureId, dst: &mut RWops) -> SdlResult<()> {
+    let result = unsafe { ll::SDL_SaveDollarTemplate(gesture_id, dst.raw()) };
+
+    if result == 1 {
+        Ok(())
+    } else {
+        Err(format!("Could not save gesture template {}: {}", gesture_id, get_error()))
+    }
+}
+
+/// Loads $1 gesture templates from `src` and assigns them to a touch device.
+///
+/// If `touch` is `None`, the templates are loaded for all touch devices.
+/// Returns the number of templates that were loaded.
+pub fn load_dollar_templates(touch: Option<TouchDevice>, src: &mut RWops) -> SdlResult<u32> {
+    clear_error();
+
+    let touch_id = touch.unwrap_or(-1);
+    let result = unsafe { ll::SDL_LoadDollarTemplates(touch_id, src.raw()) };
+
+    if result > 0 {
+        Ok(result as u32)
+    } else {
+        let err = get_error();
+
+        if err.is_empty() {
+            Ok(0)
+        } else {
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! A glium backend for SDL windows, enabled with the `glium` feature.
+//!
+//! # Example
+//! ```ignore
+//! use glium::Surface;
+//! use sdl2::glium_backend::GliumDisplay;
+//!
+//! let mut sdl_context = sdl2::init().video().unwrap();
+//! let window = sdl_context.window("glium", 800, 600).opengl().build().unwrap();
+//! let display = GliumDisplay::new(window).unwrap();
+//!
+//! 'running: loop {
+//!     for event in sdl_context.event_pump().poll_iter() {
+//!         if let sdl2::event::Event::Quit { .. } = event {
+//!             break 'running;
+//!         }
+//!     }
+//!
+//!     let mut frame = display.draw();
+//!     frame.clear_color(0.0, 0.0, 1.0, 1.0);
+//!     frame.finish().unwrap();
+//! }
+//! ```
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::os::raw::c_void;
+use std::rc::Rc;
+
+use glium::backend::{Backend, Context, Facade};
+use glium::debug::DebugCallbackBehavior;
+use glium::{Frame, SwapBuffersError};
+
+use video::{gl_get_proc_address, GLContext, Window};
+use SdlResult;
+
+/// An SDL window and its OpenGL context, implementing glium's `Backend`.
+pub struct GliumBackend {
+    // Fields are dropped in order: the context has to be deleted while its
+    // window still exists.
+    context: GLContext,
+    window: RefCell<Window>
+}
+
+impl GliumBackend {
+    /// Creates an OpenGL context for the window, which must have been built
+    /// with `WindowBuilder::opengl()`.
+    pub fn new(window: Window) -> SdlResult<GliumBackend> {
+        let context = try!(window.gl_create_context());
+
+        Ok(GliumBackend {
+            context: context,
+            window: RefCell::new(window)
+        })
+    }
+
+    pub fn window(&self) -> Ref<Window> {
+        self.window.borrow()
+    }
+
+    pub fn window_mut(&self) -> RefMut<Window> {
+        self.window.borrow_mut()
+    }
+}
+
+unsafe impl Backend for GliumBackend {
+    fn swap_buffers(&self) -> Result<(), SwapBuffersError> {
+        self.window.borrow().gl_swap_window();
+        Ok(())
+    }
+
+    unsafe fn get_proc_address(&self, symbol: &str) -> *const c_void {
+        gl_get_proc_address(symbol) as *const c_void
+    }
+
+    fn get_framebuffer_dimensions(&self) -> (u32, u32) {
+        let (width, height) = self.window.borrow().properties_getters().get_drawable_size();
+        (width as u32, height as u32)
+    }
+
+    fn is_current(&self) -> bool {
+        self.context.is_current()
+    }
+
+    unsafe fn make_current(&self) {
+        self.window.borrow().gl_make_current(&self.context).unwrap()
+    }
+}
+
+/// A glium facade for an SDL window; the counterpart of `glium::Display`.
+#[derive(Clone)]
+pub struct GliumDisplay {
+    context: Rc<Context>,
+    backend: Rc<GliumBackend>
+}
+
+impl GliumDisplay {
+    /// Creates an OpenGL context for the window and sets up glium with it.
+    ///
+    /// The window must have been built with `WindowBuilder::opengl()`. Use
+    /// `gl_attr` beforehand to choose the OpenGL version.
+    pub fn new(window: Window) -> SdlResult<GliumDisplay> {
+        let backend = Rc::new(try!(GliumBackend::new(window)));
+        let context = try!(unsafe {
+            Context::new(backend.clone(), true, DebugCallbackBehavior::default())
+        }.map_err(|e| e.to_string()));
+
+        Ok(GliumDisplay {
+            context: context,
+            backend: backend
+        })
+    }
+
+    pub fn window(&self) -> Ref<Window> {
+        self.backend.window()
+    }
+
+    /// Gives mutable access to the window, e.g. for `Window::properties()`.
+    ///
+    /// Panics if the window is already borrowed.
+    pub fn window_mut(&self) -> RefMut<Window> {
+        self.backend.window_mut()
+    }
+
+    /// Starts drawing a frame; the buffers are swapped when it's finished.
+    pub fn draw(&self) -> Frame {
+        Frame::new(self.context.clone(), self.backend.get_framebuffer_dimensions())
+    }
+}
+
+impl Facade for GliumDisplay {
+    fn get_context(&self) -> &Rc<Context> {
+        &self.context
+    }
+}
@@ -1,3 +0,0 @@
-//! Haptic Functions
-#[allow(unused)]
-use sys::haptic as ll;
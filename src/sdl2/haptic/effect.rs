@@ -0,0 +1,312 @@
+use std::mem;
+
+use sys::haptic as ll;
+
+use super::{Features, INFINITY};
+use super::{CONSTANT, SINE, TRIANGLE, SAWTOOTHUP, SAWTOOTHDOWN, RAMP, LEFTRIGHT};
+
+/// The direction a force comes from.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Direction {
+    /// An angle in hundredths of a degree, clockwise from north: 0 is
+    /// north (away from the user), 9000 is east and 18000 is south.
+    Polar(i32),
+    /// A direction vector (x, y, z). Positive x points east, positive y
+    /// south and positive z up; only the direction matters, not the length.
+    Cartesian(i32, i32, i32),
+    /// Two angles in hundredths of a degree: the rotation around the z
+    /// axis, starting east, and the elevation.
+    Spherical(i32, i32)
+}
+
+impl Direction {
+    fn to_ll(self) -> ll::SDL_HapticDirection {
+        let (type_, dir) = match self {
+            Direction::Polar(angle) => (ll::SDL_HAPTIC_POLAR, [angle, 0, 0]),
+            Direction::Cartesian(x, y, z) => (ll::SDL_HAPTIC_CARTESIAN, [x, y, z]),
+            Direction::Spherical(azimuth, elevation) => (ll::SDL_HAPTIC_SPHERICAL, [azimuth, elevation, 0])
+        };
+
+        ll::SDL_HapticDirection {
+            type_: type_,
+            dir: dir
+        }
+    }
+}
+
+/// The shape of a periodic effect.
+///
+/// There is no square wave: SDL 2 dropped `SDL_HAPTIC_SQUARE` and reused
+/// its bit for `SDL_HAPTIC_LEFTRIGHT`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Waveform {
+    Sine,
+    Triangle,
+    SawtoothUp,
+    SawtoothDown
+}
+
+impl Waveform {
+    fn feature(self) -> Features {
+        match self {
+            Waveform::Sine => SINE,
+            Waveform::Triangle => TRIANGLE,
+            Waveform::SawtoothUp => SAWTOOTHUP,
+            Waveform::SawtoothDown => SAWTOOTHDOWN
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Kind {
+    Constant { level: i16 },
+    Periodic { waveform: Waveform, period: u16, magnitude: i16, offset: i16, phase: u16 },
+    Ramp { start: i16, end: i16 },
+    LeftRight { large_magnitude: u16, small_magnitude: u16 }
+}
+
+/// A force feedback effect, created with an `EffectBuilder` and played
+/// with `Haptic::upload()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Effect {
+    kind: Kind,
+    direction: Direction,
+    length: u32,
+    delay: u16,
+    button: u16,
+    interval: u16,
+    attack_length: u16,
+    attack_level: u16,
+    fade_length: u16,
+    fade_level: u16
+}
+
+impl Effect {
+    /// The feature a device needs to support to play the effect.
+    pub fn feature(&self) -> Features {
+        match self.kind {
+            Kind::Constant {..} => CONSTANT,
+            Kind::Periodic { waveform, .. } => waveform.feature(),
+            Kind::Ramp {..} => RAMP,
+            Kind::LeftRight {..} => LEFTRIGHT
+        }
+    }
+
+    /// The length of the effect in milliseconds, or `INFINITY`.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    pub fn to_ll(&self) -> ll::SDL_HapticEffect {
+        let type_ = self.feature().bits() as u16;
+
+        unsafe {
+            let mut raw: ll::SDL_HapticEffect = mem::zeroed();
+
+            match self.kind {
+                Kind::Constant { level } => {
+                    let constant = &mut *raw.constant();
+                    constant.type_ = type_;
+                    constant.direction = self.direction.to_ll();
+                    constant.length = self.length;
+                    constant.delay = self.delay;
+                    constant.button = self.button;
+                    constant.interval = self.interval;
+                    constant.level = level;
+                    constant.attack_length = self.attack_length;
+                    constant.attack_level = self.attack_level;
+                    constant.fade_length = self.fade_length;
+                    constant.fade_level = self.fade_level;
+                },
+                Kind::Periodic { period, magnitude, offset, phase, .. } => {
+                    let periodic = &mut *raw.periodic();
+                    periodic.type_ = type_;
+                    periodic.direction = self.direction.to_ll();
+                    periodic.length = self.length;
+                    periodic.delay = self.delay;
+                    periodic.button = self.button;
+                    periodic.interval = self.interval;
+                    periodic.period = period;
+                    periodic.magnitude = magnitude;
+                    periodic.offset = offset;
+                    periodic.phase = phase;
+                    periodic.attack_length = self.attack_length;
+                    periodic.attack_level = self.attack_level;
+                    periodic.fade_length = self.fade_length;
+                    periodic.fade_level = self.fade_level;
+                },
+                Kind::Ramp { start, end } => {
+                    let ramp = &mut *raw.ramp();
+                    ramp.type_ = type_;
+                    ramp.direction = self.direction.to_ll();
+                    ramp.length = self.length;
+                    ramp.delay = self.delay;
+                    ramp.button = self.button;
+                    ramp.interval = self.interval;
+                    ramp.start = start;
+                    ramp.end = end;
+                    ramp.attack_length = self.attack_length;
+                    ramp.attack_level = self.attack_level;
+                    ramp.fade_length = self.fade_length;
+                    ramp.fade_level = self.fade_level;
+                },
+                Kind::LeftRight { large_magnitude, small_magnitude } => {
+                    let left_right = &mut *raw.left_right();
+                    left_right.type_ = type_;
+                    left_right.length = self.length;
+                    left_right.large_magnitude = large_magnitude;
+                    left_right.small_magnitude = small_magnitude;
+                }
+            }
+
+            raw
+        }
+    }
+}
+
+/// The builder for force feedback effects.
+///
+/// Levels and magnitudes range from -32768 to 32767 (or 0 to 65535 for
+/// unsigned ones), times are in milliseconds. Effects last one second
+/// unless another `length()` is given.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::haptic::{Direction, EffectBuilder, Haptic, Waveform};
+///
+/// let _sdl_context = sdl2::init().joystick().haptic().unwrap();
+/// let haptic = Haptic::open(0).unwrap();
+///
+/// // A rough road: a sine wave pushing from the left, fading out.
+/// let effect = EffectBuilder::periodic(Waveform::Sine, 100, 12000)
+///     .direction(Direction::Polar(27000))
+///     .length(2000)
+///     .envelope(0, 0, 500, 0)
+///     .build();
+///
+/// if haptic.is_effect_supported(&effect) {
+///     let uploaded = haptic.upload(&effect).unwrap();
+///     uploaded.run(1).unwrap();
+/// }
+/// ```
+pub struct EffectBuilder {
+    effect: Effect
+}
+
+impl EffectBuilder {
+    fn new(kind: Kind) -> EffectBuilder {
+        EffectBuilder {
+            effect: Effect {
+                kind: kind,
+                direction: Direction::Polar(0),
+                length: 1000,
+                delay: 0,
+                button: 0,
+                interval: 0,
+                attack_length: 0,
+                attack_level: 0,
+                fade_length: 0,
+                fade_level: 0
+            }
+        }
+    }
+
+    /// A force of constant `level`.
+    pub fn constant(level: i16) -> EffectBuilder {
+        EffectBuilder::new(Kind::Constant { level: level })
+    }
+
+    /// A force that oscillates with the given `waveform`, `period` in
+    /// milliseconds and peak `magnitude`.
+    pub fn periodic(waveform: Waveform, period: u16, magnitude: i16) -> EffectBuilder {
+        EffectBuilder::new(Kind::Periodic {
+            waveform: waveform,
+            period: period,
+            magnitude: magnitude,
+            offset: 0,
+            phase: 0
+        })
+    }
+
+    /// A force that changes linearly from `start` to `end` over the
+    /// length of the effect.
+    pub fn ramp(start: i16, end: i16) -> EffectBuilder {
+        EffectBuilder::new(Kind::Ramp { start: start, end: end })
+    }
+
+    /// A rumble driving the large (low frequency) and small (high
+    /// frequency) motors of a gamepad independently.
+    pub fn left_right(large_magnitude: u16, small_magnitude: u16) -> EffectBuilder {
+        EffectBuilder::new(Kind::LeftRight {
+            large_magnitude: large_magnitude,
+            small_magnitude: small_magnitude
+        })
+    }
+
+    /// Sets the direction the force comes from. Ignored by left/right
+    /// effects.
+    pub fn direction(&mut self, direction: Direction) -> &mut EffectBuilder {
+        self.effect.direction = direction;
+        self
+    }
+
+    /// Sets the length of the effect in milliseconds, or `INFINITY` to
+    /// play it until it is stopped.
+    pub fn length(&mut self, length: u32) -> &mut EffectBuilder {
+        self.effect.length = length;
+        self
+    }
+
+    /// Plays the effect until it is stopped.
+    pub fn infinite(&mut self) -> &mut EffectBuilder {
+        self.length(INFINITY)
+    }
+
+    /// Sets the delay in milliseconds before the effect starts. Ignored by
+    /// left/right effects.
+    pub fn delay(&mut self, delay: u16) -> &mut EffectBuilder {
+        self.effect.delay = delay;
+        self
+    }
+
+    /// Makes the effect play when the device's `button` is pressed, at
+    /// most every `interval` milliseconds. Ignored by left/right effects.
+    pub fn trigger(&mut self, button: u16, interval: u16) -> &mut EffectBuilder {
+        self.effect.button = button;
+        self.effect.interval = interval;
+        self
+    }
+
+    /// Fades the effect in from `attack_level` over `attack_length`
+    /// milliseconds, and out to `fade_level` over the last `fade_length`
+    /// milliseconds. Ignored by left/right effects.
+    pub fn envelope(&mut self, attack_length: u16, attack_level: u16,
+                    fade_length: u16, fade_level: u16) -> &mut EffectBuilder {
+        self.effect.attack_length = attack_length;
+        self.effect.attack_level = attack_level;
+        self.effect.fade_length = fade_length;
+        self.effect.fade_level = fade_level;
+        self
+    }
+
+    /// Sets the mean value of a periodic effect. Ignored by other effects.
+    pub fn offset(&mut self, value: i16) -> &mut EffectBuilder {
+        if let Kind::Periodic { ref mut offset, .. } = self.effect.kind {
+            *offset = value;
+        }
+        self
+    }
+
+    /// Sets the phase shift of a periodic effect, in hundredths of a
+    /// degree. Ignored by other effects.
+    pub fn phase(&mut self, value: u16) -> &mut EffectBuilder {
+        if let Kind::Periodic { ref mut phase, .. } = self.effect.kind {
+            *phase = value;
+        }
+        self
+    }
+
+    pub fn build(&self) -> Effect {
+        self.effect
+    }
+}
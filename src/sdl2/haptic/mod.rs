@@ -0,0 +1,429 @@
+//! Haptic Functions
+//!
+//! Force feedback for joysticks, game controllers and some mice. The
+//! haptic subsystem has to be initialized with `InitBuilder::haptic()`.
+//!
+//! # Example
+//! ```no_run
+//! use sdl2::haptic::Haptic;
+//!
+//! let _sdl_context = sdl2::init().joystick().haptic().unwrap();
+//!
+//! let haptic = Haptic::open(0).unwrap();
+//! haptic.rumble_init().unwrap();
+//!
+//! // Rumble for 300 ms at 70% strength.
+//! haptic.rumble_play(0.7, 300).unwrap();
+//! ```
+use libc::{c_char, c_int};
+use std::ffi::CStr;
+
+use SdlResult;
+use get_error;
+use controller::GameController;
+use joystick::Joystick;
+
+use sys::haptic as ll;
+
+mod effect;
+pub use self::effect::{Direction, Effect, EffectBuilder, Waveform};
+
+/// The length of an effect that plays until it is stopped.
+pub const INFINITY: u32 = ll::SDL_HAPTIC_INFINITY;
+
+bitflags! {
+    flags Features: u32 {
+        const CONSTANT = ll::SDL_HAPTIC_CONSTANT as u32,
+        const SINE = ll::SDL_HAPTIC_SINE as u32,
+        const LEFTRIGHT = ll::SDL_HAPTIC_LEFTRIGHT as u32,
+        const TRIANGLE = ll::SDL_HAPTIC_TRIANGLE as u32,
+        const SAWTOOTHUP = ll::SDL_HAPTIC_SAWTOOTHUP as u32,
+        const SAWTOOTHDOWN = ll::SDL_HAPTIC_SAWTOOTHDOWN as u32,
+        const RAMP = ll::SDL_HAPTIC_RAMP as u32,
+        const SPRING = ll::SDL_HAPTIC_SPRING as u32,
+        const DAMPER = ll::SDL_HAPTIC_DAMPER as u32,
+        const INERTIA = ll::SDL_HAPTIC_INERTIA as u32,
+        const FRICTION = ll::SDL_HAPTIC_FRICTION as u32,
+        const CUSTOM = ll::SDL_HAPTIC_CUSTOM as u32,
+        /// The device supports `Haptic::set_gain()`.
+        const GAIN = ll::SDL_HAPTIC_GAIN as u32,
+        /// The device supports `Haptic::set_autocenter()`.
+        const AUTOCENTER = ll::SDL_HAPTIC_AUTOCENTER as u32,
+        /// The device supports `EffectHandle::is_playing()`.
+        const STATUS = ll::SDL_HAPTIC_STATUS as u32,
+        /// The device supports `Haptic::pause()`.
+        const PAUSE = ll::SDL_HAPTIC_PAUSE as u32
+    }
+}
+
+/// Retreive the number of haptic devices attached to the system.
+pub fn num_haptics() -> SdlResult<i32> {
+    let result = unsafe { ll::SDL_NumHaptics() };
+
+    if result >= 0 {
+        Ok(result)
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Return the name of the haptic device at index `id`
+pub fn name_for_index(id: i32) -> SdlResult<String> {
+    let name = unsafe { ll::SDL_HapticName(id) };
+
+    if name.is_null() {
+        Err(get_error())
+    } else {
+        Ok(c_str_to_string(name))
+    }
+}
+
+/// Wrapper around the SDL_Haptic object
+pub struct Haptic {
+    raw: *mut ll::SDL_Haptic,
+}
+
+impl Haptic {
+    /// Attempt to open the haptic device at index `id` and return it.
+    pub fn open(id: i32) -> SdlResult<Haptic> {
+        let haptic = unsafe { ll::SDL_HapticOpen(id) };
+
+        if haptic.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Haptic { raw: haptic })
+        }
+    }
+
+    /// Open the haptic device of a joystick, like the rumble motors of a
+    /// gamepad.
+    ///
+    /// The haptic device should be dropped before the joystick.
+    pub fn from_joystick(joystick: &Joystick) -> SdlResult<Haptic> {
+        Haptic::from_raw_joystick(joystick.raw())
+    }
+
+    /// Open the haptic device of the joystick underlying a game
+    /// controller. See `from_joystick()`.
+    pub fn from_game_controller(controller: &GameController) -> SdlResult<Haptic> {
+        let joystick = unsafe { ::sys::controller::SDL_GameControllerGetJoystick(controller.raw()) };
+
+        Haptic::from_raw_joystick(joystick)
+    }
+
+    /// Open the haptic device of the mouse, if it has one.
+    pub fn from_mouse() -> SdlResult<Haptic> {
+        unsafe {
+            if ll::SDL_MouseIsHaptic() != 1 {
+                return Err("The mouse is not a haptic device".to_owned());
+            }
+
+            let haptic = ll::SDL_HapticOpenFromMouse();
+
+            if haptic.is_null() {
+                Err(get_error())
+            } else {
+                Ok(Haptic { raw: haptic })
+            }
+        }
+    }
+
+    fn from_raw_joystick(joystick: *mut ::sys::joystick::SDL_Joystick) -> SdlResult<Haptic> {
+        unsafe {
+            if ll::SDL_JoystickIsHaptic(joystick) != 1 {
+                return Err("The joystick is not a haptic device".to_owned());
+            }
+
+            let haptic = ll::SDL_HapticOpenFromJoystick(joystick);
+
+            if haptic.is_null() {
+                Err(get_error())
+            } else {
+                Ok(Haptic { raw: haptic })
+            }
+        }
+    }
+
+    /// Return the index of the device.
+    pub fn index(&self) -> i32 {
+        unsafe { ll::SDL_HapticIndex(self.raw) }
+    }
+
+    /// Return the name of the device or an empty string if no name is
+    /// found.
+    pub fn name(&self) -> String {
+        let name = unsafe { ll::SDL_HapticName(self.index()) };
+
+        c_str_to_string(name)
+    }
+
+    /// Return the effects and settings the device supports.
+    pub fn features(&self) -> Features {
+        let features = unsafe { ll::SDL_HapticQuery(self.raw) };
+
+        Features::from_bits_truncate(features)
+    }
+
+    /// Retreive the number of axes of the device, i.e. the number of
+    /// coordinates of `Direction::Cartesian` that are used.
+    pub fn num_axes(&self) -> SdlResult<u32> {
+        let result = unsafe { ll::SDL_HapticNumAxes(self.raw) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as u32)
+        }
+    }
+
+    /// Retreive the number of effects the device can store.
+    pub fn num_effects(&self) -> SdlResult<u32> {
+        let result = unsafe { ll::SDL_HapticNumEffects(self.raw) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as u32)
+        }
+    }
+
+    /// Retreive the number of effects the device can play at the same
+    /// time.
+    pub fn num_effects_playing(&self) -> SdlResult<u32> {
+        let result = unsafe { ll::SDL_HapticNumEffectsPlaying(self.raw) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as u32)
+        }
+    }
+
+    /// Return `true` if the device can play `effect`.
+    pub fn is_effect_supported(&self, effect: &Effect) -> bool {
+        let mut raw = effect.to_ll();
+
+        unsafe { ll::SDL_HapticEffectSupported(self.raw, &mut raw) == 1 }
+    }
+
+    /// Upload `effect` to the device, so it can be played.
+    ///
+    /// The effect is removed from the device when the returned handle is
+    /// dropped.
+    pub fn upload(&self, effect: &Effect) -> SdlResult<EffectHandle<'_>> {
+        let mut raw = effect.to_ll();
+        let id = unsafe { ll::SDL_HapticNewEffect(self.raw, &mut raw) };
+
+        if id < 0 {
+            Err(get_error())
+        } else {
+            Ok(EffectHandle {
+                haptic: self,
+                id: id,
+                effect: *effect
+            })
+        }
+    }
+
+    /// Set the overall strength of all effects, from 0 to 100 percent.
+    ///
+    /// Needs the `GAIN` feature.
+    pub fn set_gain(&self, gain: u32) -> SdlResult<()> {
+        let gain = gain.min(100) as c_int;
+        let result = unsafe { ll::SDL_HapticSetGain(self.raw, gain) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Set the strength of the force that centers the device, from 0
+    /// (off) to 100 percent.
+    ///
+    /// Needs the `AUTOCENTER` feature.
+    pub fn set_autocenter(&self, autocenter: u32) -> SdlResult<()> {
+        let autocenter = autocenter.min(100) as c_int;
+        let result = unsafe { ll::SDL_HapticSetAutocenter(self.raw, autocenter) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Pause all effects, e.g. while the game is paused.
+    ///
+    /// Needs the `PAUSE` feature.
+    pub fn pause(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticPause(self.raw) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Resume the effects paused with `pause()`.
+    pub fn unpause(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticUnpause(self.raw) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Stop all effects.
+    pub fn stop_all(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticStopAll(self.raw) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Return `true` if the device supports simple rumble effects.
+    pub fn is_rumble_supported(&self) -> bool {
+        unsafe { ll::SDL_HapticRumbleSupported(self.raw) == 1 }
+    }
+
+    /// Prepare the device for `rumble_play()`.
+    pub fn rumble_init(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticRumbleInit(self.raw) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Rumble with `strength` between 0 and 1 for `length` milliseconds,
+    /// or until `rumble_stop()` is called if `length` is `INFINITY`.
+    ///
+    /// `rumble_init()` has to be called first. A NaN `strength` counts as 0.
+    pub fn rumble_play(&self, strength: f32, length: u32) -> SdlResult<()> {
+        // `clamp` passes NaN through.
+        let strength = if strength.is_nan() { 0.0 } else { strength.clamp(0.0, 1.0) };
+        let result = unsafe { ll::SDL_HapticRumblePlay(self.raw, strength, length) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Stop the rumble effect.
+    pub fn rumble_stop(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticRumbleStop(self.raw) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+}
+
+impl Drop for Haptic {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_HapticClose(self.raw) }
+    }
+}
+
+/// An effect uploaded to a haptic device. See `Haptic::upload()`.
+pub struct EffectHandle<'a> {
+    haptic: &'a Haptic,
+    id: c_int,
+    effect: Effect
+}
+
+impl<'a> EffectHandle<'a> {
+    /// Play the effect `iterations` times, or until it is stopped if
+    /// `iterations` is `INFINITY`. Restarts the effect if it is playing.
+    pub fn run(&self, iterations: u32) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticRunEffect(self.haptic.raw, self.id, iterations) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Stop the effect.
+    pub fn stop(&self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_HapticStopEffect(self.haptic.raw, self.id) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Replace the effect, even while it is playing, e.g. to follow the
+    /// forces of a steering wheel.
+    ///
+    /// The new effect has to be of the same kind and waveform as the
+    /// uploaded one.
+    pub fn update(&mut self, effect: &Effect) -> SdlResult<()> {
+        if effect.feature() != self.effect.feature() {
+            return Err("Effects can only be updated with an effect of the same kind".to_owned());
+        }
+
+        let mut raw = effect.to_ll();
+        let result = unsafe { ll::SDL_HapticUpdateEffect(self.haptic.raw, self.id, &mut raw) };
+
+        if result == 0 {
+            self.effect = *effect;
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Return `true` if the effect is playing.
+    ///
+    /// Needs the `STATUS` feature.
+    pub fn is_playing(&self) -> SdlResult<bool> {
+        let result = unsafe { ll::SDL_HapticGetEffectStatus(self.haptic.raw, self.id) };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result == 1)
+        }
+    }
+
+    /// Return the effect as last uploaded.
+    pub fn effect(&self) -> &Effect {
+        &self.effect
+    }
+}
+
+impl<'a> Drop for EffectHandle<'a> {
+    fn drop(&mut self) {
+        unsafe { ll::SDL_HapticDestroyEffect(self.haptic.raw, self.id) }
+    }
+}
+
+/// Convert C string `c_str` to a String. Return an empty string if
+/// c_str is NULL.
+fn c_str_to_string(c_str: *const c_char) -> String {
+    if c_str.is_null() {
+        String::new()
+    } else {
+        let bytes = unsafe { CStr::from_ptr(c_str).to_bytes() };
+
+        String::from_utf8_lossy(bytes).to_string()
+    }
+}
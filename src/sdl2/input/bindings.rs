@@ -0,0 +1,332 @@
+/*!
+Rebindable controls
+
+`Bindings` maps named actions, like "jump" or "fire", to the keys, mouse
+buttons and controller buttons that trigger them. Whether an action is
+triggered is answered from an `InputState` that was fed the frame's events.
+
+Bindings can be saved to and loaded from a simple text format, with one
+binding per line:
+
+```text
+# Comments start with a hash sign.
+jump key Space
+jump controller a
+fire mouse left
+walk_left key Left
+```
+
+Each line consists of the action name, the kind of input (`key`, `mouse`
+or `controller`) and the name of the input: a scancode name (see
+`Scancode::name()`), a mouse button (`left`, `middle`, `right`, `x1`, `x2`
+or a button number), or a controller button as used in controller mappings
+(see `Button::get_string()`).
+
+# Example
+```no_run
+use sdl2::input::InputState;
+use sdl2::input::bindings::Bindings;
+
+let mut sdl_context = sdl2::init().video().game_controller().unwrap();
+let bindings = Bindings::load("controls.cfg").unwrap();
+let mut input = InputState::new();
+
+loop {
+    input.begin_frame();
+    for event in sdl_context.event_pump().poll_iter() {
+        input.handle_event(&event);
+    }
+
+    if bindings.just_pressed(&input, "jump") {
+        // ...
+    }
+
+    input.end_frame();
+}
+```
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use controller::Button;
+use keyboard::Scancode;
+use mouse::Mouse;
+use SdlResult;
+
+use super::InputState;
+
+/// An input that can trigger an action.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Binding {
+    Key(Scancode),
+    Mouse(Mouse),
+    /// A button on any controller.
+    Controller(Button)
+}
+
+impl Binding {
+    fn held(self, input: &InputState) -> bool {
+        match self {
+            Binding::Key(scancode) => input.held(scancode),
+            Binding::Mouse(button) => input.mouse_held(button),
+            Binding::Controller(button) => input.any_controller_held(button)
+        }
+    }
+
+    fn just_pressed(self, input: &InputState) -> bool {
+        match self {
+            Binding::Key(scancode) => input.just_pressed(scancode),
+            Binding::Mouse(button) => input.mouse_just_pressed(button),
+            Binding::Controller(button) => input.any_controller_just_pressed(button)
+        }
+    }
+
+    fn just_released(self, input: &InputState) -> bool {
+        match self {
+            Binding::Key(scancode) => input.just_released(scancode),
+            Binding::Mouse(button) => input.mouse_just_released(button),
+            Binding::Controller(button) => input.any_controller_just_released(button)
+        }
+    }
+
+    fn parse(kind: &str, name: &str) -> Option<Binding> {
+        match kind {
+            "key" => Scancode::from_name(name).map(Binding::Key),
+            "mouse" => mouse_from_name(name).map(Binding::Mouse),
+            "controller" => Button::from_string(name).map(Binding::Controller),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Binding::Key(scancode) => write!(f, "key {}", scancode.name()),
+            Binding::Mouse(button) => write!(f, "mouse {}", mouse_name(button)),
+            Binding::Controller(button) => write!(f, "controller {}", button.get_string())
+        }
+    }
+}
+
+fn mouse_name(button: Mouse) -> String {
+    match button {
+        Mouse::Left => "left".to_owned(),
+        Mouse::Middle => "middle".to_owned(),
+        Mouse::Right => "right".to_owned(),
+        Mouse::X1 => "x1".to_owned(),
+        Mouse::X2 => "x2".to_owned(),
+        Mouse::Unknown(x) => x.to_string()
+    }
+}
+
+fn mouse_from_name(name: &str) -> Option<Mouse> {
+    match name {
+        "left" => Some(Mouse::Left),
+        "middle" => Some(Mouse::Middle),
+        "right" => Some(Mouse::Right),
+        "x1" => Some(Mouse::X1),
+        "x2" => Some(Mouse::X2),
+        _ => name.parse().ok().map(::mouse::wrap_mouse)
+    }
+}
+
+/// A set of named actions and the inputs bound to them.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Bindings {
+    actions: BTreeMap<String, Vec<Binding>>
+}
+
+impl Bindings {
+    /// Creates an empty set of bindings.
+    pub fn new() -> Bindings {
+        Bindings {
+            actions: BTreeMap::new()
+        }
+    }
+
+    /// Parses bindings from the text format described in the module
+    /// documentation.
+    pub fn parse(config: &str) -> SdlResult<Bindings> {
+        let mut bindings = Bindings::new();
+
+        for (index, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(3, char::is_whitespace);
+            let (action, kind, name) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(action), Some(kind), Some(name)) => (action, kind, name.trim()),
+                _ => return Err(format!("Line {}: expected an action, an input kind and an input name", index + 1))
+            };
+
+            match Binding::parse(kind, name) {
+                Some(binding) => bindings.bind(action, binding),
+                None => return Err(format!("Line {}: unknown input \"{} {}\"", index + 1, kind, name))
+            }
+        }
+
+        Ok(bindings)
+    }
+
+    /// Loads bindings from the file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> SdlResult<Bindings> {
+        let mut config = String::new();
+
+        match File::open(path).and_then(|mut file| file.read_to_string(&mut config)) {
+            Ok(_) => Bindings::parse(&config),
+            Err(e) => Err(format!("Could not read bindings: {}", e))
+        }
+    }
+
+    /// Saves the bindings to the file at `path`, truncating it if it
+    /// already exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> SdlResult<()> {
+        match File::create(path).and_then(|mut file| write!(file, "{}", self)) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("Could not write bindings: {}", e))
+        }
+    }
+
+    /// Binds an input to an action.
+    ///
+    /// An input can be bound to several actions, and an action can have
+    /// several inputs. Action names must not contain whitespace, or the
+    /// bindings can't be loaded back after saving them.
+    pub fn bind(&mut self, action: &str, binding: Binding) {
+        let bindings = self.actions.entry(action.to_owned()).or_default();
+
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Removes an input from an action.
+    ///
+    /// Returns `false` if the input wasn't bound to the action.
+    pub fn unbind(&mut self, action: &str, binding: Binding) -> bool {
+        let (removed, now_empty) = match self.actions.get_mut(action) {
+            Some(bindings) => {
+                let len = bindings.len();
+                bindings.retain(|&b| b != binding);
+                (bindings.len() != len, bindings.is_empty())
+            },
+            None => return false
+        };
+
+        if now_empty {
+            self.actions.remove(action);
+        }
+
+        removed
+    }
+
+    /// Removes all inputs from an action.
+    pub fn clear_action(&mut self, action: &str) {
+        self.actions.remove(action);
+    }
+
+    /// Returns the inputs bound to an action.
+    pub fn bindings(&self, action: &str) -> &[Binding] {
+        match self.actions.get(action) {
+            Some(bindings) => bindings,
+            None => &[]
+        }
+    }
+
+    /// Returns the actions an input is bound to.
+    pub fn actions_for(&self, binding: Binding) -> Vec<&str> {
+        self.actions.iter()
+            .filter(|&(_, bindings)| bindings.contains(&binding))
+            .map(|(action, _)| &action[..])
+            .collect()
+    }
+
+    /// Tests if any input bound to the action is held down.
+    pub fn held(&self, input: &InputState, action: &str) -> bool {
+        self.bindings(action).iter().any(|b| b.held(input))
+    }
+
+    /// Tests if any input bound to the action was pressed during the
+    /// current frame.
+    pub fn just_pressed(&self, input: &InputState, action: &str) -> bool {
+        self.bindings(action).iter().any(|b| b.just_pressed(input))
+    }
+
+    /// Tests if the action stopped being held down during the current
+    /// frame, i.e. an input bound to it was released and none is held.
+    pub fn just_released(&self, input: &InputState, action: &str) -> bool {
+        self.bindings(action).iter().any(|b| b.just_released(input)) &&
+            !self.held(input, action)
+    }
+}
+
+impl fmt::Display for Bindings {
+    /// Formats the bindings in the text format described in the module
+    /// documentation.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (action, bindings) in self.actions.iter() {
+            for binding in bindings {
+                try!(writeln!(f, "{} {}", action, binding));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Binding, Bindings};
+    use input::InputState;
+    use event::Event;
+    use keyboard::Scancode;
+    use mouse::Mouse;
+
+    #[test]
+    fn test_parse_and_format() {
+        let bindings = Bindings::parse("# Controls\n\
+                                        fire mouse left\n\
+                                        \n\
+                                        jump key Space\n\
+                                        fire mouse 6\n").unwrap();
+
+        assert_eq!(bindings.bindings("fire"), &[Binding::Mouse(Mouse::Left), Binding::Mouse(Mouse::Unknown(6))]);
+        assert_eq!(bindings.bindings("jump"), &[Binding::Key(Scancode::Space)]);
+        assert_eq!(Bindings::parse(&bindings.to_string()).unwrap(), bindings);
+
+        assert!(Bindings::parse("jump key").is_err());
+        assert!(Bindings::parse("jump pedal left").is_err());
+    }
+
+    #[test]
+    fn test_query() {
+        let mut bindings = Bindings::new();
+        bindings.bind("fire", Binding::Mouse(Mouse::Left));
+        bindings.bind("fire", Binding::Mouse(Mouse::Right));
+
+        let mut input = InputState::new();
+        input.handle_event(&Event::MouseButtonDown { timestamp: 0, window_id: 0, which: 0, mouse_btn: Mouse::Left, x: 0, y: 0 });
+        input.handle_event(&Event::MouseButtonDown { timestamp: 0, window_id: 0, which: 0, mouse_btn: Mouse::Right, x: 0, y: 0 });
+        assert!(bindings.just_pressed(&input, "fire"));
+        assert!(bindings.held(&input, "fire"));
+
+        input.begin_frame();
+        input.handle_event(&Event::MouseButtonUp { timestamp: 0, window_id: 0, which: 0, mouse_btn: Mouse::Left, x: 0, y: 0 });
+        assert!(!bindings.just_released(&input, "fire"));
+
+        input.begin_frame();
+        input.handle_event(&Event::MouseButtonUp { timestamp: 0, window_id: 0, which: 0, mouse_btn: Mouse::Right, x: 0, y: 0 });
+        assert!(bindings.just_released(&input, "fire"));
+        assert!(!bindings.held(&input, "fire"));
+
+        assert!(bindings.unbind("fire", Binding::Mouse(Mouse::Left)));
+        assert!(!bindings.unbind("fire", Binding::Mouse(Mouse::Left)));
+        assert_eq!(bindings.actions_for(Binding::Mouse(Mouse::Right)), vec!["fire"]);
+    }
+}
@@ -0,0 +1,321 @@
+/*!
+Frame-based input state tracking
+
+An `InputState` is fed the events of every frame and keeps track of which
+keys and buttons are held down, and which were pressed or released during
+the current frame.
+
+# Example
+```no_run
+use sdl2::input::InputState;
+use sdl2::keyboard::Scancode;
+
+let mut sdl_context = sdl2::init().video().unwrap();
+let mut input = InputState::new();
+
+loop {
+    input.begin_frame();
+    for event in sdl_context.event_pump().poll_iter() {
+        input.handle_event(&event);
+    }
+
+    if input.just_pressed(Scancode::Space) {
+        // Jump
+    }
+    if input.held(Scancode::Right) {
+        // Walk
+    }
+
+    input.end_frame();
+}
+```
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+use controller::Button;
+use event::Event;
+use keyboard::Scancode;
+use mouse::Mouse;
+
+pub mod bindings;
+
+struct ButtonStates<T: Copy + Eq + Hash> {
+    /// Held buttons, with the number of completed frames they've been held for.
+    held: HashMap<T, u32>,
+    pressed: HashSet<T>,
+    released: HashSet<T>
+}
+
+impl<T: Copy + Eq + Hash> ButtonStates<T> {
+    fn new() -> ButtonStates<T> {
+        ButtonStates {
+            held: HashMap::new(),
+            pressed: HashSet::new(),
+            released: HashSet::new()
+        }
+    }
+
+    fn press(&mut self, button: T) {
+        if let Entry::Vacant(entry) = self.held.entry(button) {
+            entry.insert(0);
+            self.pressed.insert(button);
+        }
+    }
+
+    fn release(&mut self, button: T) {
+        if self.held.remove(&button).is_some() {
+            self.released.insert(button);
+        }
+    }
+
+    fn begin_frame(&mut self) {
+        self.pressed.clear();
+        self.released.clear();
+    }
+
+    fn end_frame(&mut self) {
+        for frames in self.held.values_mut() {
+            *frames += 1;
+        }
+    }
+
+    fn held_frames(&self, button: T) -> Option<u32> {
+        self.held.get(&button).cloned()
+    }
+}
+
+/// Tracks the state of keys, mouse buttons and controller buttons across
+/// frames.
+///
+/// Controller buttons are identified by the joystick instance id of the
+/// controller, as found in the `which` field of controller events.
+pub struct InputState {
+    keys: ButtonStates<Scancode>,
+    mouse_buttons: ButtonStates<Mouse>,
+    controller_buttons: ButtonStates<(i32, Button)>
+}
+
+impl InputState {
+    /// Creates an input state with nothing held down.
+    pub fn new() -> InputState {
+        InputState {
+            keys: ButtonStates::new(),
+            mouse_buttons: ButtonStates::new(),
+            controller_buttons: ButtonStates::new()
+        }
+    }
+
+    /// Starts a new frame, forgetting what was pressed and released during
+    /// the previous one.
+    pub fn begin_frame(&mut self) {
+        self.keys.begin_frame();
+        self.mouse_buttons.begin_frame();
+        self.controller_buttons.begin_frame();
+    }
+
+    /// Ends the current frame, counting it towards the frames everything
+    /// currently held down has been held for.
+    pub fn end_frame(&mut self) {
+        self.keys.end_frame();
+        self.mouse_buttons.end_frame();
+        self.controller_buttons.end_frame();
+    }
+
+    /// Updates the state from an event of the current frame.
+    ///
+    /// Key presses generated by keyboard auto-repeat are ignored. When a
+    /// controller is unplugged, all of its buttons are released.
+    pub fn handle_event(&mut self, event: &Event) {
+        match *event {
+            Event::KeyDown { scancode: Some(scancode), repeat: false, .. } => {
+                self.keys.press(scancode)
+            },
+            Event::KeyUp { scancode: Some(scancode), .. } => {
+                self.keys.release(scancode)
+            },
+            Event::MouseButtonDown { mouse_btn, .. } => {
+                self.mouse_buttons.press(mouse_btn)
+            },
+            Event::MouseButtonUp { mouse_btn, .. } => {
+                self.mouse_buttons.release(mouse_btn)
+            },
+            Event::ControllerButtonDown { which, button, .. } => {
+                self.controller_buttons.press((which, button))
+            },
+            Event::ControllerButtonUp { which, button, .. } => {
+                self.controller_buttons.release((which, button))
+            },
+            Event::ControllerDeviceRemoved { which, .. } => {
+                let buttons: Vec<_> = self.controller_buttons.held.keys()
+                    .filter(|&&(id, _)| id == which)
+                    .cloned()
+                    .collect();
+
+                for button in buttons {
+                    self.controller_buttons.release(button);
+                }
+            },
+            _ => ()
+        }
+    }
+
+    /// Tests if the key was pressed during the current frame.
+    pub fn just_pressed(&self, scancode: Scancode) -> bool {
+        self.keys.pressed.contains(&scancode)
+    }
+
+    /// Tests if the key was released during the current frame.
+    pub fn just_released(&self, scancode: Scancode) -> bool {
+        self.keys.released.contains(&scancode)
+    }
+
+    /// Tests if the key is held down.
+    pub fn held(&self, scancode: Scancode) -> bool {
+        self.keys.held.contains_key(&scancode)
+    }
+
+    /// Returns the number of completed frames the key has been held down
+    /// for, or `None` if it isn't held down.
+    pub fn held_frames(&self, scancode: Scancode) -> Option<u32> {
+        self.keys.held_frames(scancode)
+    }
+
+    /// Tests if the mouse button was pressed during the current frame.
+    pub fn mouse_just_pressed(&self, button: Mouse) -> bool {
+        self.mouse_buttons.pressed.contains(&button)
+    }
+
+    /// Tests if the mouse button was released during the current frame.
+    pub fn mouse_just_released(&self, button: Mouse) -> bool {
+        self.mouse_buttons.released.contains(&button)
+    }
+
+    /// Tests if the mouse button is held down.
+    pub fn mouse_held(&self, button: Mouse) -> bool {
+        self.mouse_buttons.held.contains_key(&button)
+    }
+
+    /// Returns the number of completed frames the mouse button has been
+    /// held down for, or `None` if it isn't held down.
+    pub fn mouse_held_frames(&self, button: Mouse) -> Option<u32> {
+        self.mouse_buttons.held_frames(button)
+    }
+
+    /// Tests if the button of the controller `which` was pressed during the
+    /// current frame.
+    pub fn controller_just_pressed(&self, which: i32, button: Button) -> bool {
+        self.controller_buttons.pressed.contains(&(which, button))
+    }
+
+    /// Tests if the button of the controller `which` was released during
+    /// the current frame.
+    pub fn controller_just_released(&self, which: i32, button: Button) -> bool {
+        self.controller_buttons.released.contains(&(which, button))
+    }
+
+    /// Tests if the button of the controller `which` is held down.
+    pub fn controller_held(&self, which: i32, button: Button) -> bool {
+        self.controller_buttons.held.contains_key(&(which, button))
+    }
+
+    /// Returns the number of completed frames the button of the controller
+    /// `which` has been held down for, or `None` if it isn't held down.
+    pub fn controller_held_frames(&self, which: i32, button: Button) -> Option<u32> {
+        self.controller_buttons.held_frames((which, button))
+    }
+
+    /// Tests if the button was pressed on any controller during the current
+    /// frame.
+    pub fn any_controller_just_pressed(&self, button: Button) -> bool {
+        self.controller_buttons.pressed.iter().any(|&(_, b)| b == button)
+    }
+
+    /// Tests if the button was released on any controller during the
+    /// current frame.
+    pub fn any_controller_just_released(&self, button: Button) -> bool {
+        self.controller_buttons.released.iter().any(|&(_, b)| b == button)
+    }
+
+    /// Tests if the button is held down on any controller.
+    pub fn any_controller_held(&self, button: Button) -> bool {
+        self.controller_buttons.held.keys().any(|&(_, b)| b == button)
+    }
+}
+
+impl Default for InputState {
+    fn default() -> InputState {
+        InputState::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputState;
+    use controller::Button;
+    use event::Event;
+    use keyboard::{Scancode, NOMOD};
+
+    fn key_down(scancode: Scancode, repeat: bool) -> Event {
+        Event::KeyDown {
+            timestamp: 0, window_id: 0, keycode: None,
+            scancode: Some(scancode), keymod: NOMOD, repeat: repeat
+        }
+    }
+
+    fn key_up(scancode: Scancode) -> Event {
+        Event::KeyUp {
+            timestamp: 0, window_id: 0, keycode: None,
+            scancode: Some(scancode), keymod: NOMOD, repeat: false
+        }
+    }
+
+    #[test]
+    fn test_key_transitions() {
+        let mut input = InputState::new();
+
+        input.begin_frame();
+        input.handle_event(&key_down(Scancode::A, false));
+        assert!(input.just_pressed(Scancode::A));
+        assert!(input.held(Scancode::A));
+        assert_eq!(input.held_frames(Scancode::A), Some(0));
+        input.end_frame();
+
+        input.begin_frame();
+        input.handle_event(&key_down(Scancode::A, true));
+        assert!(!input.just_pressed(Scancode::A));
+        assert!(input.held(Scancode::A));
+        assert_eq!(input.held_frames(Scancode::A), Some(1));
+        input.end_frame();
+
+        // A tap within a single frame is both pressed and released.
+        input.begin_frame();
+        input.handle_event(&key_up(Scancode::A));
+        input.handle_event(&key_down(Scancode::B, false));
+        input.handle_event(&key_up(Scancode::B));
+        assert!(input.just_released(Scancode::A));
+        assert!(input.just_pressed(Scancode::B) && input.just_released(Scancode::B));
+        assert!(!input.held(Scancode::A) && !input.held(Scancode::B));
+        assert_eq!(input.held_frames(Scancode::A), None);
+        input.end_frame();
+    }
+
+    #[test]
+    fn test_controller_removed() {
+        let mut input = InputState::new();
+
+        input.begin_frame();
+        input.handle_event(&Event::ControllerButtonDown { timestamp: 0, which: 1, button: Button::A });
+        input.handle_event(&Event::ControllerButtonDown { timestamp: 0, which: 2, button: Button::A });
+        input.end_frame();
+
+        input.begin_frame();
+        input.handle_event(&Event::ControllerDeviceRemoved { timestamp: 0, which: 1 });
+        assert!(input.controller_just_released(1, Button::A));
+        assert!(!input.controller_held(1, Button::A));
+        assert!(input.controller_held(2, Button::A));
+        input.end_frame();
+    }
+}
@@ -5,7 +5,8 @@ use get_error;
 use clear_error;
 use sys::event::{SDL_QUERY, SDL_ENABLE};
 use std::ffi::{CString, CStr, NulError};
-use std::fmt::{Display, Formatter, Error};
+use std::fmt::{Debug, Display, Formatter, Error};
+use std::str::FromStr;
 use libc::c_char;
 
 /// Retreive the total number of attached joysticks *and* controllers
@@ -21,6 +22,9 @@ pub fn num_joysticks() -> SdlResult<i32> {
 }
 
 /// Get the GUID for the joystick number `id`
+///
+/// This works before the joystick is opened, which allows to look up
+/// settings saved for the device first.
 pub fn get_device_guid(id: i32) -> SdlResult<Guid> {
     let raw = unsafe { ll::SDL_JoystickGetDeviceGUID(id) };
 
@@ -33,8 +37,69 @@ pub fn get_device_guid(id: i32) -> SdlResult<Guid> {
     }
 }
 
+/// Get the USB vendor id of the joystick number `id`, if known.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn get_device_vendor_id(id: i32) -> Option<u16> {
+    non_zero(unsafe { ll::SDL_JoystickGetDeviceVendor(id) })
+}
+
+/// Get the USB product id of the joystick number `id`, if known.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn get_device_product_id(id: i32) -> Option<u16> {
+    non_zero(unsafe { ll::SDL_JoystickGetDeviceProduct(id) })
+}
+
+/// Get the product version of the joystick number `id`, if known.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn get_device_product_version(id: i32) -> Option<u16> {
+    non_zero(unsafe { ll::SDL_JoystickGetDeviceProductVersion(id) })
+}
+
+/// A joystick or game controller found by `list_devices()`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeviceInfo {
+    /// The device index to open the device with.
+    pub index: i32,
+    pub name: String,
+    pub guid: Guid,
+    /// Whether the device can be opened as a `GameController`.
+    pub is_game_controller: bool
+}
+
+/// List the joysticks and game controllers that are plugged in.
+///
+/// Handy for diagnostics screens and bug reports.
+///
+/// # Example
+/// ```no_run
+/// let _sdl_context = sdl2::init().joystick().unwrap();
+///
+/// for device in sdl2::joystick::list_devices().unwrap() {
+///     println!("{:?}", device);
+/// }
+/// ```
+pub fn list_devices() -> SdlResult<Vec<DeviceInfo>> {
+    let count = try!(num_joysticks());
+    let mut devices = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+        devices.push(DeviceInfo {
+            index: index,
+            name: try!(name_for_index(index)),
+            guid: try!(get_device_guid(index)),
+            is_game_controller: ::controller::is_game_controller(index)
+        });
+    }
+
+    Ok(devices)
+}
+
 /// If state is `true` joystick events are processed, otherwise
 /// they're ignored.
+///
+/// See `controller::set_event_state()`. With older versions of SDL,
+/// turning joystick events off also stops controller events, since those
+/// are generated from them.
 pub fn set_event_state(state: bool) {
     unsafe { ll::SDL_JoystickEventState(state as i32) };
 }
@@ -53,6 +118,8 @@ pub fn name_for_index(id: i32) -> SdlResult<String> {
 }
 
 /// Force joystick update when not using the event loop
+///
+/// See `controller::update()`.
 pub fn update() {
     unsafe { ll::SDL_JoystickUpdate() };
 }
@@ -88,6 +155,13 @@ impl Joystick {
         unsafe { ll::SDL_JoystickGetAttached(self.raw) != 0 }
     }
 
+    /// Return the instance id of the joystick.
+    ///
+    /// Unlike the index the joystick was opened with, the instance id
+    /// stays the same while the joystick is connected and is never reused
+    /// during the run of the program. It's reported by the `which` field of
+    /// joystick events, except for `JoyDeviceAdded` which reports the
+    /// device index instead.
     pub fn get_instance_id(&self) -> i32 {
         let result = unsafe { ll::SDL_JoystickInstanceID(self.raw) };
 
@@ -113,6 +187,18 @@ impl Joystick {
         }
     }
 
+    /// Return the battery level of the joystick.
+    ///
+    /// Wired joysticks report `PowerLevel::Wired`. Many wireless
+    /// joysticks don't report their battery level, which shows as
+    /// `PowerLevel::Unknown`.
+    #[cfg(feature = "sdl_2_0_4")]
+    pub fn power_level(&self) -> PowerLevel {
+        let level = unsafe { ll::SDL_JoystickCurrentPowerLevel(self.raw) };
+
+        PowerLevel::from_ll(level)
+    }
+
     /// Retreive the number of axes for this joystick
     pub fn get_num_axes(&self) -> u32 {
         let result = unsafe { ll::SDL_JoystickNumAxes(self.raw) };
@@ -205,6 +291,9 @@ impl Joystick {
 
     /// Return a pair `(dx, dy)` containing the difference in axis
     /// position since the last poll
+    ///
+    /// Trackballs only report relative motion, so the motion is reset
+    /// with every call.
     pub fn get_ball(&self, ball: u32) -> SdlResult<(i32, i32)> {
         let mut dx = 0;
         let mut dy = 0;
@@ -219,7 +308,7 @@ impl Joystick {
         }
     }
 
-    /// Retreive the number of balls for this joystick
+    /// Retreive the number of hats for this joystick
     pub fn get_num_hats(&self) -> u32 {
         let result = unsafe { ll::SDL_JoystickNumHats(self.raw) };
 
@@ -257,14 +346,260 @@ impl Joystick {
     }
 }
 
+/// A summary of an opened joystick, see `Joystick::info()`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct JoystickInfo {
+    pub name: String,
+    pub guid: Guid,
+    pub instance_id: i32,
+    pub num_axes: u32,
+    pub num_buttons: u32,
+    pub num_hats: u32,
+    pub num_balls: u32,
+    #[cfg(feature = "sdl_2_0_4")]
+    pub power_level: PowerLevel,
+    pub attached: bool
+}
+
+impl Joystick {
+    /// Return a summary of the joystick, for diagnostics screens and bug
+    /// reports.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::joystick::Joystick;
+    ///
+    /// let _sdl_context = sdl2::init().joystick().unwrap();
+    /// let joystick = Joystick::open(0).unwrap();
+    ///
+    /// println!("{:#?}", joystick.info());
+    /// ```
+    pub fn info(&self) -> JoystickInfo {
+        JoystickInfo {
+            name: self.name(),
+            guid: self.get_guid(),
+            instance_id: self.get_instance_id(),
+            num_axes: self.get_num_axes(),
+            num_buttons: self.get_num_buttons(),
+            num_hats: self.get_num_hats(),
+            num_balls: self.get_num_balls(),
+            #[cfg(feature = "sdl_2_0_4")]
+            power_level: self.power_level(),
+            attached: self.get_attached()
+        }
+    }
+
+    /// Return the USB vendor id of the joystick, if known.
+    ///
+    /// Together with `product_id()`, this identifies the model of the
+    /// joystick, e.g. 0x045e and 0x028e for an Xbox 360 controller.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vendor_id(&self) -> Option<u16> {
+        non_zero(unsafe { ll::SDL_JoystickGetVendor(self.raw) })
+    }
+
+    /// Return the USB product id of the joystick, if known.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn product_id(&self) -> Option<u16> {
+        non_zero(unsafe { ll::SDL_JoystickGetProduct(self.raw) })
+    }
+
+    /// Return the product version of the joystick, if known.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn product_version(&self) -> Option<u16> {
+        non_zero(unsafe { ll::SDL_JoystickGetProductVersion(self.raw) })
+    }
+
+    /// Return the serial number of the joystick, if it reports one.
+    ///
+    /// Unlike the GUID, the serial number tells identical joysticks
+    /// apart.
+    #[cfg(feature = "sdl_2_0_14")]
+    pub fn serial(&self) -> Option<String> {
+        let serial = unsafe { ll::SDL_JoystickGetSerial(self.raw) };
+
+        if serial.is_null() {
+            None
+        } else {
+            Some(c_str_to_string(serial))
+        }
+    }
+
+    pub fn raw(&self) -> *mut ll::SDL_Joystick {
+        self.raw
+    }
+}
+
 impl Drop for Joystick {
     fn drop(&mut self) {
         unsafe { ll::SDL_JoystickClose(self.raw) }
     }
 }
 
+/// A joystick whose axes, buttons and hats are set by the program, e.g.
+/// to test input handling without a real device.
+///
+/// The joystick shows up like any other device: SDL sends a
+/// `JoyDeviceAdded` event for it and it can be opened by its device index.
+/// New values are reported, with the matching events, the next time the
+/// event loop is pumped or `update()` is called. The joystick is removed
+/// when this is dropped.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::controller::{Button, GameController};
+/// use sdl2::joystick::VirtualJoystick;
+///
+/// let _sdl_context = sdl2::init().game_controller().unwrap();
+///
+/// let mut pad = VirtualJoystick::attach_game_controller().unwrap();
+/// let controller = GameController::open(pad.device_index()).unwrap();
+///
+/// pad.set_button(Button::A as u32, true).unwrap();
+/// sdl2::controller::update();
+/// assert!(controller.get_button(Button::A));
+/// ```
+#[cfg(feature = "sdl_2_0_14")]
+pub struct VirtualJoystick {
+    joystick: Joystick,
+    instance_id: i32
+}
+
+#[cfg(feature = "sdl_2_0_14")]
+impl VirtualJoystick {
+    /// Attach a joystick with the given number of axes, buttons and hats.
+    pub fn attach(num_axes: u32, num_buttons: u32, num_hats: u32) -> SdlResult<VirtualJoystick> {
+        VirtualJoystick::attach_with_type(ll::SDL_JOYSTICK_TYPE_UNKNOWN, num_axes, num_buttons, num_hats)
+    }
+
+    /// Attach a joystick that is recognized as a game controller.
+    ///
+    /// Its axes and buttons are numbered like `controller::Axis` and
+    /// `controller::Button`.
+    pub fn attach_game_controller() -> SdlResult<VirtualJoystick> {
+        VirtualJoystick::attach_with_type(ll::SDL_JOYSTICK_TYPE_GAMECONTROLLER, 6, 15, 0)
+    }
+
+    fn attach_with_type(type_: ll::SDL_JoystickType, num_axes: u32, num_buttons: u32,
+                        num_hats: u32) -> SdlResult<VirtualJoystick> {
+        let num_axes = try!(u32_to_int!(num_axes));
+        let num_buttons = try!(u32_to_int!(num_buttons));
+        let num_hats = try!(u32_to_int!(num_hats));
+
+        let device_index = unsafe {
+            ll::SDL_JoystickAttachVirtual(type_, num_axes, num_buttons, num_hats)
+        };
+        if device_index < 0 {
+            return Err(get_error());
+        }
+
+        match Joystick::open(device_index) {
+            Ok(joystick) => Ok(VirtualJoystick {
+                instance_id: joystick.get_instance_id(),
+                joystick: joystick
+            }),
+            Err(e) => {
+                unsafe { ll::SDL_JoystickDetachVirtual(device_index) };
+                Err(e)
+            }
+        }
+    }
+
+    /// Return the device index to open the joystick with.
+    ///
+    /// Device indices shift when joysticks are added or removed, so the
+    /// index is only valid until the next hotplug event.
+    pub fn device_index(&self) -> i32 {
+        // Always found while the joystick is attached.
+        find_device_index(self.instance_id).unwrap_or(-1)
+    }
+
+    /// Return the joystick, opened to drive it.
+    pub fn joystick(&self) -> &Joystick {
+        &self.joystick
+    }
+
+    /// Set the position of `axis`.
+    pub fn set_axis(&mut self, axis: u32, value: i16) -> SdlResult<()> {
+        let axis = try!(u32_to_int!(axis));
+        let result = unsafe { ll::SDL_JoystickSetVirtualAxis(self.joystick.raw, axis, value) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Press or release `button`.
+    pub fn set_button(&mut self, button: u32, pressed: bool) -> SdlResult<()> {
+        let button = try!(u32_to_int!(button));
+        let result = unsafe {
+            ll::SDL_JoystickSetVirtualButton(self.joystick.raw, button, pressed as u8)
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Set the position of `hat`.
+    pub fn set_hat(&mut self, hat: u32, state: HatState) -> SdlResult<()> {
+        let hat = try!(u32_to_int!(hat));
+        let result = unsafe {
+            ll::SDL_JoystickSetVirtualHat(self.joystick.raw, hat, state.to_raw())
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+}
+
+#[cfg(feature = "sdl_2_0_14")]
+impl Drop for VirtualJoystick {
+    fn drop(&mut self) {
+        if let Some(device_index) = find_device_index(self.instance_id) {
+            unsafe { ll::SDL_JoystickDetachVirtual(device_index) };
+        }
+    }
+}
+
+/// Find the current device index of the joystick with `instance_id`.
+#[cfg(feature = "sdl_2_0_14")]
+fn find_device_index(instance_id: i32) -> Option<i32> {
+    let num_joysticks = unsafe { ll::SDL_NumJoysticks() };
+
+    (0..num_joysticks).find(|&index| unsafe {
+        ll::SDL_JoystickGetDeviceInstanceID(index) == instance_id
+    })
+}
+
 /// Wrapper around a SDL_JoystickGUID, a globally unique identifier
 /// for a joystick.
+///
+/// The GUID identifies the model of a joystick, and stays the same across
+/// runs of the program. Use it as the key for settings saved per device,
+/// like deadzones or bindings. Identical joysticks share the same GUID,
+/// though.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::joystick::{self, Guid};
+/// use std::collections::HashMap;
+///
+/// let _sdl_context = sdl2::init().joystick().unwrap();
+///
+/// let mut deadzones: HashMap<Guid, i16> = HashMap::new();
+/// deadzones.insert("030000005e0400008e02000014010000".parse().unwrap(), 8000);
+///
+/// let guid = joystick::get_device_guid(0).unwrap();
+/// let deadzone = deadzones.get(&guid).cloned().unwrap_or(4000);
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Guid {
     raw: ll::SDL_JoystickGUID,
@@ -311,6 +646,10 @@ impl Guid {
         c_str_to_string(c_str)
     }
 
+    pub fn from_raw(raw: ll::SDL_JoystickGUID) -> Guid {
+        Guid { raw: raw }
+    }
+
     /// Return a copy of the internal SDL_JoystickGUID
     pub fn raw(self) -> ll::SDL_JoystickGUID {
         self.raw
@@ -323,11 +662,77 @@ impl Display for Guid {
     }
 }
 
+impl Debug for Guid {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Guid({})", self.get_string())
+    }
+}
+
+impl FromStr for Guid {
+    type Err = NulError;
+
+    fn from_str(guid: &str) -> Result<Guid, NulError> {
+        Guid::from_string(guid)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Guid {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.get_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Guid {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Guid, D::Error> {
+        let guid = try!(<String as ::serde::Deserialize>::deserialize(deserializer));
+        Guid::from_string(&guid).map_err(::serde::de::Error::custom)
+    }
+}
+
+/// The battery level of a joystick, see `Joystick::power_level()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(i32)]
+pub enum PowerLevel {
+    Unknown = ll::SDL_JOYSTICK_POWER_UNKNOWN,
+    /// 5% of charge or less.
+    Empty   = ll::SDL_JOYSTICK_POWER_EMPTY,
+    /// 20% of charge or less.
+    Low     = ll::SDL_JOYSTICK_POWER_LOW,
+    /// 70% of charge or less.
+    Medium  = ll::SDL_JOYSTICK_POWER_MEDIUM,
+    /// Up to 100% of charge.
+    Full    = ll::SDL_JOYSTICK_POWER_FULL,
+    Wired   = ll::SDL_JOYSTICK_POWER_WIRED,
+}
+
+impl PowerLevel {
+    pub fn from_ll(level: ll::SDL_JoystickPowerLevel) -> PowerLevel {
+        match level {
+            ll::SDL_JOYSTICK_POWER_EMPTY  => PowerLevel::Empty,
+            ll::SDL_JOYSTICK_POWER_LOW    => PowerLevel::Low,
+            ll::SDL_JOYSTICK_POWER_MEDIUM => PowerLevel::Medium,
+            ll::SDL_JOYSTICK_POWER_FULL   => PowerLevel::Full,
+            ll::SDL_JOYSTICK_POWER_WIRED  => PowerLevel::Wired,
+            _ => PowerLevel::Unknown
+        }
+    }
+
+    /// Tests if the battery is running low, i.e. the player should be
+    /// warned to charge the joystick.
+    pub fn is_low(self) -> bool {
+        self == PowerLevel::Empty || self == PowerLevel::Low
+    }
+}
+
 /// This is represented in SDL2 as a bitfield but obviously not all
 /// combinations make sense: 5 for instance would mean up and down at
 /// the same time... To simplify things I turn it into an enum which
 /// is how the SDL2 docs present it anyway (using macros).
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HatState {
     Centered  = 0,
     Up        = 0x01,
@@ -341,7 +746,19 @@ pub enum HatState {
 }
 
 impl HatState {
+    /// Converts the bitmask reported by SDL.
+    ///
+    /// Some drivers report impossible positions like up and down at the
+    /// same time; such opposite directions cancel each other out.
     pub fn from_raw(raw: u8) -> HatState {
+        let mut raw = raw & 0x0f;
+        if raw & 0x05 == 0x05 {
+            raw &= !0x05;
+        }
+        if raw & 0x0a == 0x0a {
+            raw &= !0x0a;
+        }
+
         match raw {
             0  => HatState::Centered,
             1  => HatState::Up,
@@ -352,11 +769,42 @@ impl HatState {
             6  => HatState::RightDown,
             9  => HatState::LeftUp,
             12 => HatState::Leftdown,
-            _  => panic!("Unexpected hat position: {}", raw),
+            _  => unreachable!()
+        }
+    }
+
+    /// Returns the bitmask used by SDL.
+    pub fn to_raw(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the horizontal direction: -1 for left, 1 for right and 0
+    /// if centered.
+    pub fn x(self) -> i32 {
+        match self {
+            HatState::Left | HatState::LeftUp | HatState::Leftdown => -1,
+            HatState::Right | HatState::RightUp | HatState::RightDown => 1,
+            _ => 0
+        }
+    }
+
+    /// Returns the vertical direction: -1 for up, 1 for down and 0 if
+    /// centered.
+    pub fn y(self) -> i32 {
+        match self {
+            HatState::Up | HatState::LeftUp | HatState::RightUp => -1,
+            HatState::Down | HatState::Leftdown | HatState::RightDown => 1,
+            _ => 0
         }
     }
 }
 
+/// SDL reports unknown ids as 0.
+#[cfg(feature = "sdl_2_0_6")]
+fn non_zero(id: u16) -> Option<u16> {
+    if id == 0 { None } else { Some(id) }
+}
+
 /// Convert C string `c_str` to a String. Return an empty string if
 /// c_str is NULL.
 fn c_str_to_string(c_str: *const c_char) -> String {
@@ -380,3 +828,22 @@ fn c_str_to_string_or_err(c_str: *const c_char) -> SdlResult<String> {
         Ok(String::from_utf8_lossy(bytes).to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::HatState;
+
+    #[test]
+    fn test_hat_state() {
+        for raw in 0..16 {
+            let state = HatState::from_raw(raw);
+            assert_eq!(HatState::from_raw(state.to_raw()), state);
+        }
+
+        assert_eq!(HatState::from_raw(0x09), HatState::LeftUp);
+        assert_eq!(HatState::from_raw(0x05), HatState::Centered);
+        assert_eq!(HatState::from_raw(0x07), HatState::Right);
+        assert_eq!((HatState::RightDown.x(), HatState::RightDown.y()), (1, 1));
+        assert_eq!((HatState::Up.x(), HatState::Up.y()), (0, -1));
+    }
+}
@@ -0,0 +1,162 @@
+use event::Event;
+
+/// The text an input method is composing, as reported by
+/// `Event::TextEditing`.
+///
+/// Input methods for languages like Chinese or Japanese let the user
+/// compose text before committing it. While composing, the application
+/// should draw the composition text at the cursor of the text field, and
+/// call `set_text_input_rect()` so the input method can place its candidate
+/// window next to it. Once committed, the text arrives as an
+/// `Event::TextInput` and the composition ends.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::event::Event;
+/// use sdl2::keyboard::{self, Composition};
+/// use sdl2::rect::Rect;
+///
+/// let mut sdl_context = sdl2::init().video().unwrap();
+/// let mut field = String::new();
+/// let mut composition = Composition::new();
+///
+/// keyboard::start_text_input();
+/// keyboard::set_text_input_rect(&Rect::new_unwrap(100, 100, 200, 20));
+///
+/// for event in sdl_context.event_pump().poll_iter() {
+///     composition.handle_event(&event);
+///
+///     if let Event::TextInput { text, .. } = event {
+///         field.push_str(&text);
+///     }
+/// }
+///
+/// // Draw `field`, followed by `composition.text()` with
+/// // `composition.selected_text()` highlighted.
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Composition {
+    text: String,
+    cursor: usize,
+    selection_length: usize
+}
+
+impl Composition {
+    /// Creates an empty composition.
+    pub fn new() -> Composition {
+        Composition {
+            text: String::new(),
+            cursor: 0,
+            selection_length: 0
+        }
+    }
+
+    /// Updates the composition from a `TextEditing` or `TextInput` event.
+    ///
+    /// Returns `true` if the composition changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::TextEditing { ref text, start, length, .. } => {
+                let cursor = if start > 0 { start as usize } else { 0 };
+                let selection_length = if length > 0 { length as usize } else { 0 };
+
+                if self.text == *text && self.cursor == cursor &&
+                   self.selection_length == selection_length {
+                    return false;
+                }
+
+                self.text.clear();
+                self.text.push_str(text);
+                self.cursor = cursor;
+                self.selection_length = selection_length;
+                true
+            },
+            Event::TextInput {..} if self.is_active() => {
+                self.clear();
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Discards the composition.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+        self.selection_length = 0;
+    }
+
+    /// Tests if text is being composed.
+    pub fn is_active(&self) -> bool {
+        !self.text.is_empty()
+    }
+
+    /// The text being composed.
+    ///
+    /// SDL limits the composition text in an event to 31 bytes; longer
+    /// compositions are cut off.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The position of the cursor in the composition text, in characters.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The number of characters selected after the cursor.
+    pub fn selection_length(&self) -> usize {
+        self.selection_length
+    }
+
+    /// The position of the cursor in the composition text, in bytes.
+    pub fn cursor_byte_offset(&self) -> usize {
+        self.byte_offset(self.cursor)
+    }
+
+    /// The selected part of the composition text.
+    pub fn selected_text(&self) -> &str {
+        let start = self.byte_offset(self.cursor);
+        let end = self.byte_offset(self.cursor + self.selection_length);
+
+        &self.text[start..end]
+    }
+
+    fn byte_offset(&self, chars: usize) -> usize {
+        match self.text.char_indices().nth(chars) {
+            Some((offset, _)) => offset,
+            None => self.text.len()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Composition;
+    use event::Event;
+
+    #[test]
+    fn test_composition() {
+        let mut composition = Composition::new();
+        assert!(!composition.is_active());
+
+        let editing = Event::TextEditing {
+            timestamp: 0, window_id: 0,
+            text: "にほんご".to_owned(), start: 1, length: 2
+        };
+        assert!(composition.handle_event(&editing));
+        assert!(!composition.handle_event(&editing));
+
+        assert!(composition.is_active());
+        assert_eq!(composition.text(), "にほんご");
+        assert_eq!(composition.cursor(), 1);
+        assert_eq!(composition.cursor_byte_offset(), 3);
+        assert_eq!(composition.selected_text(), "ほん");
+
+        let input = Event::TextInput {
+            timestamp: 0, window_id: 0, text: "日本語".to_owned()
+        };
+        assert!(composition.handle_event(&input));
+        assert!(!composition.is_active());
+    }
+}
@@ -4,6 +4,7 @@ use std::ffi::{CString, CStr};
 use sys::keycode as ll;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Keycode {
     Backspace          = ll::SDLK_BACKSPACE as isize,
     Tab                = ll::SDLK_TAB as isize,
@@ -530,12 +531,16 @@ impl Keycode {
         }
     }
 
+    /// Gets the virtual key from its human-readable name, as returned by
+    /// `name()`. Returns None if the name isn't recognized.
     pub fn from_name(name: &str) -> Option<Keycode> {
         unsafe {
             match CString::new(name) {
                 Ok(name) => match ::sys::keyboard::SDL_GetKeyFromName(name.as_ptr()) {
                     ll::SDLK_UNKNOWN => None,
-                    keycode_id => Some(FromPrimitive::from_isize(keycode_id as isize).unwrap())
+                    // Names of characters without a `Keycode` variant, like "é",
+                    // also map to a key.
+                    keycode_id => FromPrimitive::from_isize(keycode_id as isize)
                 },
                 // string contains a nul byte - it won't match anything.
                 Err(_) => None
@@ -543,6 +548,11 @@ impl Keycode {
         }
     }
 
+    /// Gets the human-readable name of the virtual key, e.g. "Space" or "A".
+    ///
+    /// Use `Keycode::from_scancode(scancode).map(|key| key.name())` to
+    /// display the key at a physical position under the user's current
+    /// keyboard layout.
     pub fn name(self) -> String {
         // The name string pointer's contents _might_ change, depending on the last call to SDL_GetKeyName.
         // Knowing this, we must always return a new string.
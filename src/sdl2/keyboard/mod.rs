@@ -2,13 +2,16 @@ use num::{ToPrimitive, FromPrimitive};
 use std::ptr;
 
 use Sdl;
+use event::EventPump;
 use rect::Rect;
 use video::Window;
 
 use sys::keyboard as ll;
 
+mod composition;
 mod keycode;
 mod scancode;
+pub use self::composition::Composition;
 pub use self::keycode::Keycode;
 pub use self::scancode::Scancode;
 
@@ -30,6 +33,39 @@ bitflags! {
     }
 }
 
+impl Mod {
+    /// Tests if either Ctrl key is held down.
+    pub fn ctrl(&self) -> bool { self.intersects(LCTRLMOD | RCTRLMOD) }
+
+    /// Tests if either Shift key is held down.
+    pub fn shift(&self) -> bool { self.intersects(LSHIFTMOD | RSHIFTMOD) }
+
+    /// Tests if either Alt key is held down.
+    pub fn alt(&self) -> bool { self.intersects(LALTMOD | RALTMOD) }
+
+    /// Tests if either GUI key (Windows, Command or Super key) is held down.
+    pub fn gui(&self) -> bool { self.intersects(LGUIMOD | RGUIMOD) }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Mod {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Mod {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Mod, D::Error> {
+        let bits = try!(<u32 as ::serde::Deserialize>::deserialize(deserializer));
+        Ok(Mod::from_bits_truncate(bits))
+    }
+}
+
+/// Gets the id of the window that has the keyboard focus, if any.
+///
+/// Multi-window applications can compare it to `Window::get_id()` to decide
+/// which window a shortcut applies to; see also `Window::has_keyboard_focus()`.
 pub fn get_focused_window_id() -> Option<u32> {
     let raw = unsafe { ll::SDL_GetKeyboardFocus() };
     if raw == ptr::null_mut() {
@@ -40,21 +76,33 @@ pub fn get_focused_window_id() -> Option<u32> {
     }
 }
 
+unsafe fn get_keyboard_state<'a>() -> &'a [u8] {
+    let mut count = 0;
+    let state_ptr = ll::SDL_GetKeyboardState(&mut count);
+
+    ::std::slice::from_raw_parts(state_ptr, count as usize)
+}
+
+/// The state of every key, indexed by scancode.
 pub struct KeyboardState<'sdl> {
     keyboard_state: &'sdl [u8]
 }
 
 impl<'sdl> KeyboardState<'sdl> {
     pub fn new(_sdl: &Sdl) -> KeyboardState {
-        let keyboard_state = unsafe {
-            let mut count = 0;
-            let state_ptr = ll::SDL_GetKeyboardState(&mut count);
-
-            ::std::slice::from_raw_parts(state_ptr, count as usize)
-        };
+        KeyboardState {
+            keyboard_state: unsafe { get_keyboard_state() }
+        }
+    }
 
+    /// Returns the keyboard state as of the last time `event_pump` pumped
+    /// the event loop.
+    ///
+    /// The state can't change while it is borrowed, since pumping requires
+    /// a mutable borrow of the event pump.
+    pub fn from_event_pump(_event_pump: &'sdl EventPump) -> KeyboardState<'sdl> {
         KeyboardState {
-            keyboard_state: keyboard_state
+            keyboard_state: unsafe { get_keyboard_state() }
         }
     }
 
@@ -152,34 +200,72 @@ impl<'a> Iterator for PressedScancodeIterator<'a> {
     }
 }
 
+/// Gets the current key modifier state of the keyboard.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::keyboard::{self, Keycode};
+///
+/// fn is_save_shortcut(keycode: Keycode) -> bool {
+///     keycode == Keycode::S && keyboard::mod_state().ctrl()
+/// }
+/// ```
 pub fn mod_state() -> Mod {
-    unsafe { Mod::from_bits(ll::SDL_GetModState()).unwrap() }
+    unsafe { Mod::from_bits_truncate(ll::SDL_GetModState()) }
 }
 
+/// Sets the current key modifier state of the keyboard.
+///
+/// This doesn't change the keyboard state, only the modifiers SDL reports.
+/// It can be used to correct the state of toggles like Caps Lock.
 pub fn set_mod_state(flags: Mod) {
     unsafe { ll::SDL_SetModState(flags.bits()); }
 }
 
+/// Starts accepting Unicode text input, delivered as `Event::TextInput`
+/// and, while an input method composes text, `Event::TextEditing`.
+///
+/// On platforms with a screen keyboard, this shows it.
+///
+/// On desktop platforms, SDL already starts text input when the video
+/// subsystem is initialized. Games should call `stop_text_input()` at
+/// startup and only start it while a text field has focus, so input
+/// methods don't react to gameplay keys.
 pub fn start_text_input() {
     unsafe { ll::SDL_StartTextInput(); }
 }
 
+/// Tests if Unicode text input is accepted.
 pub fn is_text_input_active() -> bool {
     unsafe { ll::SDL_IsTextInputActive() == 1 }
 }
 
+/// Stops accepting Unicode text input.
 pub fn stop_text_input() {
     unsafe { ll::SDL_StopTextInput(); }
 }
 
+/// Sets the rectangle of the text field that is being edited, in window
+/// coordinates.
+///
+/// Input methods use it to place their candidate window next to the text
+/// being composed instead of covering it.
 pub fn set_text_input_rect(rect: &Rect) {
     unsafe { ll::SDL_SetTextInputRect(rect.raw()); }
 }
 
+/// Tests if the platform has a screen keyboard, as on Android and iOS.
+///
+/// The screen keyboard is shown by `start_text_input()` and hidden by
+/// `stop_text_input()`.
 pub fn has_screen_keyboard_support() -> bool {
     unsafe { ll::SDL_HasScreenKeyboardSupport() == 1 }
 }
 
+/// Tests if the screen keyboard is shown for `window`.
+///
+/// While it is, part of the window is covered, so the focused text field
+/// should be moved into the part that stays visible.
 pub fn is_screen_keyboard_shown(window: &Window) -> bool {
     unsafe { ll::SDL_IsScreenKeyboardShown(window.raw()) == 1 }
 }
@@ -4,6 +4,7 @@ use std::ffi::{CString, CStr};
 use sys::scancode as ll;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Scancode {
     A                  = ll::SDL_SCANCODE_A as isize,
     B                  = ll::SDL_SCANCODE_B as isize,
@@ -540,12 +541,14 @@ impl Scancode {
         }
     }
 
+    /// Gets the scancode from its name, as returned by `name()`.
+    /// Returns None if the name isn't recognized.
     pub fn from_name(name: &str) -> Option<Scancode> {
         unsafe {
             match CString::new(name) {
                 Ok(name) => match ::sys::keyboard::SDL_GetScancodeFromName(name.as_ptr()) {
                     ll::SDL_SCANCODE_UNKNOWN => None,
-                    scancode_id => Some(FromPrimitive::from_isize(scancode_id as isize).unwrap())
+                    scancode_id => FromPrimitive::from_isize(scancode_id as isize)
                 },
                 // string contains a nul byte - it won't match anything.
                 Err(_) => None
@@ -553,6 +556,11 @@ impl Scancode {
         }
     }
 
+    /// Gets the name of the scancode.
+    ///
+    /// Scancode names are those of a US keyboard layout, regardless of the
+    /// user's layout, which makes them suitable for saving key bindings but
+    /// not for display. See `Keycode::name()`.
     pub fn name(self) -> &'static str {
         // The name string pointer lives in static, read-only memory.
         // Knowing this, we can always return a string slice.
@@ -6,6 +6,15 @@ extern crate libc;
 #[macro_use]
 extern crate bitflags;
 extern crate sdl2_sys as sys;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+#[cfg(feature = "raw-window-handle")]
+extern crate raw_window_handle;
+#[cfg(feature = "glium")]
+extern crate glium;
 
 pub use sdl::*;
 
@@ -15,6 +24,7 @@ pub mod cpuinfo;
 pub mod event;
 pub mod filesystem;
 pub mod gesture;
+pub mod input;
 pub mod touch;
 pub mod joystick;
 pub mod controller;
@@ -33,5 +43,9 @@ pub mod audio;
 pub mod version;
 pub mod messagebox;
 pub mod hint;
+#[cfg(feature = "glium")]
+pub mod glium_backend;
 
 mod util;
+#[cfg(feature = "raw-window-handle")]
+mod raw_handle;
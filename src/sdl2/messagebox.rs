@@ -1,6 +1,9 @@
 use std::ffi::CString;
 use std::ptr;
 
+use libc::c_int;
+
+use pixels::Color;
 use video::Window;
 use get_error;
 use SdlResult;
@@ -16,6 +19,65 @@ bitflags! {
     }
 }
 
+bitflags! {
+    flags MessageBoxButtonFlag: u32 {
+        /// The button is pressed when the user hits Return.
+        const MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT = ll::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT,
+        /// The button is pressed when the user hits Escape.
+        const MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT = ll::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT
+    }
+}
+
+/// A button of a message box shown with `show_message_box()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ButtonData<'a> {
+    pub flags: MessageBoxButtonFlag,
+    /// An id of your choosing that identifies the button.
+    pub button_id: i32,
+    pub text: &'a str
+}
+
+/// The colors of a message box shown with `show_message_box()`.
+///
+/// Only some platforms (e.g. X11) honor color schemes. The alpha of each
+/// color is ignored.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MessageBoxColorScheme {
+    pub background: Color,
+    pub text: Color,
+    pub button_border: Color,
+    pub button_background: Color,
+    pub button_selected: Color
+}
+
+impl MessageBoxColorScheme {
+    fn to_ll(self) -> ll::SDL_MessageBoxColorScheme {
+        fn to_ll_color(color: Color) -> ll::SDL_MessageBoxColor {
+            let (r, g, b) = color.get_rgb();
+            ll::SDL_MessageBoxColor { r: r, g: g, b: b }
+        }
+
+        ll::SDL_MessageBoxColorScheme {
+            colors: [
+                to_ll_color(self.background),
+                to_ll_color(self.text),
+                to_ll_color(self.button_border),
+                to_ll_color(self.button_background),
+                to_ll_color(self.button_selected)
+            ]
+        }
+    }
+}
+
+/// The outcome of `show_message_box()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ClickedButton<'a> {
+    /// The message box was closed without pressing a button, e.g. with the
+    /// window's close button.
+    CloseButton,
+    CustomButton(&'a ButtonData<'a>)
+}
+
 pub fn show_simple_message_box(flags: MessageBoxFlag, title: &str, message: &str, window: Option<&Window>) -> SdlResult<()> {
     let result = unsafe {
         let title = CString::new(title).remove_nul();
@@ -32,3 +94,103 @@ pub fn show_simple_message_box(flags: MessageBoxFlag, title: &str, message: &str
         Err(get_error())
     }
 }
+
+/// Shows a message box with custom buttons and waits for the user to press
+/// one of them.
+///
+/// If `window` is given, the message box is modal to it.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::messagebox::*;
+///
+/// let buttons = [
+///     ButtonData { flags: MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT, button_id: 0, text: "Save" },
+///     ButtonData { flags: MessageBoxButtonFlag::empty(), button_id: 1, text: "Discard" },
+///     ButtonData { flags: MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT, button_id: 2, text: "Cancel" },
+/// ];
+///
+/// match show_message_box(MESSAGEBOX_WARNING, &buttons, "Quit",
+///                        "Save changes before quitting?", None, None).unwrap() {
+///     ClickedButton::CustomButton(button) => println!("{} was pressed", button.text),
+///     ClickedButton::CloseButton => println!("The message box was closed")
+/// }
+/// ```
+pub fn show_message_box<'a>(flags: MessageBoxFlag, buttons: &'a [ButtonData<'a>], title: &str,
+                            message: &str, window: Option<&Window>,
+                            scheme: Option<MessageBoxColorScheme>) -> SdlResult<ClickedButton<'a>> {
+    let title = CString::new(title).remove_nul();
+    let message = CString::new(message).remove_nul();
+    let texts: Vec<CString> = buttons.iter().map(|button| CString::new(button.text).remove_nul()).collect();
+    let ll_buttons: Vec<ll::SDL_MessageBoxButtonData> = buttons.iter().zip(texts.iter()).map(|(button, text)| {
+        ll::SDL_MessageBoxButtonData {
+            flags: button.flags.bits(),
+            buttonid: button.button_id as c_int,
+            text: text.as_ptr()
+        }
+    }).collect();
+    let scheme = scheme.map(|scheme| scheme.to_ll());
+
+    let data = ll::SDL_MessageBoxData {
+        flags: flags.bits(),
+        window: window.map_or(ptr::null_mut(), |win| unsafe { win.raw() }),
+        title: title.as_ptr(),
+        message: message.as_ptr(),
+        numbuttons: ll_buttons.len() as c_int,
+        buttons: ll_buttons.as_ptr(),
+        colorScheme: scheme.as_ref().map_or(ptr::null(), |scheme| scheme as *const ll::SDL_MessageBoxColorScheme)
+    };
+
+    let mut button_id: c_int = -1;
+    let result = unsafe { ll::SDL_ShowMessageBox(&data, &mut button_id) } == 0;
+
+    if result {
+        // SDL reports -1 if the message box was closed without a button.
+        match buttons.iter().find(|button| button.button_id == button_id as i32) {
+            Some(button) if button_id != -1 => Ok(ClickedButton::CustomButton(button)),
+            _ => Ok(ClickedButton::CloseButton)
+        }
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Asks the user a yes/no question and returns `true` if "Yes" was pressed.
+///
+/// Return selects "Yes" and Escape selects "No". Closing the message box, or
+/// failing to show it at all, counts as "No".
+///
+/// # Example
+/// ```no_run
+/// if sdl2::messagebox::confirm("Unsaved changes", "Discard your changes?") {
+///     // throw the document away
+/// }
+/// ```
+pub fn confirm(title: &str, text: &str) -> bool {
+    let buttons = [
+        ButtonData { flags: MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT, button_id: 1, text: "Yes" },
+        ButtonData { flags: MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT, button_id: 0, text: "No" }
+    ];
+
+    match show_message_box(MESSAGEBOX_INFORMATION, &buttons, title, text, None, None) {
+        Ok(ClickedButton::CustomButton(button)) => button.button_id == 1,
+        _ => false
+    }
+}
+
+/// Shows an error message with a single "OK" button.
+///
+/// This doesn't need SDL to be initialized, so it can be used to report
+/// failures that happen during startup.
+///
+/// # Example
+/// ```no_run
+/// let result: Result<(), String> = Err("could not load level".to_owned());
+///
+/// if let Err(e) = result {
+///     let _ = sdl2::messagebox::error("Fatal error", &e);
+/// }
+/// ```
+pub fn error(title: &str, text: &str) -> SdlResult<()> {
+    show_simple_message_box(MESSAGEBOX_ERROR, title, text, None)
+}
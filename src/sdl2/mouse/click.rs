@@ -0,0 +1,260 @@
+use event::Event;
+use super::Mouse;
+
+/// A click or drag recognized by a `ClickDetector`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ClickEvent {
+    /// A button was pressed and released without moving the mouse.
+    ///
+    /// `clicks` is 1 for a single click, 2 for a double click, 3 for a
+    /// triple click, and so on.
+    Click { button: Mouse, clicks: u32, x: i32, y: i32 },
+
+    /// The mouse moved past the threshold while a button was held down.
+    /// (`x`, `y`) is the position the button was pressed at.
+    DragStart { button: Mouse, x: i32, y: i32 }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Press {
+    button: Mouse,
+    timestamp: u32,
+    x: i32,
+    y: i32,
+    clicks: u32
+}
+
+/// Recognizes single, double and triple clicks and the start of drags
+/// from mouse button and motion events.
+///
+/// Presses of the same button count as one multiple click if each comes
+/// within the click interval of the previous one and the mouse stays
+/// within the movement threshold of where the first press happened.
+/// Moving farther than the threshold while a button is held down starts a
+/// drag instead, and a drag doesn't produce a click when released.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::mouse::{ClickDetector, ClickEvent};
+///
+/// let mut sdl_context = sdl2::init().video().unwrap();
+/// let mut clicks = ClickDetector::new();
+///
+/// for event in sdl_context.event_pump().poll_iter() {
+///     match clicks.handle_event(&event) {
+///         Some(ClickEvent::Click { clicks: 2, x, y, .. }) => println!("Double click at {}, {}", x, y),
+///         Some(ClickEvent::DragStart { x, y, .. }) => println!("Drag from {}, {}", x, y),
+///         _ => ()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClickDetector {
+    interval: u32,
+    threshold: i32,
+    pressed: Option<Press>,
+    dragging: bool,
+    last_click: Option<Press>
+}
+
+impl Default for ClickDetector {
+    fn default() -> ClickDetector {
+        ClickDetector::new()
+    }
+}
+
+impl ClickDetector {
+    /// Creates a detector with a click interval of 500 milliseconds and a
+    /// movement threshold of 4 pixels.
+    pub fn new() -> ClickDetector {
+        ClickDetector {
+            interval: 500,
+            threshold: 4,
+            pressed: None,
+            dragging: false,
+            last_click: None
+        }
+    }
+
+    /// The longest time between two presses of a multiple click, in
+    /// milliseconds.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Sets the longest time between two presses of a multiple click, in
+    /// milliseconds.
+    pub fn set_interval(&mut self, interval: u32) {
+        self.interval = interval;
+    }
+
+    /// How far the mouse may move, in pixels along either axis, before a
+    /// press becomes a drag or stops counting towards a multiple click.
+    pub fn threshold(&self) -> i32 {
+        self.threshold
+    }
+
+    /// Sets how far the mouse may move, in pixels along either axis, before
+    /// a press becomes a drag or stops counting towards a multiple click.
+    pub fn set_threshold(&mut self, threshold: i32) {
+        self.threshold = threshold;
+    }
+
+    /// Tests if a drag is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Forgets pressed buttons and earlier clicks.
+    pub fn reset(&mut self) {
+        self.pressed = None;
+        self.dragging = false;
+        self.last_click = None;
+    }
+
+    /// Updates the detector from a mouse event, returning the click or drag
+    /// it completes.
+    pub fn handle_event(&mut self, event: &Event) -> Option<ClickEvent> {
+        match *event {
+            Event::MouseButtonDown { timestamp, mouse_btn, x, y, .. } => {
+                if self.pressed.is_some() {
+                    // Pressing a second button cancels the click or drag.
+                    self.pressed = None;
+                    self.dragging = false;
+                    self.last_click = None;
+                    return None;
+                }
+
+                let clicks = match self.last_click {
+                    Some(last) if last.button == mouse_btn &&
+                                  timestamp.wrapping_sub(last.timestamp) <= self.interval &&
+                                  self.is_near(&last, x, y) => last.clicks + 1,
+                    _ => 1
+                };
+
+                self.pressed = Some(Press {
+                    button: mouse_btn,
+                    timestamp: timestamp,
+                    x: x,
+                    y: y,
+                    clicks: clicks
+                });
+                None
+            },
+            Event::MouseMotion { x, y, .. } => {
+                match self.pressed {
+                    Some(press) if !self.dragging && !self.is_near(&press, x, y) => {
+                        self.dragging = true;
+                        self.last_click = None;
+                        Some(ClickEvent::DragStart { button: press.button, x: press.x, y: press.y })
+                    },
+                    _ => None
+                }
+            },
+            Event::MouseButtonUp { mouse_btn, x, y, .. } => {
+                let press = match self.pressed {
+                    Some(press) if press.button == mouse_btn => press,
+                    _ => return None
+                };
+
+                self.pressed = None;
+
+                if self.dragging || !self.is_near(&press, x, y) {
+                    self.dragging = false;
+                    self.last_click = None;
+                    None
+                } else {
+                    // Later presses are measured against the first press
+                    // of the multiple click.
+                    let (anchor_x, anchor_y) = match self.last_click {
+                        Some(last) if press.clicks > 1 => (last.x, last.y),
+                        _ => (press.x, press.y)
+                    };
+                    self.last_click = Some(Press { x: anchor_x, y: anchor_y, ..press });
+                    Some(ClickEvent::Click { button: mouse_btn, clicks: press.clicks, x: x, y: y })
+                }
+            },
+            _ => None
+        }
+    }
+
+    fn is_near(&self, press: &Press, x: i32, y: i32) -> bool {
+        (x - press.x).abs() <= self.threshold && (y - press.y).abs() <= self.threshold
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClickDetector, ClickEvent};
+    use event::Event;
+    use mouse::{Mouse, MouseState};
+
+    fn down(timestamp: u32, x: i32, y: i32) -> Event {
+        Event::MouseButtonDown { timestamp: timestamp, window_id: 0, which: 0, mouse_btn: Mouse::Left, x: x, y: y }
+    }
+
+    fn up(timestamp: u32, x: i32, y: i32) -> Event {
+        Event::MouseButtonUp { timestamp: timestamp, window_id: 0, which: 0, mouse_btn: Mouse::Left, x: x, y: y }
+    }
+
+    fn motion(timestamp: u32, x: i32, y: i32) -> Event {
+        Event::MouseMotion {
+            timestamp: timestamp, window_id: 0, which: 0,
+            mousestate: MouseState::from_flags(1), x: x, y: y, xrel: 0, yrel: 0
+        }
+    }
+
+    #[test]
+    fn test_clicks() {
+        let mut detector = ClickDetector::new();
+
+        assert_eq!(detector.handle_event(&down(0, 10, 10)), None);
+        assert_eq!(detector.handle_event(&up(50, 10, 10)),
+                   Some(ClickEvent::Click { button: Mouse::Left, clicks: 1, x: 10, y: 10 }));
+        detector.handle_event(&down(200, 12, 11));
+        assert_eq!(detector.handle_event(&up(250, 12, 11)),
+                   Some(ClickEvent::Click { button: Mouse::Left, clicks: 2, x: 12, y: 11 }));
+        detector.handle_event(&down(400, 11, 9));
+        assert_eq!(detector.handle_event(&up(450, 11, 9)),
+                   Some(ClickEvent::Click { button: Mouse::Left, clicks: 3, x: 11, y: 9 }));
+
+        // Too late for a fourth click.
+        detector.handle_event(&down(1000, 10, 10));
+        assert_eq!(detector.handle_event(&up(1050, 10, 10)),
+                   Some(ClickEvent::Click { button: Mouse::Left, clicks: 1, x: 10, y: 10 }));
+    }
+
+    #[test]
+    fn test_drag() {
+        let mut detector = ClickDetector::new();
+
+        detector.handle_event(&down(0, 10, 10));
+        assert_eq!(detector.handle_event(&motion(10, 12, 12)), None);
+        assert_eq!(detector.handle_event(&motion(20, 30, 12)),
+                   Some(ClickEvent::DragStart { button: Mouse::Left, x: 10, y: 10 }));
+        assert!(detector.is_dragging());
+        assert_eq!(detector.handle_event(&motion(30, 40, 12)), None);
+        assert_eq!(detector.handle_event(&up(40, 40, 12)), None);
+        assert!(!detector.is_dragging());
+    }
+
+    #[test]
+    fn test_second_button_cancels_drag() {
+        let mut detector = ClickDetector::new();
+
+        detector.handle_event(&down(0, 10, 10));
+        detector.handle_event(&motion(10, 30, 10));
+        assert!(detector.is_dragging());
+
+        let right_down = Event::MouseButtonDown {
+            timestamp: 20, window_id: 0, which: 0, mouse_btn: Mouse::Right, x: 30, y: 10
+        };
+        assert_eq!(detector.handle_event(&right_down), None);
+        assert!(!detector.is_dragging());
+
+        // The next click is detected again.
+        detector.handle_event(&down(1000, 10, 10));
+        assert_eq!(detector.handle_event(&up(1050, 10, 10)),
+                   Some(ClickEvent::Click { button: Mouse::Left, clicks: 1, x: 10, y: 10 }));
+    }
+}
@@ -0,0 +1,604 @@
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use get_error;
+use SdlResult;
+use surface::SurfaceRef;
+use video;
+
+use sys::mouse as ll;
+
+mod click;
+pub use self::click::{ClickDetector, ClickEvent};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum SystemCursor {
+    Arrow = ll::SDL_SYSTEM_CURSOR_ARROW,
+    IBeam = ll::SDL_SYSTEM_CURSOR_IBEAM,
+    Wait = ll::SDL_SYSTEM_CURSOR_WAIT,
+    Crosshair = ll::SDL_SYSTEM_CURSOR_CROSSHAIR,
+    WaitArrow = ll::SDL_SYSTEM_CURSOR_WAITARROW,
+    SizeNWSE = ll::SDL_SYSTEM_CURSOR_SIZENWSE,
+    SizeNESW = ll::SDL_SYSTEM_CURSOR_SIZENESW,
+    SizeWE = ll::SDL_SYSTEM_CURSOR_SIZEWE,
+    SizeNS = ll::SDL_SYSTEM_CURSOR_SIZENS,
+    SizeAll = ll::SDL_SYSTEM_CURSOR_SIZEALL,
+    No = ll::SDL_SYSTEM_CURSOR_NO,
+    Hand = ll::SDL_SYSTEM_CURSOR_HAND,
+}
+
+/// A mouse cursor.
+///
+/// A cursor stays valid as long as it is alive. If the current cursor is
+/// dropped, SDL switches back to the default cursor.
+pub struct Cursor {
+    raw: *mut ll::SDL_Cursor
+}
+
+impl Drop for Cursor {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { ll::SDL_FreeCursor(self.raw) };
+    }
+}
+
+impl Cursor {
+    /// Creates a black and white cursor from bitmap data, in the format of
+    /// XBM files.
+    ///
+    /// `data` and `mask` hold one bit per pixel, most significant bit first,
+    /// and `width` must be a multiple of 8. Each pixel is:
+    ///
+    /// data | mask | pixel
+    /// -----|------|------
+    ///  0   |  1   | white
+    ///  1   |  1   | black
+    ///  0   |  0   | transparent
+    ///  1   |  0   | inverted, or black if not supported
+    pub fn new(data: &[u8], mask: &[u8], width: i32, height: i32, hot_x: i32, hot_y: i32) -> SdlResult<Cursor> {
+        if width <= 0 || height <= 0 || width % 8 != 0 {
+            return Err(format!("Invalid cursor size {}x{}: the width must be a positive multiple of 8", width, height));
+        }
+
+        let len = (width / 8 * height) as usize;
+        if data.len() != len || mask.len() != len {
+            return Err(format!("A {}x{} cursor needs {} bytes of data and mask", width, height, len));
+        }
+
+        unsafe {
+            let raw = ll::SDL_CreateCursor(data.as_ptr(),
+                                           mask.as_ptr(),
+                                           width as i32, height as i32,
+                                           hot_x as i32, hot_y as i32);
+
+            if raw == ptr::null_mut() {
+                Err(get_error())
+            } else {
+                Ok(Cursor{ raw: raw })
+            }
+        }
+    }
+
+    /// Creates a color cursor from a surface, with its hot spot at
+    /// (`hot_x`, `hot_y`).
+    pub fn from_surface<S: AsRef<SurfaceRef>>(surface: S, hot_x: i32, hot_y: i32) -> SdlResult<Cursor> {
+        unsafe {
+            let raw = ll::SDL_CreateColorCursor(surface.as_ref().raw(), hot_x, hot_y);
+
+            if raw == ptr::null_mut() {
+                Err(get_error())
+            } else {
+                Ok(Cursor{ raw: raw })
+            }
+        }
+    }
+
+    /// Creates one of the cursors of the operating system.
+    pub fn from_system(cursor: SystemCursor) -> SdlResult<Cursor> {
+        unsafe {
+            let raw = ll::SDL_CreateSystemCursor(cursor as u32);
+
+            if raw == ptr::null_mut() {
+                Err(get_error())
+            } else {
+                Ok(Cursor{ raw: raw })
+            }
+        }
+    }
+
+    /// Makes this the current cursor.
+    pub fn set(&self) {
+        unsafe { ll::SDL_SetCursor(self.raw); }
+    }
+
+    /// Makes this the current cursor, until the returned value is dropped.
+    ///
+    /// The cursor that was current before is restored then, if it still
+    /// exists.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::mouse::{Cursor, SystemCursor};
+    ///
+    /// let _sdl_context = sdl2::init().video().unwrap();
+    /// let wait = Cursor::from_system(SystemCursor::Wait).unwrap();
+    ///
+    /// {
+    ///     let _busy = wait.set_scoped();
+    ///     // Load something...
+    /// }
+    /// // The previous cursor is back.
+    /// ```
+    pub fn set_scoped(&self) -> ScopedCursor<'_> {
+        let previous = unsafe { ll::SDL_GetCursor() };
+        self.set();
+
+        ScopedCursor {
+            previous: previous,
+            _cursor: self
+        }
+    }
+}
+
+/// Restores the previous cursor when dropped. See `Cursor::set_scoped()`.
+#[must_use]
+pub struct ScopedCursor<'a> {
+    previous: *mut ll::SDL_Cursor,
+    _cursor: &'a Cursor
+}
+
+impl<'a> Drop for ScopedCursor<'a> {
+    fn drop(&mut self) {
+        // SDL ignores cursors that have been freed since.
+        unsafe { ll::SDL_SetCursor(self.previous); }
+    }
+}
+
+/// A cursor that cycles through a set of frames, like a loading spinner.
+///
+/// SDL only supports static cursors, so the animation has to be driven by
+/// the application: call `update()` (or `tick()`) once per frame of the main
+/// loop while the cursor is shown. Cursors can only be changed on the main
+/// thread, which is why this can't be driven by a `Timer`.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::mouse::AnimatedCursor;
+/// use sdl2::surface::Surface;
+/// use std::path::Path;
+///
+/// let mut sdl_context = sdl2::init().video().unwrap();
+/// let frames: Vec<Surface> = (0..8).map(|i| {
+///     Surface::load_bmp(&Path::new(&format!("spinner{}.bmp", i))).unwrap()
+/// }).collect();
+///
+/// let mut spinner = AnimatedCursor::from_surfaces(&frames, 16, 16, 100).unwrap();
+/// spinner.set();
+///
+/// loop {
+///     for _event in sdl_context.event_pump().poll_iter() {}
+///     spinner.update();
+/// }
+/// ```
+pub struct AnimatedCursor {
+    frames: Vec<Cursor>,
+    frame_duration: u32,
+    current: usize,
+    elapsed: u32,
+    last_update: Option<u32>
+}
+
+impl AnimatedCursor {
+    /// Creates an animated cursor showing each of `frames` for
+    /// `frame_duration` milliseconds.
+    pub fn new(frames: Vec<Cursor>, frame_duration: u32) -> SdlResult<AnimatedCursor> {
+        if frames.is_empty() {
+            return Err("An animated cursor needs at least one frame".to_owned());
+        }
+        if frame_duration == 0 {
+            return Err("The frame duration of an animated cursor must not be zero".to_owned());
+        }
+
+        Ok(AnimatedCursor {
+            frames: frames,
+            frame_duration: frame_duration,
+            current: 0,
+            elapsed: 0,
+            last_update: None
+        })
+    }
+
+    /// Creates an animated cursor from surfaces, which all share the hot
+    /// spot (`hot_x`, `hot_y`).
+    pub fn from_surfaces<S: AsRef<SurfaceRef>>(surfaces: &[S], hot_x: i32, hot_y: i32,
+                                               frame_duration: u32) -> SdlResult<AnimatedCursor> {
+        let mut frames = Vec::with_capacity(surfaces.len());
+        for surface in surfaces {
+            frames.push(try!(Cursor::from_surface(surface, hot_x, hot_y)));
+        }
+
+        AnimatedCursor::new(frames, frame_duration)
+    }
+
+    /// Makes the current frame the current cursor.
+    pub fn set(&self) {
+        self.frames[self.current].set();
+    }
+
+    /// Advances the animation by `elapsed` milliseconds, and updates the
+    /// current cursor if the frame changed.
+    pub fn tick(&mut self, elapsed: u32) {
+        let total = self.elapsed as u64 + elapsed as u64;
+        let frames = total / self.frame_duration as u64;
+        self.elapsed = (total % self.frame_duration as u64) as u32;
+
+        if frames > 0 {
+            self.current = ((self.current as u64 + frames) % self.frames.len() as u64) as usize;
+            self.set();
+        }
+    }
+
+    /// Advances the animation by the time passed since the last call,
+    /// measured with `timer::get_ticks()`.
+    pub fn update(&mut self) {
+        let now = ::timer::get_ticks();
+
+        if let Some(last_update) = self.last_update {
+            self.tick(now.wrapping_sub(last_update));
+        }
+        self.last_update = Some(now);
+    }
+
+    /// Restarts the animation at the first frame.
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed = 0;
+        self.last_update = None;
+    }
+
+    /// The index of the frame shown.
+    pub fn current_frame(&self) -> usize {
+        self.current
+    }
+
+    /// The number of frames.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The time each frame is shown, in milliseconds.
+    pub fn frame_duration(&self) -> u32 {
+        self.frame_duration
+    }
+
+    /// Sets the time each frame is shown, in milliseconds.
+    pub fn set_frame_duration(&mut self, frame_duration: u32) -> SdlResult<()> {
+        if frame_duration == 0 {
+            return Err("The frame duration of an animated cursor must not be zero".to_owned());
+        }
+
+        self.frame_duration = frame_duration;
+        self.elapsed %= frame_duration;
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Mouse {
+    Left,
+    Middle,
+    Right,
+    X1,
+    X2,
+    Unknown(u8)
+}
+
+/// The scroll direction setting of a mouse wheel.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MouseWheelDirection {
+    Normal,
+    /// The wheel deltas are inverted, e.g. due to "natural scrolling" on
+    /// Mac OS X. Multiply them by -1 to get the physical direction.
+    Flipped,
+    Unknown(u32)
+}
+
+impl MouseWheelDirection {
+    pub fn from_ll(direction: u32) -> MouseWheelDirection {
+        match direction {
+            ll::SDL_MOUSEWHEEL_NORMAL => MouseWheelDirection::Normal,
+            ll::SDL_MOUSEWHEEL_FLIPPED => MouseWheelDirection::Flipped,
+            _ => MouseWheelDirection::Unknown(direction)
+        }
+    }
+
+    pub fn to_ll(self) -> u32 {
+        match self {
+            MouseWheelDirection::Normal => ll::SDL_MOUSEWHEEL_NORMAL,
+            MouseWheelDirection::Flipped => ll::SDL_MOUSEWHEEL_FLIPPED,
+            MouseWheelDirection::Unknown(direction) => direction
+        }
+    }
+}
+
+/// The state of the mouse buttons.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MouseState {
+    flags: u32
+}
+
+impl MouseState {
+    /// Tests if a mouse button was pressed.
+    pub fn button(&self, button: Mouse) -> bool {
+        match button {
+            Mouse::Left => self.left(),
+            Mouse::Middle => self.middle(),
+            Mouse::Right => self.right(),
+            Mouse::X1 => self.x1(),
+            Mouse::X2 => self.x2(),
+            Mouse::Unknown(x) => {
+                assert!(x <= 32);
+                let mask = 1 << ((x as u32) - 1);
+                (self.flags & mask) != 0
+            }
+        }
+    }
+
+    /// Tests if the left mouse button was pressed.
+    pub fn left(&self) -> bool { (self.flags & ll::SDL_BUTTON_LMASK) != 0 }
+
+    /// Tests if the middle mouse button was pressed.
+    pub fn middle(&self) -> bool { (self.flags & ll::SDL_BUTTON_MMASK) != 0 }
+
+    /// Tests if the right mouse button was pressed.
+    pub fn right(&self) -> bool { (self.flags & ll::SDL_BUTTON_RMASK) != 0 }
+
+    /// Tests if the X1 mouse button was pressed.
+    pub fn x1(&self) -> bool { (self.flags & ll::SDL_BUTTON_X1MASK) != 0 }
+
+    /// Tests if the X2 mouse button was pressed.
+    pub fn x2(&self) -> bool { (self.flags & ll::SDL_BUTTON_X2MASK) != 0 }
+
+    /// Returns an iterator of pressed mouse buttons.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::mouse::Mouse;
+    /// use std::collections::HashSet;
+    ///
+    /// fn pressed_buttons(sdl_context: &mut sdl2::Sdl) -> HashSet<Mouse> {
+    ///     let (state, _, _) = sdl_context.event_pump().mouse_state();
+    ///     state.pressed_mouse_buttons().collect()
+    /// }
+    /// ```
+    pub fn pressed_mouse_buttons(&self) -> PressedMouseButtonIterator {
+        PressedMouseButtonIterator {
+            flags: self.flags,
+            index: 0
+        }
+    }
+
+    pub fn from_flags(flags: u32) -> MouseState {
+        MouseState { flags: flags }
+    }
+
+    pub fn to_flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+pub struct PressedMouseButtonIterator {
+    flags: u32,
+    index: u32
+}
+
+impl Iterator for PressedMouseButtonIterator {
+    type Item = Mouse;
+
+    fn next(&mut self) -> Option<Mouse> {
+        while self.index < 32 {
+            let index = self.index;
+            self.index += 1;
+
+            if (self.flags & (1 << index)) != 0 {
+                return Some(wrap_mouse(index as u8 + 1));
+            }
+        }
+
+        None
+    }
+}
+
+pub fn wrap_mouse(bitflags: u8) -> Mouse {
+    match bitflags {
+        1 => Mouse::Left,
+        2 => Mouse::Middle,
+        3 => Mouse::Right,
+        4 => Mouse::X1,
+        5 => Mouse::X2,
+        _ => Mouse::Unknown(bitflags)
+    }
+}
+
+pub fn unwrap_mouse(mouse: Mouse) -> u8 {
+    match mouse {
+        Mouse::Left => 1,
+        Mouse::Middle => 2,
+        Mouse::Right => 3,
+        Mouse::X1 => 4,
+        Mouse::X2 => 5,
+        Mouse::Unknown(x) => x
+    }
+}
+
+/// Gets the id of the window that the mouse is over, if any.
+///
+/// Multi-window applications can compare it to `Window::get_id()` to decide
+/// which window wheel events apply to; see also `Window::has_mouse_focus()`.
+pub fn get_focused_window_id() -> Option<u32> {
+    let raw = unsafe { ll::SDL_GetMouseFocus() };
+    if raw == ptr::null_mut() {
+        None
+    } else {
+        let id = unsafe { ::sys::video::SDL_GetWindowID(raw) };
+        Some(id)
+    }
+}
+
+pub fn get_mouse_state() -> (MouseState, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    unsafe {
+        let raw = ll::SDL_GetMouseState(&mut x, &mut y);
+        return (MouseState::from_flags(raw), x as i32, y as i32);
+    }
+}
+
+/// Returns the button state and the mouse motion accumulated since the
+/// last call.
+///
+/// In relative mouse mode, the motion keeps accumulating even though the
+/// cursor doesn't move.
+pub fn get_relative_mouse_state() -> (MouseState, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    unsafe {
+        let raw = ll::SDL_GetRelativeMouseState(&mut x, &mut y);
+        return (MouseState::from_flags(raw), x as i32, y as i32);
+    }
+}
+
+/// Returns the button state and the position of the mouse on the desktop.
+///
+/// Unlike `get_mouse_state()`, this queries the operating system directly,
+/// so the position is up to date even if the mouse is outside all windows.
+#[cfg(feature = "sdl_2_0_4")]
+pub fn get_global_mouse_state() -> (MouseState, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    unsafe {
+        let raw = ll::SDL_GetGlobalMouseState(&mut x, &mut y);
+        (MouseState::from_flags(raw), x, y)
+    }
+}
+
+/// Moves the mouse to a position within a window.
+pub fn warp_mouse_in_window(window: &video::Window, x: i32, y: i32) {
+    unsafe { ll::SDL_WarpMouseInWindow(window.raw(), x, y); }
+}
+
+/// Moves the mouse to a position on the desktop.
+#[cfg(feature = "sdl_2_0_4")]
+pub fn warp_mouse_global(x: i32, y: i32) {
+    unsafe { ll::SDL_WarpMouseGlobal(x, y); }
+}
+
+/// Captures the mouse, so that the focused window keeps receiving mouse
+/// events while the mouse is outside of it.
+///
+/// This is meant for drag operations: capture the mouse when a button is
+/// pressed and release the capture when it is released.
+#[cfg(feature = "sdl_2_0_4")]
+pub fn capture_mouse(enable: bool) -> SdlResult<()> {
+    let result = unsafe { ll::SDL_CaptureMouse(enable as i32) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Enables or disables relative mouse mode.
+///
+/// In relative mouse mode, the cursor is hidden and confined to the focused
+/// window, and only relative motion is reported, also when the mouse would
+/// otherwise hit the edge of the screen. This is what first-person camera
+/// controls need.
+///
+/// Returns an error if relative mouse mode isn't supported.
+pub fn set_relative_mouse_mode(on: bool) -> SdlResult<()> {
+    let result = unsafe { ll::SDL_SetRelativeMouseMode(on as i32) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Tests if relative mouse mode is enabled.
+pub fn relative_mouse_mode() -> bool {
+    unsafe { ll::SDL_GetRelativeMouseMode() == 1 }
+}
+
+#[deprecated(note = "renamed to `relative_mouse_mode()`")]
+pub fn get_relative_mouse_mode() -> bool {
+    relative_mouse_mode()
+}
+
+/// Tests if the cursor is shown.
+pub fn is_cursor_showing() -> bool {
+    unsafe { ll::SDL_ShowCursor(ll::SDL_QUERY) == 1 }
+}
+
+/// Shows or hides the cursor.
+///
+/// Prefer `hide_cursor()` to hide the cursor temporarily.
+pub fn show_cursor(show: bool) {
+    unsafe { ll::SDL_ShowCursor(show as i32); }
+}
+
+/// The number of `HiddenCursor` guards alive.
+static HIDDEN_CURSOR_GUARDS: AtomicUsize = AtomicUsize::new(0);
+/// Whether the cursor was shown when the first guard was created.
+static CURSOR_WAS_SHOWN: AtomicBool = AtomicBool::new(false);
+
+/// Hides the cursor until the returned guard is dropped.
+///
+/// Guards can be nested: the cursor is only shown again once all of them
+/// have been dropped, and only if it was shown before the first one was
+/// created. Since the guard is dropped on early returns and panics, the
+/// cursor can't be left hidden by accident.
+///
+/// # Example
+/// ```no_run
+/// let _sdl_context = sdl2::init().video().unwrap();
+///
+/// {
+///     let _hidden = sdl2::mouse::hide_cursor();
+///     // Play...
+/// }
+/// // The cursor is shown again.
+/// ```
+pub fn hide_cursor() -> HiddenCursor {
+    if HIDDEN_CURSOR_GUARDS.fetch_add(1, Ordering::Relaxed) == 0 {
+        CURSOR_WAS_SHOWN.store(is_cursor_showing(), Ordering::Relaxed);
+        show_cursor(false);
+    }
+
+    HiddenCursor {
+        _nosend: PhantomData
+    }
+}
+
+/// Keeps the cursor hidden while alive. See `hide_cursor()`.
+#[must_use]
+pub struct HiddenCursor {
+    // The cursor can only be shown and hidden on the main thread.
+    _nosend: PhantomData<*mut ()>
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        if HIDDEN_CURSOR_GUARDS.fetch_sub(1, Ordering::Relaxed) == 1 &&
+           CURSOR_WAS_SHOWN.load(Ordering::Relaxed) {
+            show_cursor(true);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Implementations of the `raw-window-handle` traits for `Window`, enabled
+//! with the `raw-window-handle` feature.
+//!
+//! They let graphics libraries such as wgpu or ash create a surface for an
+//! SDL window without any platform-specific code:
+//!
+//! ```ignore
+//! let window = sdl_context.window("wgpu", 800, 600).build().unwrap();
+//! let surface = instance.create_surface(&window).unwrap();
+//! ```
+
+use std::mem;
+use std::num::NonZeroIsize;
+use std::os::raw;
+use std::ptr::NonNull;
+
+use libc::c_void;
+use raw_window_handle::{
+    AndroidDisplayHandle, AndroidNdkWindowHandle, AppKitDisplayHandle, DisplayHandle,
+    HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle, RawWindowHandle,
+    UiKitDisplayHandle, WaylandDisplayHandle, WaylandWindowHandle, Win32WindowHandle,
+    WindowHandle, WindowsDisplayHandle, XlibDisplayHandle, XlibWindowHandle
+};
+#[cfg(target_os = "macos")]
+use raw_window_handle::AppKitWindowHandle;
+#[cfg(target_os = "ios")]
+use raw_window_handle::UiKitWindowHandle;
+
+use video::Window;
+
+use sys::syswm as ll;
+use sys::version::SDL_version;
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+mod objc {
+    use libc::{c_char, c_void};
+
+    #[link(name = "objc")]
+    extern "C" {
+        fn sel_registerName(name: *const c_char) -> *mut c_void;
+        fn objc_msgSend(receiver: *mut c_void, selector: *mut c_void) -> *mut c_void;
+    }
+
+    /// Sends a message without arguments that returns an object, e.g.
+    /// `[window contentView]`. `selector` must be nul-terminated.
+    pub unsafe fn send(receiver: *mut c_void, selector: &[u8]) -> *mut c_void {
+        objc_msgSend(receiver, sel_registerName(selector.as_ptr() as *const c_char))
+    }
+}
+
+fn get_wm_info(window: &Window) -> Result<ll::SDL_SysWMinfo, HandleError> {
+    unsafe {
+        let mut info: ll::SDL_SysWMinfo = mem::zeroed();
+        info.version = SDL_version { major: 2, minor: 0, patch: 4 };
+
+        if ll::SDL_GetWindowWMInfo(window.raw(), &mut info) == 1 {
+            Ok(info)
+        } else {
+            Err(HandleError::Unavailable)
+        }
+    }
+}
+
+/// Converts an SDL pointer to the `NonNull` that `raw-window-handle` expects.
+fn non_null(ptr: *mut c_void) -> Result<NonNull<raw::c_void>, HandleError> {
+    NonNull::new(ptr as *mut raw::c_void).ok_or(HandleError::Unavailable)
+}
+
+impl HasWindowHandle for Window {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        let info = try!(get_wm_info(self));
+
+        let raw: RawWindowHandle = unsafe {
+            match info.subsystem {
+                ll::SDL_SYSWM_WINDOWS => {
+                    let hwnd = try!(NonZeroIsize::new(info.info.win.window as isize).ok_or(HandleError::Unavailable));
+                    Win32WindowHandle::new(hwnd).into()
+                },
+                ll::SDL_SYSWM_X11 => XlibWindowHandle::new(info.info.x11.window).into(),
+                ll::SDL_SYSWM_WAYLAND => WaylandWindowHandle::new(try!(non_null(info.info.wl.surface))).into(),
+                #[cfg(target_os = "macos")]
+                ll::SDL_SYSWM_COCOA => {
+                    let ns_view = objc::send(info.info.cocoa.window, b"contentView\0");
+                    AppKitWindowHandle::new(try!(non_null(ns_view))).into()
+                },
+                #[cfg(target_os = "ios")]
+                ll::SDL_SYSWM_UIKIT => {
+                    let view_controller = objc::send(info.info.uikit.window, b"rootViewController\0");
+                    let ui_view = objc::send(view_controller, b"view\0");
+                    UiKitWindowHandle::new(try!(non_null(ui_view))).into()
+                },
+                ll::SDL_SYSWM_ANDROID => AndroidNdkWindowHandle::new(try!(non_null(info.info.android.window))).into(),
+                _ => return Err(HandleError::NotSupported)
+            }
+        };
+
+        // The handle borrows the window, so it can't outlive it.
+        Ok(unsafe { WindowHandle::borrow_raw(raw) })
+    }
+}
+
+impl HasDisplayHandle for Window {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        let info = try!(get_wm_info(self));
+
+        let raw: RawDisplayHandle = unsafe {
+            match info.subsystem {
+                ll::SDL_SYSWM_WINDOWS => WindowsDisplayHandle::new().into(),
+                ll::SDL_SYSWM_X11 => XlibDisplayHandle::new(non_null(info.info.x11.display).ok(), 0).into(),
+                ll::SDL_SYSWM_WAYLAND => WaylandDisplayHandle::new(try!(non_null(info.info.wl.display))).into(),
+                ll::SDL_SYSWM_COCOA => AppKitDisplayHandle::new().into(),
+                ll::SDL_SYSWM_UIKIT => UiKitDisplayHandle::new().into(),
+                ll::SDL_SYSWM_ANDROID => AndroidDisplayHandle::new().into(),
+                _ => return Err(HandleError::NotSupported)
+            }
+        };
+
+        Ok(unsafe { DisplayHandle::borrow_raw(raw) })
+    }
+}
@@ -2,6 +2,7 @@ use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 
 use sys::sdl as ll;
+use sys::video::{SDL_GetCurrentVideoDriver, SDL_VideoInit, SDL_VideoQuit};
 use event::EventPump;
 use keyboard::KeyboardState;
 use video::WindowBuilder;
@@ -39,6 +40,9 @@ static IS_SDL_CONTEXT_ALIVE: AtomicBool = ATOMIC_BOOL_INIT;
 /// This guarantees that the only way to call event-pumping functions is on
 /// the main thread.
 pub struct Sdl {
+    /// Set when video was initialized with `SDL_VideoInit`, which
+    /// `SDL_Quit` doesn't undo.
+    quit_video: bool,
     _nosyncsend: PhantomData<*mut ()>
 }
 
@@ -72,7 +76,12 @@ impl Drop for Sdl {
         let was_alive = IS_SDL_CONTEXT_ALIVE.swap(false, Ordering::Relaxed);
         assert!(was_alive);
 
-        unsafe { ll::SDL_Quit(); }
+        unsafe {
+            if self.quit_video {
+                SDL_VideoQuit();
+            }
+            ll::SDL_Quit();
+        }
     }
 }
 
@@ -82,24 +91,31 @@ impl Drop for Sdl {
 /// the specified subsystems' ref-counts are decremented via `SDL_QuitSubSystem`.
 pub struct Subsystem<'sdl> {
     flags: u32,
+    quit_video: bool,
     _marker: PhantomData<&'sdl Sdl>
 }
 
 impl<'sdl> Drop for Subsystem<'sdl> {
     fn drop(&mut self) {
-        unsafe { ll::SDL_QuitSubSystem(self.flags); }
+        unsafe {
+            if self.quit_video {
+                SDL_VideoQuit();
+            }
+            ll::SDL_QuitSubSystem(self.flags);
+        }
     }
 }
 
 /// The type that allows you to build the SDL2 context.
 pub struct InitBuilder {
-    flags: u32
+    flags: u32,
+    video_driver: Option<CString>
 }
 
 impl InitBuilder {
     /// Initializes a new `InitBuilder`.
     pub fn new() -> InitBuilder {
-        InitBuilder { flags: 0 }
+        InitBuilder { flags: 0, video_driver: None }
     }
 
     /// Builds the SDL2 context.
@@ -113,13 +129,21 @@ impl InitBuilder {
             if was_alive {
                 Err(format!("Cannot have more than one `Sdl` in use at the same time"))
             } else {
-                if ll::SDL_Init(self.flags) == 0 {
-                    Ok(Sdl {
-                        _nosyncsend: PhantomData
-                    })
-                } else {
+                if ll::SDL_Init(self.subsystem_flags()) != 0 {
                     IS_SDL_CONTEXT_ALIVE.swap(false, Ordering::Relaxed);
-                    Err(get_error())
+                    return Err(get_error());
+                }
+
+                match self.init_video_driver() {
+                    Ok(quit_video) => Ok(Sdl {
+                        quit_video: quit_video,
+                        _nosyncsend: PhantomData
+                    }),
+                    Err(e) => {
+                        ll::SDL_Quit();
+                        IS_SDL_CONTEXT_ALIVE.swap(false, Ordering::Relaxed);
+                        Err(e)
+                    }
                 }
             }
         }
@@ -132,14 +156,23 @@ impl InitBuilder {
 
     /// Builds an SDL2 subsystem. Requires SDL2 to have already been initialized.
     pub fn build_subsystem(&self, _sdl: &Sdl) -> SdlResult<Subsystem> {
+        let flags = self.subsystem_flags();
+
         unsafe {
-            if ll::SDL_InitSubSystem(self.flags) == 0 {
-                Ok(Subsystem {
-                    flags: self.flags,
+            if ll::SDL_InitSubSystem(flags) != 0 {
+                return Err(get_error());
+            }
+
+            match self.init_video_driver() {
+                Ok(quit_video) => Ok(Subsystem {
+                    flags: flags,
+                    quit_video: quit_video,
                     _marker: PhantomData
-                })
-            } else {
-                Err(get_error())
+                }),
+                Err(e) => {
+                    ll::SDL_QuitSubSystem(flags);
+                    Err(e)
+                }
             }
         }
     }
@@ -168,6 +201,53 @@ impl InitBuilder {
         self
     }
 
+    /// Initializes the video subsystem with the named driver, e.g. "dummy"
+    /// for headless tests, or "wayland" or "x11" to pick a backend.
+    ///
+    /// Initialization fails if the driver isn't available. See
+    /// `video::drivers()` for the drivers compiled into SDL.
+    ///
+    /// The video subsystem is then started with `SDL_VideoInit` instead of
+    /// `SDL_Init`, so `Sdl::was_init()` doesn't report it. Initialization
+    /// fails if video is already initialized, as SDL would restart it with
+    /// the new driver and invalidate existing windows.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video_driver("dummy").unwrap();
+    /// assert_eq!(sdl2::video::current_driver(), Some("dummy".to_owned()));
+    /// ```
+    pub fn video_driver(&mut self, name: &str) -> &mut InitBuilder {
+        self.video_driver = Some(CString::new(name).remove_nul());
+        self.video()
+    }
+
+    /// The flags to pass to `SDL_Init`; video is left out when it's
+    /// started by `init_video_driver()`.
+    fn subsystem_flags(&self) -> u32 {
+        match self.video_driver {
+            Some(_) => self.flags & !(ll::SDL_INIT_VIDEO as u32),
+            None => self.flags
+        }
+    }
+
+    /// Starts video with the chosen driver, if any. Returns whether it did.
+    fn init_video_driver(&self) -> SdlResult<bool> {
+        match self.video_driver {
+            Some(ref name) => unsafe {
+                let video = ll::SDL_INIT_VIDEO as u32;
+                if ll::SDL_WasInit(video) != 0 || !SDL_GetCurrentVideoDriver().is_null() {
+                    Err("The video subsystem is already initialized".to_owned())
+                } else if SDL_VideoInit(name.as_ptr()) == 0 {
+                    Ok(true)
+                } else {
+                    Err(get_error())
+                }
+            },
+            None => Ok(false)
+        }
+    }
+
     /// Initializes the joystick subsystem.
     pub fn joystick(&mut self) -> &mut InitBuilder {
         self.flags |= ll::SDL_INIT_JOYSTICK as u32;
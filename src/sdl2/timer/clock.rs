@@ -0,0 +1,157 @@
+//! A game clock decoupled from wall-clock time.
+use std::time::Duration;
+
+use super::PerformanceCounter;
+
+/// Measures gameplay time, which can be paused, slowed down or sped up.
+///
+/// Call `tick()` once per frame; it returns how much gameplay time passed
+/// since the previous tick.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use sdl2::timer::GameClock;
+///
+/// let mut clock = GameClock::new();
+/// clock.set_max_delta(Some(Duration::from_millis(100)));
+///
+/// loop {
+///     let delta = clock.tick();
+///     // update the game by `delta`...
+/// #   let _ = delta;
+/// }
+/// ```
+pub struct GameClock {
+    last: PerformanceCounter,
+    elapsed: Duration,
+    delta: Duration,
+    scale: f64,
+    max_delta: Option<Duration>,
+    paused: bool,
+    /// Gameplay time from before a pause, which the next tick returns.
+    pending: Duration
+}
+
+impl GameClock {
+    /// Creates a running clock at normal speed, starting now.
+    pub fn new() -> GameClock {
+        GameClock {
+            last: PerformanceCounter::now(),
+            elapsed: Duration::new(0, 0),
+            delta: Duration::new(0, 0),
+            scale: 1.0,
+            max_delta: None,
+            paused: false,
+            pending: Duration::new(0, 0)
+        }
+    }
+
+    /// Advances the clock, and returns the gameplay time since the previous
+    /// tick.
+    ///
+    /// The wall-clock time is capped to the maximum delta before being
+    /// scaled; while paused, only the time up to the pause is counted.
+    pub fn tick(&mut self) -> Duration {
+        let delta = self.advance();
+        self.delta = self.pending.saturating_add(delta);
+        self.pending = Duration::new(0, 0);
+        self.elapsed = self.elapsed.saturating_add(self.delta);
+        self.delta
+    }
+
+    /// Takes the gameplay time since `last`, without counting it yet.
+    fn advance(&mut self) -> Duration {
+        let now = PerformanceCounter::now();
+        let real = now - self.last;
+        self.last = now;
+
+        if self.paused {
+            Duration::new(0, 0)
+        } else {
+            scale_delta(real, self.max_delta, self.scale)
+        }
+    }
+
+    /// The gameplay time returned by the last `tick()`.
+    pub fn delta(&self) -> Duration { self.delta }
+
+    /// The total gameplay time up to the last `tick()`.
+    pub fn elapsed(&self) -> Duration { self.elapsed }
+
+    pub fn is_paused(&self) -> bool { self.paused }
+
+    /// Stops gameplay time. The time before the pause is still returned by
+    /// the next tick.
+    pub fn pause(&mut self) {
+        if !self.paused {
+            let delta = self.advance();
+            self.pending = self.pending.saturating_add(delta);
+            self.paused = true;
+        }
+    }
+
+    /// Restarts gameplay time; the time spent paused is not counted.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.last = PerformanceCounter::now();
+            self.paused = false;
+        }
+    }
+
+    pub fn scale(&self) -> f64 { self.scale }
+
+    /// Sets how fast gameplay time passes relative to wall-clock time, e.g.
+    /// 0.5 for slow motion.
+    ///
+    /// Negative and NaN scales stop gameplay time like 0.0, and an infinite
+    /// scale is limited to the largest finite one.
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = if scale.is_nan() { 0.0 } else { scale.clamp(0.0, f64::MAX) };
+    }
+
+    pub fn max_delta(&self) -> Option<Duration> { self.max_delta }
+
+    /// Sets the longest wall-clock time a single tick can count, so a long
+    /// stall (e.g. dragging the window) doesn't make the game jump ahead.
+    pub fn set_max_delta(&mut self, max_delta: Option<Duration>) {
+        self.max_delta = max_delta;
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> GameClock { GameClock::new() }
+}
+
+fn scale_delta(real: Duration, max_delta: Option<Duration>, scale: f64) -> Duration {
+    let capped = match max_delta {
+        Some(max) if real > max => max,
+        _ => real
+    };
+    // `Duration::mul_f64()` panics if the result doesn't fit in a duration.
+    let secs = capped.as_secs_f64() * scale;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(delta) => delta,
+        Err(_) if secs > 0.0 => Duration::MAX,
+        Err(_) => Duration::new(0, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+    use super::scale_delta;
+
+    #[test]
+    fn test_scale_delta() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(scale_delta(ms(10), None, 1.0), ms(10));
+        assert_eq!(scale_delta(ms(10), None, 0.5), ms(5));
+        assert_eq!(scale_delta(ms(500), Some(ms(100)), 1.0), ms(100));
+        assert_eq!(scale_delta(ms(500), Some(ms(100)), 2.0), ms(200));
+        assert_eq!(scale_delta(ms(10), Some(ms(100)), 0.0), ms(0));
+        assert_eq!(scale_delta(ms(10), None, f64::MAX), Duration::MAX);
+        assert_eq!(scale_delta(ms(0), None, f64::MAX), ms(0));
+    }
+}
@@ -0,0 +1,187 @@
+//! Helpers for the timing of a game loop.
+use std::thread;
+use std::time::Duration;
+
+use super::{delay, to_interval, PerformanceCounter};
+
+/// Caps the frame rate of a loop.
+///
+/// `SDL_Delay()` often oversleeps by a millisecond or more, so the limiter
+/// sleeps for most of the remaining frame time and busy-waits for the rest.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::timer::FrameLimiter;
+///
+/// let mut limiter = FrameLimiter::new(60);
+///
+/// loop {
+///     // handle events, update and render...
+///     let frame_time = limiter.wait();
+/// #   let _ = frame_time;
+/// }
+/// ```
+pub struct FrameLimiter {
+    frame_time: Duration,
+    spin_time: Duration,
+    last: PerformanceCounter
+}
+
+impl FrameLimiter {
+    /// Creates a limiter for the given number of frames per second.
+    ///
+    /// Panics if `fps` is 0.
+    pub fn new(fps: u32) -> FrameLimiter {
+        assert!(fps > 0, "the frame rate must be positive");
+        FrameLimiter::with_frame_time(Duration::from_secs(1) / fps)
+    }
+
+    /// Creates a limiter that makes every frame last at least `frame_time`.
+    pub fn with_frame_time(frame_time: Duration) -> FrameLimiter {
+        FrameLimiter {
+            frame_time: frame_time,
+            spin_time: Duration::from_millis(2),
+            last: PerformanceCounter::now()
+        }
+    }
+
+    pub fn frame_time(&self) -> Duration { self.frame_time }
+
+    /// Sets how long before the end of the frame the limiter stops sleeping
+    /// and busy-waits instead; defaults to 2 ms.
+    ///
+    /// Longer times are more precise but use more CPU time.
+    pub fn set_spin_time(&mut self, spin_time: Duration) {
+        self.spin_time = spin_time;
+    }
+
+    /// Waits until the frame time has passed since the previous call (or
+    /// since the limiter was created), and returns the actual time since
+    /// then, which is the time the frame took.
+    ///
+    /// Returns immediately if the frame took longer than the frame time.
+    pub fn wait(&mut self) -> Duration {
+        let elapsed = self.last.elapsed();
+        if elapsed < self.frame_time {
+            let remaining = self.frame_time - elapsed;
+            match remaining.checked_sub(self.spin_time) {
+                Some(sleep) if sleep >= Duration::from_millis(1) => delay(to_interval(sleep)),
+                _ => ()
+            }
+
+            while self.last.elapsed() < self.frame_time {
+                thread::yield_now();
+            }
+        }
+
+        let now = PerformanceCounter::now();
+        let frame_time = now - self.last;
+        self.last = now;
+        frame_time
+    }
+}
+
+/// Runs game updates at a fixed rate, independently of the frame rate.
+///
+/// Each frame, pass the frame time to `advance()`, run as many update steps
+/// as it returns, and render with `alpha()` to interpolate between the
+/// previous and the current state.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use sdl2::timer::{FixedTimestep, PerformanceCounter};
+///
+/// let mut timestep = FixedTimestep::new(Duration::from_millis(10));
+/// let mut last = PerformanceCounter::now();
+///
+/// loop {
+///     let now = PerformanceCounter::now();
+///     let frame_time = now - last;
+///     last = now;
+///
+///     for _ in 0..timestep.advance(frame_time) {
+///         // update the game by `timestep.step()`
+///     }
+///
+///     let alpha = timestep.alpha();
+///     // render the game, interpolating by `alpha`
+/// #   let _ = alpha;
+/// }
+/// ```
+pub struct FixedTimestep {
+    step: Duration,
+    accumulator: Duration,
+    max_steps: u32
+}
+
+impl FixedTimestep {
+    /// Creates a fixed timestep of `step` per update.
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(step: Duration) -> FixedTimestep {
+        assert!(step > Duration::new(0, 0), "the step must be positive");
+        FixedTimestep {
+            step: step,
+            accumulator: Duration::new(0, 0),
+            max_steps: 8
+        }
+    }
+
+    pub fn step(&self) -> Duration { self.step }
+
+    /// Sets the most update steps to run in one frame; defaults to 8.
+    ///
+    /// If updates are slower than real time, the game would otherwise fall
+    /// further behind every frame. Time beyond the limit is dropped, so the
+    /// game slows down instead.
+    pub fn set_max_steps(&mut self, max_steps: u32) {
+        self.max_steps = max_steps;
+    }
+
+    /// Adds the time the last frame took, and returns how many update steps
+    /// to run.
+    pub fn advance(&mut self, frame_time: Duration) -> u32 {
+        self.accumulator += frame_time;
+
+        let step = self.step.as_nanos();
+        let accumulated = self.accumulator.as_nanos();
+        let steps = accumulated / step;
+        let remainder = accumulated % step;
+
+        if steps > self.max_steps as u128 {
+            // Drop the time that can't be caught up on.
+            self.accumulator = Duration::from_nanos(remainder as u64);
+            self.max_steps
+        } else {
+            self.accumulator -= self.step * steps as u32;
+            steps as u32
+        }
+    }
+
+    /// How far the game is between the last update step and the next, from
+    /// 0 to 1.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator.as_secs_f64() / self.step.as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+    use super::FixedTimestep;
+
+    #[test]
+    fn test_fixed_timestep() {
+        let mut timestep = FixedTimestep::new(Duration::from_millis(10));
+
+        assert_eq!(timestep.advance(Duration::from_millis(4)), 0);
+        assert_eq!(timestep.advance(Duration::from_millis(21)), 2);
+        assert!((timestep.alpha() - 0.5).abs() < 1e-9);
+
+        // Falling behind drops the time beyond `max_steps`
+        timestep.set_max_steps(3);
+        assert_eq!(timestep.advance(Duration::from_millis(100)), 3);
+        assert!((timestep.alpha() - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,100 @@
+//! Recurring timers that deliver their ticks to the main thread.
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use event::{self, Event};
+use get_error;
+use sys::event::SDL_USEREVENT;
+use SdlResult;
+
+use super::{shared_callback, start_timer, to_interval, PerformanceCounter, Timer};
+
+/// A tick of a timer started by `interval()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Tick {
+    /// The number of ticks before this one.
+    pub index: u64,
+    /// When the tick fired.
+    pub time: PerformanceCounter
+}
+
+/// Starts a timer that sends a `Tick` every `period`, so a single-threaded
+/// main loop can poll timers instead of running callbacks on the timer
+/// thread.
+///
+/// The timer stops once the receiver is dropped. Timers have millisecond
+/// precision. Fails if SDL can't start the timer, e.g. because the timer
+/// subsystem isn't initialized.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+///
+/// let _sdl_context = sdl2::init().timer().unwrap();
+/// let ticks = sdl2::timer::interval(Duration::from_millis(500)).unwrap();
+///
+/// loop {
+///     for tick in ticks.try_iter() {
+///         println!("tick {}", tick.index);
+///     }
+///     // handle events, update and render...
+/// }
+/// ```
+pub fn interval(period: Duration) -> SdlResult<Receiver<Tick>> {
+    let (sender, receiver) = mpsc::channel();
+    let period = to_interval(period);
+    let mut index = 0;
+
+    // Nothing owns the timer but SDL, which drops the callback once it
+    // returns 0.
+    let callback = shared_callback(Box::new(move || {
+        let tick = Tick { index: index, time: PerformanceCounter::now() };
+        index += 1;
+        match sender.send(tick) {
+            Ok(()) => period,
+            Err(_) => 0
+        }
+    }));
+    if start_timer(period, &callback) == 0 {
+        // `start_timer()` already took back SDL's reference to the callback.
+        return Err(get_error());
+    }
+
+    Ok(receiver)
+}
+
+/// Starts a timer that pushes an `Event::User` with the given `code` to the
+/// event queue every `period`, until the returned timer is dropped.
+///
+/// The ticks are then handled along with other events, e.g. by
+/// `wait_event()`.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use sdl2::event::Event;
+///
+/// const TICK: i32 = 1;
+///
+/// let mut sdl_context = sdl2::init().timer().unwrap();
+/// let _timer = sdl2::timer::push_interval(Duration::from_secs(1), TICK);
+///
+/// for event in sdl_context.event_pump().wait_iter() {
+///     match event {
+///         Event::User { code: TICK, .. } => println!("tick"),
+///         Event::Quit { .. } => break,
+///         _ => ()
+///     }
+/// }
+/// ```
+pub fn push_interval(period: Duration, code: i32) -> Timer<'static> {
+    Timer::repeating(period, move || {
+        // A full event queue only drops this tick.
+        let _ = event::push_event(Event::User {
+            timestamp: 0,
+            window_id: 0,
+            type_: SDL_USEREVENT,
+            code: code
+        });
+    })
+}
@@ -0,0 +1,346 @@
+use libc::{uint32_t, c_void};
+use std::ops::Sub;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use sys::timer as ll;
+
+mod clock;
+mod frame;
+mod interval;
+
+pub use self::clock::GameClock;
+pub use self::frame::{FixedTimestep, FrameLimiter};
+pub use self::interval::{interval, push_interval, Tick};
+
+pub fn get_ticks() -> u32 {
+    unsafe { ll::SDL_GetTicks() }
+}
+
+pub fn get_performance_counter() -> u64 {
+    unsafe { ll::SDL_GetPerformanceCounter() }
+}
+
+pub fn get_performance_frequency() -> u64 {
+    unsafe { ll::SDL_GetPerformanceFrequency() }
+}
+
+pub fn delay(ms: u32) {
+    unsafe { ll::SDL_Delay(ms) }
+}
+
+/// A point in time, read from the high-resolution performance counter.
+///
+/// Unlike `get_ticks()`, it's precise enough to time single frames.
+/// Subtracting two points gives the `Duration` between them.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::timer::PerformanceCounter;
+///
+/// let start = PerformanceCounter::now();
+/// // render a frame...
+/// let frame_time = PerformanceCounter::now() - start;
+/// println!("{:.2} ms", frame_time.as_secs_f64() * 1000.0);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PerformanceCounter {
+    count: u64
+}
+
+impl PerformanceCounter {
+    pub fn now() -> PerformanceCounter {
+        PerformanceCounter { count: get_performance_counter() }
+    }
+
+    /// Gets the raw counter value; see `get_performance_frequency()`.
+    pub fn count(&self) -> u64 { self.count }
+
+    /// Gets the time elapsed since `earlier`, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: PerformanceCounter) -> Duration {
+        ticks_to_duration(self.count.saturating_sub(earlier.count), get_performance_frequency())
+    }
+
+    /// Gets the time elapsed since this point.
+    pub fn elapsed(&self) -> Duration {
+        PerformanceCounter::now().duration_since(*self)
+    }
+}
+
+impl Sub for PerformanceCounter {
+    type Output = Duration;
+
+    fn sub(self, earlier: PerformanceCounter) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+fn ticks_to_duration(ticks: u64, frequency: u64) -> Duration {
+    if frequency == 0 {
+        return Duration::new(0, 0);
+    }
+    let secs = ticks / frequency;
+    let nanos = (ticks % frequency) as u128 * 1_000_000_000 / frequency as u128;
+    Duration::new(secs, nanos as u32)
+}
+
+/// A timer callback; it returns the number of milliseconds until it's
+/// called again, or 0 to stop the timer.
+///
+/// Timer callbacks run on a separate thread, so they must be `Send`.
+pub type TimerCallback<'a> = Box<dyn FnMut() -> u32 + Send + 'a>;
+
+struct TimerState<'a> {
+    callback: Option<TimerCallback<'a>>,
+    /// Whether SDL still holds its reference to the state, i.e. may call
+    /// the callback again.
+    scheduled: bool
+}
+
+type SharedCallback<'a> = Mutex<TimerState<'a>>;
+
+/// A running timer. The timer is removed when it's dropped.
+pub struct Timer<'a> {
+    /// Shared with SDL's timer thread, which holds another reference until
+    /// the callback stops the timer.
+    callback: Arc<SharedCallback<'a>>,
+    raw: ll::SDL_TimerID,
+}
+
+impl<'a> Timer<'a> {
+    /// Constructs a new timer using the boxed closure `callback`.
+    /// The timer is started immediately, it will be cancelled either:
+    ///   * when the timer is dropped
+    ///   * or when the callback returns a non-positive continuation interval
+    pub fn new(delay: u32, callback: TimerCallback<'a>) -> Timer<'a> {
+        let callback = shared_callback(callback);
+        let timer_id = start_timer(delay, &callback);
+
+        Timer {
+            callback: callback,
+            raw: timer_id,
+        }
+    }
+
+    /// Calls `callback` once after `delay`.
+    pub fn once<F: FnOnce() + Send + 'static>(delay: Duration, callback: F) -> Timer<'static> {
+        let mut callback = Some(callback);
+        add_timer(delay, move || {
+            if let Some(callback) = callback.take() {
+                callback();
+            }
+            None
+        })
+    }
+
+    /// Calls `callback` every `interval` until the timer is dropped.
+    pub fn repeating<F: FnMut() + Send + 'static>(interval: Duration, mut callback: F) -> Timer<'static> {
+        add_timer(interval, move || {
+            callback();
+            Some(interval)
+        })
+    }
+
+    /// Returns the closure as a trait-object and cancels the timer
+    /// by consuming it...
+    pub fn into_inner(self) -> TimerCallback<'a> {
+        let callback = lock(&self.callback).callback.take();
+        callback.unwrap()
+    }
+}
+
+impl<'a> Drop for Timer<'a> {
+    fn drop(&mut self) {
+        let removed = unsafe { ll::SDL_RemoveTimer(self.raw) != 0 };
+
+        // The callback may be running right now; taking it out under the
+        // lock waits for it to return.
+        let mut state = lock(&self.callback);
+        drop(state.callback.take());
+
+        // Once removed, SDL never calls back again, so its reference must be
+        // released here, unless the callback stopped the timer and released
+        // it already.
+        if removed && state.scheduled {
+            state.scheduled = false;
+            drop(state);
+            unsafe { drop(Arc::from_raw(Arc::as_ptr(&self.callback))) };
+        }
+    }
+}
+
+/// Adds a timer that calls `callback` on a separate thread after `delay`.
+///
+/// The callback returns when to call it again, or `None` to stop the timer,
+/// so one-shot and repeating timers can be expressed; see also
+/// `Timer::once()` and `Timer::repeating()`. The timer is removed when the
+/// returned `Timer` is dropped. Timers have millisecond precision.
+///
+/// # Example
+/// ```no_run
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+///
+/// let _sdl_context = sdl2::init().timer().unwrap();
+/// let (sender, receiver) = mpsc::channel();
+///
+/// // Count down from 3, once per second
+/// let mut count = 3;
+/// let _timer = sdl2::timer::add_timer(Duration::from_secs(1), move || {
+///     sender.send(count).unwrap();
+///     count -= 1;
+///     if count > 0 { Some(Duration::from_secs(1)) } else { None }
+/// });
+///
+/// for count in receiver.iter() {
+///     println!("{}", count);
+/// }
+/// ```
+pub fn add_timer<F>(delay: Duration, mut callback: F) -> Timer<'static>
+where F: FnMut() -> Option<Duration> + Send + 'static {
+    Timer::new(to_interval(delay), Box::new(move || {
+        callback().map_or(0, to_interval)
+    }))
+}
+
+/// Converts a duration to a timer interval in milliseconds; a zero interval
+/// would stop the timer, so it's rounded up to 1.
+fn to_interval(duration: Duration) -> u32 {
+    let ms = duration.as_secs().saturating_mul(1000).saturating_add(duration.subsec_millis() as u64);
+    ms.clamp(1, u32::MAX as u64) as u32
+}
+
+/// Starts an SDL timer, which holds a reference to `callback` until the
+/// callback stops it.
+fn start_timer<'a>(delay: u32, callback: &Arc<SharedCallback<'a>>) -> ll::SDL_TimerID {
+    let param = Arc::into_raw(callback.clone()) as *mut c_void;
+    lock(callback).scheduled = true;
+    let timer_id = unsafe { ll::SDL_AddTimer(delay, Some(c_timer_callback), param) };
+
+    if timer_id == 0 {
+        lock(callback).scheduled = false;
+        unsafe { drop(Arc::from_raw(param as *const SharedCallback<'a>)) };
+    }
+    timer_id
+}
+
+fn shared_callback(callback: TimerCallback<'_>) -> Arc<SharedCallback<'_>> {
+    Arc::new(Mutex::new(TimerState {
+        callback: Some(callback),
+        scheduled: false
+    }))
+}
+
+fn lock<'m, 'a>(callback: &'m SharedCallback<'a>) -> MutexGuard<'m, TimerState<'a>> {
+    // A panicking callback aborts, so the lock can't be poisoned in practice.
+    callback.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+extern "C" fn c_timer_callback(_interval: u32, param: *mut c_void) -> uint32_t {
+    let shared = param as *const SharedCallback<'static>;
+    let mut state = lock(unsafe { &*shared });
+    let interval = match state.callback {
+        Some(ref mut callback) => callback(),
+        None => 0
+    };
+
+    if interval == 0 {
+        // SDL won't call us again, so release its reference.
+        state.scheduled = false;
+        drop(state);
+        unsafe { drop(Arc::from_raw(shared)) };
+    }
+    interval as uint32_t
+}
+
+
+#[cfg(test)]
+fn test_timer_runs_multiple_times() {
+    use std::sync::{Arc, Mutex};
+    ::sdl::init().timer().unwrap();
+
+    let local_num = Arc::new(Mutex::new(0));
+    let timer_num = local_num.clone();
+
+    let _timer = Timer::new(20, Box::new(|| {
+        // increment up to 10 times (0 -> 9)
+        // tick again in 100ms after each increment
+        //
+        let mut num = timer_num.lock().unwrap();
+        if *num < 9 {
+            *num += 1;
+            20
+        } else { 0 }
+    }));
+
+    delay(250);                         // tick the timer at least 10 times w/ 200ms of "buffer"
+    let num = local_num.lock().unwrap(); // read the number back
+    assert_eq!(*num, 9);                 // it should have incremented at least 10 times...
+}
+
+#[cfg(test)]
+fn test_timer_runs_at_least_once() {
+    use std::sync::{Arc, Mutex};
+    ::sdl::init().timer().unwrap();
+
+    let local_flag = Arc::new(Mutex::new(false));
+    let timer_flag = local_flag.clone();
+
+    let _timer = Timer::new(20, Box::new(|| {
+        let mut flag = timer_flag.lock().unwrap();
+        *flag = true; 0
+    }));
+
+    delay(50);
+    let flag = local_flag.lock().unwrap();
+    assert_eq!(*flag, true);
+}
+
+#[cfg(test)]
+fn test_timer_can_be_recreated() {
+    use std::sync::{Arc, Mutex};
+    ::sdl::init().timer().unwrap();
+
+    let local_num = Arc::new(Mutex::new(0));
+    let timer_num = local_num.clone();
+
+    // run the timer once and reclaim its closure
+    let timer_1 = Timer::new(20, Box::new(move|| {
+        let mut num = timer_num.lock().unwrap();
+        *num += 1; // increment the number
+        0          // do not run timer again
+    }));
+
+    // reclaim closure after timer runs
+    delay(50);
+    let closure = timer_1.into_inner();
+
+    // create a second timer and increment again
+    let _timer_2 = Timer::new(20, closure);
+    delay(50);
+
+    // check that timer was incremented twice
+    let num = local_num.lock().unwrap();
+    assert_eq!(*num, 2);
+}
+
+#[test]
+fn test_ticks_to_duration() {
+    assert_eq!(ticks_to_duration(1_500_000, 1_000_000), Duration::from_millis(1500));
+    assert_eq!(ticks_to_duration(1, 3), Duration::new(0, 333_333_333));
+    assert_eq!(ticks_to_duration(u64::max_value(), 1_000_000_000), Duration::from_nanos(u64::max_value()));
+    assert_eq!(ticks_to_duration(10, 0), Duration::new(0, 0));
+}
+
+#[test]
+fn test_to_interval() {
+    assert_eq!(to_interval(Duration::from_millis(1500)), 1500);
+    assert_eq!(to_interval(Duration::new(0, 0)), 1);
+    assert_eq!(to_interval(Duration::from_secs(u64::max_value())), u32::max_value());
+}
+
+#[test]
+fn test_timer() {
+    test_timer_runs_multiple_times();
+    test_timer_runs_at_least_once();
+    test_timer_can_be_recreated();
+}
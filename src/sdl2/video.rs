@@ -1,4 +1,5 @@
-use libc::{c_void, c_int, c_float, uint32_t};
+use libc::{c_void, c_int, c_uint, c_float, uint32_t};
+use std::convert::TryFrom;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::mem;
@@ -7,6 +8,8 @@ use std::ptr;
 use std::vec::Vec;
 
 use rect::Rect;
+#[cfg(feature = "sdl_2_0_4")]
+use rect::Point;
 use render::RendererBuilder;
 use surface::SurfaceRef;
 use pixels;
@@ -18,6 +21,12 @@ use util::CStringExt;
 use get_error;
 
 use sys::video as ll;
+use sys::shape as shape_ll;
+#[cfg(feature = "sdl_2_0_6")]
+use sys::vulkan as vk_ll;
+
+#[cfg(feature = "sdl_2_0_6")]
+pub use sys::vulkan::{VkInstance, VkSurfaceKHR};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum GLProfile {
@@ -61,6 +70,9 @@ impl GLAttrTypeUtil for GLProfile {
             1 => Core,
             2 => Compatibility,
             4 => GLES,
+            // SDL's default of 0 leaves the choice to the platform, which
+            // gives a compatibility context on desktop OpenGL.
+            0 => Compatibility,
             _ => panic!("unknown SDL_GLProfile value: {}", value)
         }
     }
@@ -120,6 +132,7 @@ macro_rules! attrs {
 /// ```
 pub mod gl_attr {
     use get_error;
+    use SdlResult;
     use sys::video as ll;
     use super::{GLProfile, GLAttrTypeUtil};
 
@@ -201,12 +214,18 @@ pub mod gl_attr {
         (SDL_GL_ACCELERATED_VISUAL, set_accelerated_visual, accelerated_visual, bool,
             "whether to require hardware acceleration; false to force software rendering; defaults to allow either"),
 
+        (SDL_GL_RETAINED_BACKING, set_retained_backing, retained_backing, bool,
+            "whether the contents of the back buffer are kept after swapping (iOS only); defaults to true"),
+
         (SDL_GL_CONTEXT_MAJOR_VERSION, set_context_major_version, context_major_version, u8,
             "OpenGL context major version"),
 
         (SDL_GL_CONTEXT_MINOR_VERSION, set_context_minor_version, context_minor_version, u8,
             "OpenGL context minor version"),
 
+        (SDL_GL_CONTEXT_EGL, set_context_egl, context_egl, bool,
+            "whether to create the context with EGL instead of GLX or WGL; defaults to false"),
+
         (SDL_GL_CONTEXT_PROFILE_MASK, set_context_profile, context_profile, GLProfile,
             "type of GL context (Core, Compatibility, ES)"),
 
@@ -214,7 +233,33 @@ pub mod gl_attr {
             "OpenGL context sharing; defaults to false"),
 
         (SDL_GL_FRAMEBUFFER_SRGB_CAPABLE, set_framebuffer_srgb_compatible, framebuffer_srgb_compatible, bool,
-            "requests sRGB capable visual; defaults to false (>= SDL 2.0.1)")
+            "requests sRGB capable visual; defaults to false (>= SDL 2.0.1)"),
+
+        (SDL_GL_CONTEXT_RELEASE_BEHAVIOR, set_context_release_flush, context_release_flush, bool,
+            "whether pending commands are flushed when the context stops being current; defaults to true (>= SDL 2.0.4)")
+    }
+
+    /// **Sets** multisample anti-aliasing with the given number of samples
+    /// per pixel, e.g. 4; 0 turns it off.
+    #[inline]
+    pub fn set_multisampling(samples: u8) {
+        set_multisample_buffers(if samples > 0 { 1 } else { 0 });
+        set_multisample_samples(samples);
+    }
+
+    /// **Gets** the number of samples per pixel used for multisample
+    /// anti-aliasing, or 0 if it's off.
+    #[inline]
+    pub fn multisampling() -> u8 {
+        if multisample_buffers() > 0 { multisample_samples() } else { 0 }
+    }
+
+    /// **Sets** the minimum number of bits of the depth and stencil buffers,
+    /// e.g. `(24, 8)`.
+    #[inline]
+    pub fn set_depth_stencil_size(depth: u8, stencil: u8) {
+        set_depth_size(depth);
+        set_stencil_size(stencil);
     }
 
     /// **Sets** the OpenGL context major and minor versions.
@@ -230,6 +275,54 @@ pub mod gl_attr {
         (context_major_version(), context_minor_version())
     }
 
+    /// **Sets** up a desktop OpenGL core profile context of the given version,
+    /// e.g. `(3, 3)`.
+    #[inline]
+    pub fn set_context_core(major: u8, minor: u8) {
+        set_context_profile(GLProfile::Core);
+        set_context_version(major, minor);
+    }
+
+    /// **Sets** up an OpenGL ES context of the given version, e.g. `(2, 0)`
+    /// or `(3, 0)`.
+    ///
+    /// This works on mobile and embedded platforms (Android, iOS, Raspberry
+    /// Pi) as well as on desktop drivers that support ES contexts, so the
+    /// same code path can target both. On X11, the context is created with
+    /// EGL, which is how most Linux drivers provide ES. See also
+    /// `set_native_gles_driver()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::video::gl_attr;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// gl_attr::set_context_gles(2, 0);
+    ///
+    /// let window = sdl_context.window("GLES", 800, 600).opengl().build().unwrap();
+    /// let _context = window.gl_create_context().unwrap();
+    /// ```
+    #[inline]
+    pub fn set_context_gles(major: u8, minor: u8) {
+        set_context_profile(GLProfile::GLES);
+        set_context_version(major, minor);
+        if cfg!(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android")))) {
+            set_context_egl(true);
+        }
+    }
+
+    /// Makes SDL load the system's native OpenGL ES and EGL libraries (e.g.
+    /// `libGLESv2` and `libEGL`) for ES contexts, instead of asking the
+    /// desktop OpenGL driver for one.
+    ///
+    /// This is needed with desktop drivers that don't support ES contexts,
+    /// and with ES emulators such as ANGLE. It must be called before the
+    /// window is created. Requires SDL 2.0.6 or later; older versions ignore it.
+    #[inline]
+    pub fn set_native_gles_driver(native: bool) {
+        ::hint::set("SDL_OPENGL_ES_DRIVER", if native { "1" } else { "0" });
+    }
+
     /// The type that allows you to build a OpenGL context configuration.
     pub struct ContextFlagsBuilder {
         flags: i32
@@ -256,12 +349,16 @@ pub mod gl_attr {
             self
         }
 
+        /// Requests a context with robust buffer access, where out-of-bounds
+        /// accesses can't crash the program.
         #[inline]
         pub fn robust_access(&mut self) -> &mut ContextFlagsBuilder {
             self.flags |= 0x0004;
             self
         }
 
+        /// Requests a context that isn't affected when another application
+        /// causes a GPU reset.
         #[inline]
         pub fn reset_isolation(&mut self) -> &mut ContextFlagsBuilder {
             self.flags |= 0x0008;
@@ -269,6 +366,8 @@ pub mod gl_attr {
         }
     }
 
+    /// The OpenGL context flags, as returned by `context_flags()`.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct ContextFlags {
         flags: i32
     }
@@ -318,6 +417,89 @@ pub mod gl_attr {
             flags: flags
         }
     }
+
+    /// Resets all OpenGL attributes to their defaults.
+    #[inline]
+    pub fn reset_attributes() {
+        unsafe { ll::SDL_GL_ResetAttributes() }
+    }
+
+    /// The OpenGL attributes, as returned by `attributes()`.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct GLAttributes {
+        pub red_size: u8,
+        pub green_size: u8,
+        pub blue_size: u8,
+        pub alpha_size: u8,
+        pub buffer_size: u8,
+        pub double_buffer: bool,
+        pub depth_size: u8,
+        pub stencil_size: u8,
+        pub stereo: bool,
+        pub multisample_buffers: u8,
+        pub multisample_samples: u8,
+        pub accelerated_visual: bool,
+        pub context_version: (u8, u8),
+        pub context_profile: GLProfile,
+        pub context_flags: ContextFlags,
+        pub framebuffer_srgb_compatible: bool
+    }
+
+    fn get(attr: ll::SDL_GLattr) -> SdlResult<i32> {
+        let mut value = 0;
+        let result = unsafe { ll::SDL_GL_GetAttribute(attr, &mut value) };
+        if result == 0 {
+            Ok(value)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Reads back all OpenGL attributes at once.
+    ///
+    /// While a context is current, SDL reports the attributes of that
+    /// context, which may differ from the requested ones: the driver may give
+    /// a newer version than requested, or refuse a debug context. Returns an
+    /// error if an attribute can't be read, e.g. without a current context.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::video::gl_attr;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// gl_attr::set_context_flags().debug().set();
+    ///
+    /// let window = sdl_context.window("GL", 800, 600).opengl().build().unwrap();
+    /// let _context = window.gl_create_context().unwrap();
+    ///
+    /// let attributes = gl_attr::attributes().unwrap();
+    /// if !attributes.context_flags.has_debug() {
+    ///     println!("No debug context; got {:?}", attributes);
+    /// }
+    /// ```
+    pub fn attributes() -> SdlResult<GLAttributes> {
+        use sys::video::SDL_GLattr::*;
+
+        Ok(GLAttributes {
+            red_size: try!(get(SDL_GL_RED_SIZE)) as u8,
+            green_size: try!(get(SDL_GL_GREEN_SIZE)) as u8,
+            blue_size: try!(get(SDL_GL_BLUE_SIZE)) as u8,
+            alpha_size: try!(get(SDL_GL_ALPHA_SIZE)) as u8,
+            buffer_size: try!(get(SDL_GL_BUFFER_SIZE)) as u8,
+            double_buffer: try!(get(SDL_GL_DOUBLEBUFFER)) != 0,
+            depth_size: try!(get(SDL_GL_DEPTH_SIZE)) as u8,
+            stencil_size: try!(get(SDL_GL_STENCIL_SIZE)) as u8,
+            stereo: try!(get(SDL_GL_STEREO)) != 0,
+            multisample_buffers: try!(get(SDL_GL_MULTISAMPLEBUFFERS)) as u8,
+            multisample_samples: try!(get(SDL_GL_MULTISAMPLESAMPLES)) as u8,
+            accelerated_visual: try!(get(SDL_GL_ACCELERATED_VISUAL)) != 0,
+            context_version: (try!(get(SDL_GL_CONTEXT_MAJOR_VERSION)) as u8,
+                              try!(get(SDL_GL_CONTEXT_MINOR_VERSION)) as u8),
+            context_profile: GLAttrTypeUtil::from_gl_value(try!(get(SDL_GL_CONTEXT_PROFILE_MASK))),
+            context_flags: ContextFlags { flags: try!(get(SDL_GL_CONTEXT_FLAGS)) },
+            framebuffer_srgb_compatible: try!(get(SDL_GL_FRAMEBUFFER_SRGB_CAPABLE)) != 0
+        })
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -358,13 +540,67 @@ impl DisplayMode {
     }
 }
 
+bitflags! {
+    /// The state of a window, as returned by `WindowProperties::flags()`.
+    flags WindowFlags: u32 {
+        const FULLSCREEN = ll::SDL_WindowFlags::SDL_WINDOW_FULLSCREEN as u32,
+        const OPENGL = ll::SDL_WindowFlags::SDL_WINDOW_OPENGL as u32,
+        const SHOWN = ll::SDL_WindowFlags::SDL_WINDOW_SHOWN as u32,
+        const HIDDEN = ll::SDL_WindowFlags::SDL_WINDOW_HIDDEN as u32,
+        const BORDERLESS = ll::SDL_WindowFlags::SDL_WINDOW_BORDERLESS as u32,
+        const RESIZABLE = ll::SDL_WindowFlags::SDL_WINDOW_RESIZABLE as u32,
+        const MINIMIZED = ll::SDL_WindowFlags::SDL_WINDOW_MINIMIZED as u32,
+        const MAXIMIZED = ll::SDL_WindowFlags::SDL_WINDOW_MAXIMIZED as u32,
+        const INPUT_GRABBED = ll::SDL_WindowFlags::SDL_WINDOW_INPUT_GRABBED as u32,
+        const INPUT_FOCUS = ll::SDL_WindowFlags::SDL_WINDOW_INPUT_FOCUS as u32,
+        const MOUSE_FOCUS = ll::SDL_WindowFlags::SDL_WINDOW_MOUSE_FOCUS as u32,
+        /// Includes the `FULLSCREEN` bit.
+        const FULLSCREEN_DESKTOP = ll::SDL_WindowFlags::SDL_WINDOW_FULLSCREEN_DESKTOP as u32,
+        /// The window wasn't created by SDL.
+        const FOREIGN = ll::SDL_WindowFlags::SDL_WINDOW_FOREIGN as u32,
+        const ALLOW_HIGHDPI = ll::SDL_WindowFlags::SDL_WINDOW_ALLOW_HIGHDPI as u32,
+        /// The window stays above other windows. Requires SDL 2.0.5 or later.
+        const ALWAYS_ON_TOP = ll::SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32,
+        /// The window can be used with Vulkan. Requires SDL 2.0.6 or later.
+        const VULKAN = ll::SDL_WindowFlags::SDL_WINDOW_VULKAN as u32
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FullscreenType {
     FTOff = 0,
     FTTrue = 0x00000001,
     FTDesktop = 0x00001001,
 }
 
+/// The position, size and state of a window, for restoring the window layout
+/// in the next session; see `WindowProperties::geometry()`.
+///
+/// With the `serde` feature, it can be serialized along with the rest of the
+/// application's settings.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindowGeometry {
+    /// The position in screen coordinates.
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+    pub fullscreen: FullscreenType,
+    /// The display that contained the center of the window.
+    pub display_index: i32
+}
+
+impl WindowGeometry {
+    /// The saved area, or `None` if it isn't a valid rectangle, e.g. because
+    /// a hand-edited size doesn't fit in an `i32`.
+    fn rect(&self) -> Option<Rect> {
+        Rect::new(self.x, self.y, self.width.max(1), self.height.max(1)).unwrap_or(None)
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum WindowPos {
     PosUndefined,
@@ -380,6 +616,10 @@ fn unwrap_windowpos (pos: WindowPos) -> ll::SDL_WindowPos {
     }
 }
 
+/// An OpenGL context, created with `Window::gl_create_context()`.
+///
+/// The context is deleted when dropped. It mustn't be current on another
+/// thread at that point.
 pub struct GLContext {
     raw: ll::SDL_GLContext
 }
@@ -398,10 +638,143 @@ impl GLContext {
         let current_raw = unsafe { ll::SDL_GL_GetCurrentContext() };
         self.raw == current_raw
     }
+
+    /// Returns a function loader for OpenGL bindings such as `gl` and `glow`.
+    ///
+    /// The loader resolves function names with `gl_get_proc_address()`, and
+    /// returns null for functions that aren't available, including the bogus
+    /// addresses some Windows drivers hand out. A non-null address doesn't
+    /// mean the function is supported: it may belong to an extension the
+    /// driver doesn't support, so check `gl_extension_supported()` before
+    /// using extension functions.
+    ///
+    /// Returns an error if this context isn't current, since some platforms
+    /// can only look up functions for the current context.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let window = sdl_context.window("GL", 800, 600).opengl().build().unwrap();
+    /// let context = window.gl_create_context().unwrap();
+    ///
+    /// gl::load_with(context.loader().unwrap());
+    ///
+    /// if sdl2::video::gl_extension_supported("GL_ARB_debug_output") {
+    ///     // gl::DebugMessageCallbackARB is safe to use
+    /// }
+    /// ```
+    pub fn loader(&self) -> SdlResult<fn(&str) -> *const ::std::os::raw::c_void> {
+        fn load(procname: &str) -> *const ::std::os::raw::c_void {
+            let address = gl_get_proc_address(procname) as isize;
+            match address {
+                // wglGetProcAddress may return these instead of null on failure
+                -1 | 1 | 2 | 3 => ptr::null(),
+                _ => address as *const ::std::os::raw::c_void
+            }
+        }
+
+        if self.is_current() {
+            Ok(load)
+        } else {
+            Err("The OpenGL context isn't current".to_owned())
+        }
+    }
+}
+
+/// What dragging the mouse at a point of a window does; returned by the
+/// callback given to `Window::set_hit_test()`.
+#[cfg(feature = "sdl_2_0_4")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum HitTestResult {
+    /// The point behaves as usual, i.e. the application receives the mouse events.
+    Normal = ll::SDL_HITTEST_NORMAL as isize,
+    /// Dragging at the point moves the window, like a title bar.
+    Draggable = ll::SDL_HITTEST_DRAGGABLE as isize,
+    ResizeTopLeft = ll::SDL_HITTEST_RESIZE_TOPLEFT as isize,
+    ResizeTop = ll::SDL_HITTEST_RESIZE_TOP as isize,
+    ResizeTopRight = ll::SDL_HITTEST_RESIZE_TOPRIGHT as isize,
+    ResizeRight = ll::SDL_HITTEST_RESIZE_RIGHT as isize,
+    ResizeBottomRight = ll::SDL_HITTEST_RESIZE_BOTTOMRIGHT as isize,
+    ResizeBottom = ll::SDL_HITTEST_RESIZE_BOTTOM as isize,
+    ResizeBottomLeft = ll::SDL_HITTEST_RESIZE_BOTTOMLEFT as isize,
+    ResizeLeft = ll::SDL_HITTEST_RESIZE_LEFT as isize
+}
+
+#[cfg(feature = "sdl_2_0_4")]
+type HitTestCallback = Box<dyn FnMut(Point) -> HitTestResult>;
+
+/// How the shape surface given to `WindowProperties::set_shape()` is turned
+/// into the visible area of a shaped window.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum WindowShapeMode {
+    /// Pixels with nonzero alpha are visible; same as `BinarizeAlpha(1)`.
+    Default,
+    /// Pixels with an alpha greater than or equal to the cutoff are visible.
+    BinarizeAlpha(u8),
+    /// Pixels with an alpha less than or equal to the cutoff are visible.
+    ReverseBinarizeAlpha(u8),
+    /// Pixels of any color but the color key are visible.
+    ColorKey(pixels::Color)
+}
+
+impl WindowShapeMode {
+    fn to_ll(self) -> shape_ll::SDL_WindowShapeMode {
+        use sys::pixels::SDL_Color;
+
+        let (mode, color_key) = match self {
+            WindowShapeMode::Default => (shape_ll::ShapeModeDefault, SDL_Color { r: 1, g: 0, b: 0, a: 0 }),
+            WindowShapeMode::BinarizeAlpha(cutoff) => (shape_ll::ShapeModeBinarizeAlpha, SDL_Color { r: cutoff, g: 0, b: 0, a: 0 }),
+            WindowShapeMode::ReverseBinarizeAlpha(cutoff) => (shape_ll::ShapeModeReverseBinarizeAlpha, SDL_Color { r: cutoff, g: 0, b: 0, a: 0 }),
+            WindowShapeMode::ColorKey(color) => {
+                let (r, g, b) = color.get_rgb();
+                (shape_ll::ShapeModeColorKey, SDL_Color { r: r, g: g, b: b, a: 255 })
+            }
+        };
+
+        shape_ll::SDL_WindowShapeMode {
+            mode: mode,
+            parameters: shape_ll::SDL_WindowShapeParams { colorKey: color_key }
+        }
+    }
+
+    fn from_ll(raw: shape_ll::SDL_WindowShapeMode) -> WindowShapeMode {
+        let cutoff = raw.parameters.binarizationCutoff();
+        match raw.mode {
+            shape_ll::ShapeModeBinarizeAlpha => WindowShapeMode::BinarizeAlpha(cutoff),
+            shape_ll::ShapeModeReverseBinarizeAlpha => WindowShapeMode::ReverseBinarizeAlpha(cutoff),
+            shape_ll::ShapeModeColorKey => {
+                let color = raw.parameters.colorKey;
+                WindowShapeMode::ColorKey(pixels::Color::RGB(color.r, color.g, color.b))
+            },
+            _ => WindowShapeMode::Default
+        }
+    }
+}
+
+fn shape_error(code: c_int) -> String {
+    match code {
+        shape_ll::SDL_NONSHAPEABLE_WINDOW => "Window is not a shaped window".to_owned(),
+        shape_ll::SDL_INVALID_SHAPE_ARGUMENT => "Invalid shape surface or shape mode".to_owned(),
+        shape_ll::SDL_WINDOW_LACKS_SHAPE => "Window has no shape".to_owned(),
+        _ => get_error()
+    }
+}
+
+#[cfg(feature = "sdl_2_0_4")]
+extern "C" fn hit_test_callback(_win: *mut ll::SDL_Window, area: *const ::sys::rect::SDL_Point,
+                                data: *mut c_void) -> ll::SDL_HitTestResult {
+    unsafe {
+        let callback = &mut *(data as *mut HitTestCallback);
+        callback(Point::from_ll(*area)) as ll::SDL_HitTestResult
+    }
 }
 
 pub struct Window {
-    raw: *mut ll::SDL_Window
+    raw: *mut ll::SDL_Window,
+    /// The callback installed with `set_hit_test()`. It's boxed twice so SDL
+    /// can be handed a thin pointer that stays put when the window moves.
+    #[cfg(feature = "sdl_2_0_4")]
+    hit_test: Option<Box<HitTestCallback>>
 }
 
 impl_raw_accessors!(
@@ -409,9 +782,20 @@ impl_raw_accessors!(
     (Window, *mut ll::SDL_Window)
 );
 
-impl_raw_constructor!(
-    (Window, Window (raw: *mut ll::SDL_Window))
-);
+impl Window {
+    /// Takes ownership of a raw SDL window.
+    ///
+    /// # Safety
+    /// `raw` must be a valid window that nothing else destroys.
+    #[inline]
+    pub unsafe fn from_ll(raw: *mut ll::SDL_Window) -> Window {
+        Window {
+            raw: raw,
+            #[cfg(feature = "sdl_2_0_4")]
+            hit_test: None
+        }
+    }
+}
 
 impl Drop for Window {
     #[inline]
@@ -469,15 +853,54 @@ impl WindowBuilder {
                 if raw == ptr::null_mut() {
                     Err(get_error())
                 } else {
-                    Ok(Window { raw: raw })
+                    Ok(Window::from_ll(raw))
                 }
             }
         }
     }
 
+    /// Builds a shaped window, i.e. a window whose visible area is given by
+    /// `WindowProperties::set_shape()`.
+    ///
+    /// Shaped windows are always borderless and can't be fullscreen or
+    /// resizable; those flags are ignored. The window stays hidden until a
+    /// shape is set. The position is ignored as well, so use
+    /// `WindowProperties::set_position()` after building.
+    pub fn build_shaped(&self) -> SdlResult<Window> {
+        if self.width >= (1<<31) || self.height >= (1<<31) {
+            // SDL2 only supports int (signed 32-bit) arguments.
+            return Err("Window is too large.".to_owned());
+        }
+
+        let raw = unsafe {
+            shape_ll::SDL_CreateShapedWindow(
+                    self.title.as_ptr(),
+                    0,
+                    0,
+                    self.width as c_uint,
+                    self.height as c_uint,
+                    self.window_flags
+            )
+        };
+
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(unsafe { Window::from_ll(raw) })
+        }
+    }
+
     /// Gets the underlying window flags.
     pub fn get_window_flags(&self) -> u32 { self.window_flags }
 
+    /// Adds window flags, e.g. `sdl2::video::BORDERLESS | sdl2::video::HIDDEN`.
+    ///
+    /// This is an alternative to calling the individual flag methods.
+    pub fn flags(&mut self, flags: WindowFlags) -> &mut WindowBuilder {
+        self.window_flags |= flags.bits();
+        self
+    }
+
     /// Sets the underlying window flags.
     /// This will effectively undo any previous build operations, excluding window size and position.
     pub fn set_window_flags(&mut self, flags: u32) -> &mut WindowBuilder {
@@ -528,6 +951,51 @@ impl WindowBuilder {
 
     /// Creates the window in high-DPI mode if supported (>= SDL 2.0.1)
     pub fn allow_highdpi(&mut self) -> &mut WindowBuilder { self.window_flags |= ll::SDL_WindowFlags::SDL_WINDOW_ALLOW_HIGHDPI as u32; self }
+
+    /// Makes the window exactly cover one or more displays, e.g. for a
+    /// projector, a video wall or a game spanning several monitors.
+    ///
+    /// A single display is covered with a fullscreen desktop window, which
+    /// also hides task bars and docks. Several displays are covered with a
+    /// borderless window spanning the smallest rectangle that contains all of
+    /// them; task bars may stay on top of it on some platforms, and on Mac
+    /// OS X it only works if "Displays have separate Spaces" is turned off.
+    /// Window managers that don't let applications position windows, such as
+    /// Wayland compositors, may ignore the position.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let num_displays = sdl2::video::get_num_video_displays().unwrap();
+    /// let displays: Vec<i32> = (0..num_displays).collect();
+    ///
+    /// let window = sdl_context.window("Video wall", 0, 0)
+    ///     .cover_displays(&displays).unwrap()
+    ///     .build().unwrap();
+    /// ```
+    pub fn cover_displays(&mut self, display_indices: &[i32]) -> SdlResult<&mut WindowBuilder> {
+        let bounds = try!(get_displays_bounds(display_indices));
+        let (x, y, width, height) = bounds.xywh();
+
+        self.position(x, y);
+        self.width = width;
+        self.height = height;
+
+        if display_indices.len() == 1 {
+            self.fullscreen_desktop();
+        } else {
+            self.borderless();
+        }
+        Ok(self)
+    }
+
+    /// Sets the window to be usable with a Vulkan instance (>= SDL 2.0.6)
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vulkan(&mut self) -> &mut WindowBuilder { self.window_flags |= ll::SDL_WindowFlags::SDL_WINDOW_VULKAN as u32; self }
+
+    /// Keeps the window above other windows, e.g. for overlays (>= SDL 2.0.5)
+    #[cfg(feature = "sdl_2_0_5")]
+    pub fn always_on_top(&mut self) -> &mut WindowBuilder { self.window_flags |= ll::SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32; self }
 }
 
 /// Contains accessors to a `Window`'s properties.
@@ -552,6 +1020,39 @@ impl<'a> Deref for WindowPropertiesGetters<'a> {
 }
 
 impl Window {
+    /// Wraps a window that was created by another toolkit, so SDL can render
+    /// into it, e.g. into a widget of a Qt or GTK editor.
+    ///
+    /// `handle` is the platform's native window: an `HWND` on Windows, an
+    /// X11 `Window` (cast to a pointer) on X11 and an `NSWindow*` on Mac OS X.
+    /// The window gets the `FOREIGN` flag, and dropping it doesn't destroy
+    /// the native window.
+    ///
+    /// # Safety
+    /// `handle` must be a valid native window that outlives the returned
+    /// `Window`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn native_widget_handle() -> *const std::os::raw::c_void { std::ptr::null() }
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let handle = native_widget_handle();
+    ///
+    /// let window = unsafe { sdl2::video::Window::from_native_handle(&sdl_context, handle) }.unwrap();
+    /// let mut renderer = window.renderer().build().unwrap();
+    /// renderer.clear();
+    /// renderer.present();
+    /// ```
+    pub unsafe fn from_native_handle(_sdl: &Sdl, handle: *const ::std::os::raw::c_void) -> SdlResult<Window> {
+        let raw = ll::SDL_CreateWindowFrom(handle as *const c_void);
+
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Window::from_ll(raw))
+        }
+    }
+
     /// Initializes a new `RendererBuilder`; a convenience method that calls `RendererBuilder::new()`.
     pub fn renderer(self) -> RendererBuilder {
         RendererBuilder::new(self)
@@ -608,6 +1109,41 @@ impl Window {
         unsafe { ll::SDL_GetWindowID(self.raw) }
     }
 
+    /// Returns true if the window has the keyboard focus.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::Event;
+    /// use sdl2::keyboard::Keycode;
+    ///
+    /// let mut sdl_context = sdl2::init().video().unwrap();
+    /// let editor = sdl_context.window("Editor", 800, 600).build().unwrap();
+    /// let palette = sdl_context.window("Palette", 200, 600).build().unwrap();
+    ///
+    /// for event in sdl_context.event_pump().poll_iter() {
+    ///     if let Event::KeyDown { keycode: Some(Keycode::Delete), .. } = event {
+    ///         if editor.has_keyboard_focus() {
+    ///             // delete the selection
+    ///         } else if palette.has_keyboard_focus() {
+    ///             // delete the swatch
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn has_keyboard_focus(&self) -> bool {
+        unsafe { ::sys::keyboard::SDL_GetKeyboardFocus() == self.raw }
+    }
+
+    /// Returns true if the mouse is over the window.
+    pub fn has_mouse_focus(&self) -> bool {
+        unsafe { ::sys::mouse::SDL_GetMouseFocus() == self.raw }
+    }
+
+    /// Creates an OpenGL context for the window and makes it current.
+    ///
+    /// A window can have several contexts; use `gl_make_current()` to switch
+    /// between them. The context uses the attributes set with `gl_attr`; set
+    /// them before creating the window.
     pub fn gl_create_context(&self) -> SdlResult<GLContext> {
         let result = unsafe { ll::SDL_GL_CreateContext(self.raw) };
         if result == ptr::null_mut() {
@@ -617,6 +1153,36 @@ impl Window {
         }
     }
 
+    /// Creates an OpenGL context that shares textures, buffers and other
+    /// objects with `share_with`, and makes it current.
+    ///
+    /// This is useful to upload resources with one context while rendering
+    /// with another.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let window = sdl_context.window("Viewer", 800, 600).opengl().build().unwrap();
+    ///
+    /// let render_context = window.gl_create_context().unwrap();
+    /// let upload_context = window.gl_create_shared_context(&render_context).unwrap();
+    ///
+    /// // upload textures with `upload_context` ...
+    ///
+    /// window.gl_make_current(&render_context).unwrap();
+    /// // ... and draw them with `render_context`
+    /// ```
+    pub fn gl_create_shared_context(&self, share_with: &GLContext) -> SdlResult<GLContext> {
+        try!(self.gl_make_current(share_with));
+
+        let was_sharing = gl_attr::share_with_current_context();
+        gl_attr::set_share_with_current_context(true);
+        let result = self.gl_create_context();
+        gl_attr::set_share_with_current_context(was_sharing);
+
+        result
+    }
+
     /// Set the window's OpenGL context to the current context on the thread.
     pub fn gl_set_context_to_current(&self) -> SdlResult<()> {
         unsafe {
@@ -634,6 +1200,10 @@ impl Window {
         }
     }
 
+    /// Makes `context` the current OpenGL context of the calling thread,
+    /// rendering to this window.
+    ///
+    /// A context can only be current on one thread at a time.
     pub fn gl_make_current(&self, context: &GLContext) -> SdlResult<()> {
         unsafe {
             if ll::SDL_GL_MakeCurrent(self.raw, context.raw) == 0 {
@@ -647,9 +1217,134 @@ impl Window {
     pub fn gl_swap_window(&self) {
         unsafe { ll::SDL_GL_SwapWindow(self.raw) }
     }
+
+    /// Gets the names of the Vulkan instance extensions needed to create a
+    /// surface for the window, e.g. `VK_KHR_surface`.
+    ///
+    /// The window must have been built with `WindowBuilder::vulkan()`.
+    /// Requires SDL 2.0.6 or later.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vulkan_instance_extensions(&self) -> SdlResult<Vec<&'static str>> {
+        let mut count: c_uint = 0;
+        if unsafe { vk_ll::SDL_Vulkan_GetInstanceExtensions(self.raw, &mut count, ptr::null_mut()) } == 0 {
+            return Err(get_error());
+        }
+
+        let mut names: Vec<*const ::libc::c_char> = vec![ptr::null(); count as usize];
+        if unsafe { vk_ll::SDL_Vulkan_GetInstanceExtensions(self.raw, &mut count, names.as_mut_ptr()) } == 0 {
+            return Err(get_error());
+        }
+
+        // SDL returns static strings.
+        Ok(names.iter().take(count as usize).map(|&name| unsafe {
+            CStr::from_ptr(name).to_str().unwrap()
+        }).collect())
+    }
+
+    /// Creates a Vulkan surface for the window.
+    ///
+    /// `instance` must have been created with the extensions returned by
+    /// `vulkan_instance_extensions()`. The surface must be destroyed with
+    /// `vkDestroySurfaceKHR` before the instance and the window.
+    /// Requires SDL 2.0.6 or later.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use ash::vk::Handle;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let window = sdl_context.window("Vulkan", 800, 600).vulkan().build().unwrap();
+    ///
+    /// let extensions = window.vulkan_instance_extensions().unwrap();
+    /// let instance = create_instance(&extensions);
+    ///
+    /// let surface = window.vulkan_create_surface(instance.handle().as_raw() as usize).unwrap();
+    /// let surface = ash::vk::SurfaceKHR::from_raw(surface);
+    /// ```
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vulkan_create_surface(&self, instance: VkInstance) -> SdlResult<VkSurfaceKHR> {
+        let mut surface: VkSurfaceKHR = 0;
+        if unsafe { vk_ll::SDL_Vulkan_CreateSurface(self.raw, instance, &mut surface) } == 0 {
+            Err(get_error())
+        } else {
+            Ok(surface)
+        }
+    }
+
+    /// Gets the size of the window's Vulkan drawable in pixels, which may be
+    /// larger than the window size on high-DPI displays.
+    /// Requires SDL 2.0.6 or later.
+    #[cfg(feature = "sdl_2_0_6")]
+    pub fn vulkan_drawable_size(&self) -> (u32, u32) {
+        let mut width: c_int = 0;
+        let mut height: c_int = 0;
+        unsafe { vk_ll::SDL_Vulkan_GetDrawableSize(self.raw, &mut width, &mut height) };
+        (width as u32, height as u32)
+    }
+
+    /// Lets the application decide which parts of the window move or resize
+    /// it when dragged with the mouse.
+    ///
+    /// This makes borderless windows with a custom title bar possible. The
+    /// callback is given a point in window coordinates and is called while
+    /// events are pumped, so it should be quick. It replaces any callback set
+    /// before.
+    ///
+    /// Returns an error if the platform doesn't support hit testing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::video::HitTestResult;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("Custom title bar", 800, 600)
+    ///     .borderless()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// window.set_hit_test(|point| {
+    ///     if point.y() < 32 {
+    ///         HitTestResult::Draggable
+    ///     } else if point.x() >= 792 && point.y() >= 592 {
+    ///         HitTestResult::ResizeBottomRight
+    ///     } else {
+    ///         HitTestResult::Normal
+    ///     }
+    /// }).unwrap();
+    /// ```
+    #[cfg(feature = "sdl_2_0_4")]
+    pub fn set_hit_test<F>(&mut self, callback: F) -> SdlResult<()>
+    where F: FnMut(Point) -> HitTestResult + 'static {
+        let mut callback: Box<HitTestCallback> = Box::new(Box::new(callback));
+        let data = &mut *callback as *mut HitTestCallback as *mut c_void;
+        let result = unsafe { ll::SDL_SetWindowHitTest(self.raw, Some(hit_test_callback), data) };
+
+        if result == 0 {
+            // The previous callback is only dropped once SDL no longer uses it.
+            self.hit_test = Some(callback);
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Removes the callback installed with `set_hit_test()`, so the window
+    /// is dragged and resized as usual again.
+    #[cfg(feature = "sdl_2_0_4")]
+    pub fn clear_hit_test(&mut self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_SetWindowHitTest(self.raw, None, ptr::null_mut()) };
+
+        if result == 0 {
+            self.hit_test = None;
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
 }
 
 impl<'a> WindowProperties<'a> {
+    /// Gets the index of the display that contains the center of the window.
     pub fn get_display_index(&self) -> SdlResult<i32> {
         let result = unsafe { ll::SDL_GetWindowDisplayIndex(self.raw) };
         if result < 0 {
@@ -659,15 +1354,195 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Moves the window to another display.
+    ///
+    /// `x` and `y` are relative to the display's top-left corner.
+    /// `PosCentered` centers the window on the display, and `PosUndefined`
+    /// keeps the window's offset from the top-left corner of the display
+    /// it's currently on.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::video::WindowPos;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("Editor", 800, 600).build().unwrap();
+    ///
+    /// // Reopen on the display the user last used, if it's still connected
+    /// let last_display = 1;
+    /// if last_display < sdl2::video::get_num_video_displays().unwrap() {
+    ///     window.properties(&sdl_context)
+    ///           .move_to_display(last_display, WindowPos::PosCentered, WindowPos::PosCentered)
+    ///           .unwrap();
+    /// }
+    /// ```
+    pub fn move_to_display(&mut self, display_index: i32, x: WindowPos, y: WindowPos) -> SdlResult<()> {
+        let bounds = try!(get_display_bounds(display_index));
+        let (width, height) = self.get_size();
+
+        let (offset_x, offset_y) = if x == WindowPos::PosUndefined || y == WindowPos::PosUndefined {
+            let current_bounds = try!(get_display_bounds(try!(self.get_display_index())));
+            let (window_x, window_y) = self.get_position();
+            (window_x - current_bounds.x(), window_y - current_bounds.y())
+        } else {
+            (0, 0)
+        };
+
+        fn coordinate(pos: WindowPos, start: i32, extent: u32, size: i32, offset: i32) -> i32 {
+            match pos {
+                WindowPos::PosUndefined => start + offset,
+                WindowPos::PosCentered => start + (extent as i32 - size) / 2,
+                WindowPos::Positioned(pos) => start + pos
+            }
+        }
+
+        let x = coordinate(x, bounds.x(), bounds.width(), width, offset_x);
+        let y = coordinate(y, bounds.y(), bounds.height(), height, offset_y);
+        self.set_position(WindowPos::Positioned(x), WindowPos::Positioned(y));
+        Ok(())
+    }
+
+    /// Resizes and moves the window to exactly cover one or more displays;
+    /// see `WindowBuilder::cover_displays()`.
+    ///
+    /// Covering several displays removes the window's border; use
+    /// `set_bordered()` to get it back.
+    pub fn cover_displays(&mut self, display_indices: &[i32]) -> SdlResult<()> {
+        let bounds = try!(get_displays_bounds(display_indices));
+        let (x, y, width, height) = bounds.xywh();
+
+        try!(self.set_fullscreen(FullscreenType::FTOff));
+        self.restore();
+        if display_indices.len() > 1 {
+            self.set_bordered(false);
+        }
+
+        // The window must be on the display before going fullscreen, since
+        // fullscreen windows cover the display they're on.
+        self.set_position(WindowPos::Positioned(x), WindowPos::Positioned(y));
+        self.set_size(width as i32, height as i32);
+
+        if display_indices.len() == 1 {
+            try!(self.set_fullscreen(FullscreenType::FTDesktop));
+        }
+        Ok(())
+    }
+
+    /// Gets the window's position, size and state.
+    ///
+    /// SDL doesn't report the size of a maximized window before it was
+    /// maximized, so the size of a maximized window is its maximized size.
+    pub fn geometry(&self) -> SdlResult<WindowGeometry> {
+        let (x, y) = self.get_position();
+        let (width, height) = self.get_size();
+        let flags = self.flags();
+
+        let fullscreen = if flags.contains(FULLSCREEN_DESKTOP) {
+            FullscreenType::FTDesktop
+        } else if flags.contains(FULLSCREEN) {
+            FullscreenType::FTTrue
+        } else {
+            FullscreenType::FTOff
+        };
+
+        Ok(WindowGeometry {
+            x: x,
+            y: y,
+            width: width as u32,
+            height: height as u32,
+            maximized: flags.contains(MAXIMIZED),
+            fullscreen: fullscreen,
+            display_index: try!(self.get_display_index())
+        })
+    }
+
+    /// Restores a window's position, size and state saved with `geometry()`.
+    ///
+    /// If the saved area is no longer on any display, e.g. because a monitor
+    /// was disconnected, or isn't a valid rectangle, the window is centered on its saved display, or on
+    /// the primary display if that's gone too. The window is shrunk to fit
+    /// the display if needed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn load_settings() -> Option<sdl2::video::WindowGeometry> { None }
+    /// # fn save_settings(_: &sdl2::video::WindowGeometry) {}
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("Editor", 800, 600).resizable().build().unwrap();
+    ///
+    /// if let Some(geometry) = load_settings() {
+    ///     window.properties(&sdl_context).apply_geometry(&geometry).unwrap();
+    /// }
+    ///
+    /// // ... when quitting:
+    /// save_settings(&window.properties(&sdl_context).geometry().unwrap());
+    /// ```
+    pub fn apply_geometry(&mut self, geometry: &WindowGeometry) -> SdlResult<()> {
+        try!(self.set_fullscreen(FullscreenType::FTOff));
+        self.restore();
+
+        let num_displays = try!(get_num_video_displays());
+        let mut on_screen = false;
+        if let Some(rect) = geometry.rect() {
+            for display_index in 0..num_displays {
+                if try!(get_display_bounds(display_index)).has_intersection(&rect) {
+                    on_screen = true;
+                    break;
+                }
+            }
+        }
+
+        let display_index = if geometry.display_index >= 0 && geometry.display_index < num_displays {
+            geometry.display_index
+        } else {
+            0
+        };
+        let bounds = try!(get_display_bounds(display_index));
+
+        let width = geometry.width.min(bounds.width());
+        let height = geometry.height.min(bounds.height());
+        self.set_size(width as i32, height as i32);
+
+        if on_screen {
+            self.set_position(WindowPos::Positioned(geometry.x), WindowPos::Positioned(geometry.y));
+        } else {
+            try!(self.move_to_display(display_index, WindowPos::PosCentered, WindowPos::PosCentered));
+        }
+
+        if geometry.maximized {
+            self.maximize();
+        }
+
+        self.set_fullscreen(geometry.fullscreen)
+    }
+
+    /// Sets the display mode to use when the window is in exclusive
+    /// fullscreen (`FullscreenType::FTTrue`).
+    ///
+    /// `None` uses the window's size and the desktop's refresh rate. SDL
+    /// picks the closest mode the display supports; use
+    /// `get_closest_display_mode()` to see which one that is.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::video::{DisplayMode, FullscreenType};
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("My SDL window", 800, 600).build().unwrap();
+    /// let mut properties = window.properties(&sdl_context);
+    ///
+    /// // 1280x720 at any refresh rate, in the desktop's pixel format
+    /// let request = DisplayMode::new(0, 1280, 720, 0);
+    /// let mode = properties.get_closest_display_mode(&request).unwrap();
+    /// properties.set_display_mode(Some(mode)).unwrap();
+    /// properties.set_fullscreen(FullscreenType::FTTrue).unwrap();
+    /// ```
     pub fn set_display_mode(&mut self, display_mode: Option<DisplayMode>) -> SdlResult<()> {
+        let mode = display_mode.map(|mode| mode.to_ll());
+        let mode_ptr = mode.as_ref().map_or(ptr::null(), |mode| mode as *const ll::SDL_DisplayMode);
+
         unsafe {
-            let result = ll::SDL_SetWindowDisplayMode(
-                self.raw,
-                match display_mode {
-                    Some(ref mode) => &mode.to_ll(),
-                    None => ptr::null()
-                }
-            );
+            let result = ll::SDL_SetWindowDisplayMode(self.raw, mode_ptr);
             if result < 0 {
                 Err(get_error())
             } else {
@@ -676,6 +1551,7 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Gets the display mode used when the window is in exclusive fullscreen.
     pub fn get_display_mode(&self) -> SdlResult<DisplayMode> {
         let mut dm = unsafe { mem::uninitialized() };
 
@@ -693,6 +1569,26 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Gets the desktop's display mode on the display the window is on.
+    pub fn get_desktop_display_mode(&self) -> SdlResult<DisplayMode> {
+        get_desktop_display_mode(try!(self.get_display_index()))
+    }
+
+    /// Gets all the display modes the window's display supports, best first.
+    ///
+    /// Handy for offering a resolution picker.
+    pub fn get_display_modes(&self) -> SdlResult<Vec<DisplayMode>> {
+        get_display_modes(try!(self.get_display_index()))
+    }
+
+    /// Gets the display mode of the window's display that best matches
+    /// `mode`.
+    ///
+    /// A `format` or `refresh_rate` of 0 in `mode` matches the desktop's.
+    pub fn get_closest_display_mode(&self, mode: &DisplayMode) -> SdlResult<DisplayMode> {
+        get_closest_display_mode(try!(self.get_display_index()), mode)
+    }
+
     pub fn get_window_pixel_format(&self) -> pixels::PixelFormatEnum {
         unsafe{ FromPrimitive::from_u64(ll::SDL_GetWindowPixelFormat(self.raw) as u64).unwrap() }
     }
@@ -703,6 +1599,26 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Gets the current state of the window, such as whether it's
+    /// fullscreen, minimized or has input focus.
+    ///
+    /// Unlike the flags passed to `WindowBuilder`, these reflect what the
+    /// window manager actually did.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let window = sdl_context.window("My SDL window", 800, 600).maximized().build().unwrap();
+    ///
+    /// let flags = window.properties_getters().flags();
+    /// if !flags.contains(sdl2::video::MAXIMIZED) {
+    ///     println!("The window manager refused to maximize the window");
+    /// }
+    /// ```
+    pub fn flags(&self) -> WindowFlags {
+        WindowFlags::from_bits_truncate(self.get_window_flags())
+    }
+
     pub fn set_title(&mut self, title: &str) {
         let title = CString::new(title).remove_nul();
         unsafe { ll::SDL_SetWindowTitle(self.raw, title.as_ptr()); }
@@ -720,10 +1636,72 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Sets the icon shown in the title bar, task bar and dock.
+    ///
+    /// SDL copies the pixels, so the surface can be dropped afterwards.
+    /// Surfaces with an alpha channel keep their transparency.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::surface::Surface;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("My SDL window", 800, 600).build().unwrap();
+    ///
+    /// let icon = Surface::load_bmp("icon.bmp").unwrap();
+    /// window.properties(&sdl_context).set_icon(&icon);
+    /// ```
     pub fn set_icon<S: AsRef<SurfaceRef>>(&mut self, icon: S) {
         unsafe { ll::SDL_SetWindowIcon(self.raw, icon.as_ref().raw()) }
     }
 
+    /// Returns true if the window was built with `WindowBuilder::build_shaped()`.
+    pub fn is_shaped(&self) -> bool {
+        unsafe { shape_ll::SDL_IsShapedWindow(self.raw) == 1 }
+    }
+
+    /// Sets the visible area of a shaped window from a surface, which must
+    /// have the same size as the window. The window is shown if it was
+    /// hidden.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::surface::Surface;
+    /// use sdl2::video::WindowShapeMode;
+    ///
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let shape = Surface::load_bmp("splash_shape.bmp").unwrap();
+    /// let (width, height) = shape.get_size();
+    /// let mut window = sdl_context.window("Splash", width, height).build_shaped().unwrap();
+    ///
+    /// // Every pixel that isn't pure black belongs to the window
+    /// let mode = WindowShapeMode::ColorKey(sdl2::pixels::Color::RGB(0, 0, 0));
+    /// window.properties(&sdl_context).set_shape(&shape, mode).unwrap();
+    /// ```
+    pub fn set_shape<S: AsRef<SurfaceRef>>(&mut self, shape: S, mode: WindowShapeMode) -> SdlResult<()> {
+        let mut raw_mode = mode.to_ll();
+        let result = unsafe { shape_ll::SDL_SetWindowShape(self.raw, shape.as_ref().raw(), &mut raw_mode) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(shape_error(result))
+        }
+    }
+
+    /// Gets the shape mode of a shaped window, or `None` if no shape has
+    /// been set yet.
+    pub fn get_shape_mode(&self) -> SdlResult<Option<WindowShapeMode>> {
+        let mut raw_mode = WindowShapeMode::Default.to_ll();
+        let result = unsafe { shape_ll::SDL_GetShapedWindowMode(self.raw, &mut raw_mode) };
+
+        match result {
+            0 => Ok(Some(WindowShapeMode::from_ll(raw_mode))),
+            shape_ll::SDL_WINDOW_LACKS_SHAPE => Ok(None),
+            _ => Err(shape_error(result))
+        }
+    }
+
     //pub fn SDL_SetWindowData(window: *SDL_Window, name: *c_char, userdata: *c_void) -> *c_void; //TODO: Figure out what this does
     //pub fn SDL_GetWindowData(window: *SDL_Window, name: *c_char) -> *c_void;
 
@@ -756,10 +1734,14 @@ impl<'a> WindowProperties<'a> {
         (w as i32, h as i32)
     }
 
+    /// Sets the smallest size the user can resize the window to.
+    ///
+    /// Both dimensions must be positive.
     pub fn set_minimum_size(&mut self, w: i32, h: i32) {
         unsafe { ll::SDL_SetWindowMinimumSize(self.raw, w as c_int, h as c_int) }
     }
 
+    /// Gets the minimum size; `(0, 0)` if there is none.
     pub fn get_minimum_size(&self) -> (i32, i32) {
         let mut w: c_int = 0;
         let mut h: c_int = 0;
@@ -767,10 +1749,14 @@ impl<'a> WindowProperties<'a> {
         (w as i32, h as i32)
     }
 
+    /// Sets the largest size the user can resize the window to.
+    ///
+    /// Both dimensions must be positive.
     pub fn set_maximum_size(&mut self, w: i32, h: i32) {
         unsafe { ll::SDL_SetWindowMaximumSize(self.raw, w as c_int, h as c_int) }
     }
 
+    /// Gets the maximum size; `(0, 0)` if there is none.
     pub fn get_maximum_size(&self) -> (i32, i32) {
         let mut w: c_int = 0;
         let mut h: c_int = 0;
@@ -778,10 +1764,56 @@ impl<'a> WindowProperties<'a> {
         (w as i32, h as i32)
     }
 
+    /// Sets whether the user can resize the window.
+    ///
+    /// Has no effect on fullscreen windows. Requires SDL 2.0.5 or later;
+    /// with older versions, use `WindowBuilder::resizable()` instead.
+    #[cfg(feature = "sdl_2_0_5")]
+    pub fn set_resizable(&mut self, resizable: bool) {
+        unsafe { ll::SDL_SetWindowResizable(self.raw, if resizable { 1 } else { 0 }) }
+    }
+
+    /// Checks if the user can resize the window.
+    pub fn is_resizable(&self) -> bool {
+        self.flags().contains(RESIZABLE)
+    }
+
+    /// Adds or removes the window's border and title bar.
+    ///
+    /// Has no effect on fullscreen windows.
     pub fn set_bordered(&mut self, bordered: bool) {
         unsafe { ll::SDL_SetWindowBordered(self.raw, if bordered { 1 } else { 0 }) }
     }
 
+    /// Checks if the window has a border and title bar.
+    pub fn is_bordered(&self) -> bool {
+        !self.flags().contains(BORDERLESS)
+    }
+
+    /// Sets whether the window stays above other windows.
+    ///
+    /// Requires SDL 2.0.16 or later; with older versions, use
+    /// `WindowBuilder::always_on_top()` instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("Picture in picture", 320, 180).build().unwrap();
+    ///
+    /// let mut properties = window.properties(&sdl_context);
+    /// let pinned = properties.is_always_on_top();
+    /// properties.set_always_on_top(!pinned);
+    /// ```
+    #[cfg(feature = "sdl_2_0_16")]
+    pub fn set_always_on_top(&mut self, on_top: bool) {
+        unsafe { ll::SDL_SetWindowAlwaysOnTop(self.raw, if on_top { 1 } else { 0 }) }
+    }
+
+    /// Checks if the window stays above other windows.
+    pub fn is_always_on_top(&self) -> bool {
+        self.flags().contains(ALWAYS_ON_TOP)
+    }
+
     pub fn show(&mut self) {
         unsafe { ll::SDL_ShowWindow(self.raw) }
     }
@@ -790,18 +1822,93 @@ impl<'a> WindowProperties<'a> {
         unsafe { ll::SDL_HideWindow(self.raw) }
     }
 
+    /// Checks if the window is shown, i.e. not hidden with `hide()`.
+    ///
+    /// A minimized window still counts as shown.
+    pub fn is_shown(&self) -> bool {
+        self.flags().contains(SHOWN)
+    }
+
+    /// Raises the window above other windows and gives it the input focus.
     pub fn raise(&mut self) {
         unsafe { ll::SDL_RaiseWindow(self.raw) }
     }
 
+    /// Makes the window a modal dialog of `parent`: it stays on top of the
+    /// parent, which doesn't take input while the dialog is open.
+    ///
+    /// Only supported on X11 and Wayland; returns an error elsewhere.
+    /// Requires SDL 2.0.5 or later.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let main_window = sdl_context.window("Editor", 800, 600).build().unwrap();
+    /// let mut dialog = sdl_context.window("Preferences", 400, 300).build().unwrap();
+    ///
+    /// let mut dialog_properties = dialog.properties(&sdl_context);
+    /// dialog_properties.set_modal_for(&main_window).unwrap();
+    /// dialog_properties.set_input_focus().unwrap();
+    /// ```
+    #[cfg(feature = "sdl_2_0_5")]
+    pub fn set_modal_for(&mut self, parent: &Window) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_SetWindowModalFor(self.raw, parent.raw) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Gives the window the input focus without raising it.
+    ///
+    /// Only supported on X11; use `raise()` elsewhere. The window must be
+    /// shown. Requires SDL 2.0.5 or later.
+    #[cfg(feature = "sdl_2_0_5")]
+    pub fn set_input_focus(&mut self) -> SdlResult<()> {
+        let result = unsafe { ll::SDL_SetWindowInputFocus(self.raw) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Makes the window as large as possible.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("Editor", 800, 600).resizable().build().unwrap();
+    ///
+    /// // A double click on a custom title bar toggles between maximized and normal
+    /// let mut properties = window.properties(&sdl_context);
+    /// if properties.is_maximized() {
+    ///     properties.restore();
+    /// } else {
+    ///     properties.maximize();
+    /// }
+    /// ```
     pub fn maximize(&mut self) {
         unsafe { ll::SDL_MaximizeWindow(self.raw) }
     }
 
+    /// Checks if the window is maximized.
+    pub fn is_maximized(&self) -> bool {
+        self.flags().contains(MAXIMIZED)
+    }
+
+    /// Minimizes the window to an iconic representation, e.g. the taskbar.
     pub fn minimize(&mut self) {
         unsafe { ll::SDL_MinimizeWindow(self.raw) }
     }
 
+    /// Checks if the window is minimized.
+    pub fn is_minimized(&self) -> bool {
+        self.flags().contains(MINIMIZED)
+    }
+
+    /// Restores the size and position of a minimized or maximized window.
     pub fn restore(&mut self) {
         unsafe { ll::SDL_RestoreWindow(self.raw) }
     }
@@ -856,14 +1963,25 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Confines the mouse cursor to the window while it has input focus.
+    ///
+    /// Only one window can have the grab at a time; see
+    /// `get_grabbed_window_id()`. Combine this with relative mouse mode for
+    /// first-person controls.
     pub fn set_grab(&mut self, grabbed: bool) {
         unsafe { ll::SDL_SetWindowGrab(self.raw, if grabbed { 1 } else { 0 }) }
     }
 
+    /// Checks if the window has grabbed the mouse cursor.
     pub fn get_grab(&self) -> bool {
         unsafe { ll::SDL_GetWindowGrab(self.raw) == 1 }
     }
 
+    /// Sets the brightness of the display the window is on, where 1.0 is
+    /// normal.
+    ///
+    /// This builds a gamma ramp with `SDL_CalculateGammaRamp` and applies it
+    /// while the window has input focus. Not every platform supports it.
     pub fn set_brightness(&mut self, brightness: f64) -> SdlResult<()> {
         unsafe {
             if ll::SDL_SetWindowBrightness(self.raw, brightness as c_float) == 0 {
@@ -874,10 +1992,29 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
+    /// Gets the brightness set with `set_brightness()`, 1.0 by default.
     pub fn get_brightness(&self) -> f64 {
         unsafe { ll::SDL_GetWindowBrightness(self.raw) as f64 }
     }
 
+    /// Sets the gamma ramp of each color channel for the display the window
+    /// is on. `None` leaves that channel's ramp unchanged.
+    ///
+    /// Each ramp maps an 8-bit input value to a 16-bit output value; the
+    /// identity ramp is `ramp[i] = i * 257`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl2::init().video().unwrap();
+    /// let mut window = sdl_context.window("My SDL window", 800, 600).build().unwrap();
+    ///
+    /// // Darken the screen by a third
+    /// let mut ramp = [0u16; 256];
+    /// for (i, value) in ramp.iter_mut().enumerate() {
+    ///     *value = (i * 257 * 2 / 3) as u16;
+    /// }
+    /// window.properties(&sdl_context).set_gamma_ramp(Some(&ramp), Some(&ramp), Some(&ramp)).unwrap();
+    /// ```
     pub fn set_gamma_ramp(&mut self, red: Option<&[u16; 256]>, green: Option<&[u16; 256]>, blue: Option<&[u16; 256]>) -> SdlResult<()> {
         unsafe {
             let unwrapped_red = match red {
@@ -901,10 +2038,11 @@ impl<'a> WindowProperties<'a> {
         }
     }
 
-    pub fn get_gamma_ramp(&self) -> SdlResult<(Vec<u16>, Vec<u16>, Vec<u16>)> {
-        let mut red: Vec<u16> = Vec::with_capacity(256);
-        let mut green: Vec<u16> = Vec::with_capacity(256);
-        let mut blue: Vec<u16> = Vec::with_capacity(256);
+    /// Gets the red, green and blue gamma ramps for the window.
+    pub fn get_gamma_ramp(&self) -> SdlResult<([u16; 256], [u16; 256], [u16; 256])> {
+        let mut red = [0u16; 256];
+        let mut green = [0u16; 256];
+        let mut blue = [0u16; 256];
         let result = unsafe {ll::SDL_GetWindowGammaRamp(self.raw, red.as_mut_ptr(), green.as_mut_ptr(), blue.as_mut_ptr()) == 0};
         if result {
             Ok((red, green, blue))
@@ -944,10 +2082,37 @@ pub fn video_quit() {
     unsafe { ll::SDL_VideoQuit() }
 }
 
+/// Gets the name of the video driver in use, or an empty string if video
+/// isn't initialized; see `current_driver()`.
 pub fn get_current_video_driver() -> String {
+    current_driver().unwrap_or_default()
+}
+
+/// Lists the video drivers compiled into SDL, e.g. "x11", "wayland" or "dummy".
+///
+/// Not every driver is usable on every system; `InitBuilder::video_driver()`
+/// fails for drivers that can't be initialized.
+///
+/// # Example
+/// ```no_run
+/// for driver in sdl2::video::drivers() {
+///     println!("{}", driver);
+/// }
+/// ```
+pub fn drivers() -> Vec<String> {
+    let num_drivers = get_num_video_drivers().unwrap_or(0);
+    (0..num_drivers).map(get_video_driver).collect()
+}
+
+/// Gets the name of the video driver in use, or `None` if video isn't initialized.
+pub fn current_driver() -> Option<String> {
     unsafe {
         let video = ll::SDL_GetCurrentVideoDriver();
-        String::from_utf8_lossy(CStr::from_ptr(video).to_bytes()).to_string()
+        if video.is_null() {
+            None
+        } else {
+            Some(String::from_utf8_lossy(CStr::from_ptr(video).to_bytes()).to_string())
+        }
     }
 }
 
@@ -979,6 +2144,127 @@ pub fn get_display_bounds(display_index: i32) -> SdlResult<Rect> {
     }
 }
 
+/// Gets the smallest rectangle that contains all the given displays, in
+/// screen coordinates.
+pub fn get_displays_bounds(display_indices: &[i32]) -> SdlResult<Rect> {
+    let mut indices = display_indices.iter();
+    let first = match indices.next() {
+        Some(&index) => try!(get_display_bounds(index)),
+        None => return Err("No displays given".to_owned())
+    };
+
+    indices.try_fold(first, |bounds, &index| {
+        Ok(bounds.union(&try!(get_display_bounds(index))))
+    })
+}
+
+/// Gets the area of a display that isn't taken up by the task bar, dock or
+/// menu bar.
+///
+/// Requires SDL 2.0.5 or later.
+#[cfg(feature = "sdl_2_0_5")]
+pub fn get_display_usable_bounds(display_index: i32) -> SdlResult<Rect> {
+    let mut out = unsafe { mem::zeroed() };
+    let result = unsafe { ll::SDL_GetDisplayUsableBounds(display_index as c_int, &mut out) == 0 };
+
+    if result {
+        // Unwrap twice because there is always a non-empty rect.
+        Ok(Rect::from_ll(out).unwrap().unwrap())
+    } else {
+        Err(get_error())
+    }
+}
+
+/// The pixel density of a display, in dots per inch.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DisplayDpi {
+    pub diagonal: f32,
+    pub horizontal: f32,
+    pub vertical: f32
+}
+
+/// Gets the pixel density of a display.
+///
+/// Not every platform can report this, so expect an error.
+#[cfg(feature = "sdl_2_0_4")]
+pub fn get_display_dpi(display_index: i32) -> SdlResult<DisplayDpi> {
+    let mut dpi = DisplayDpi { diagonal: 0.0, horizontal: 0.0, vertical: 0.0 };
+    let result = unsafe {
+        ll::SDL_GetDisplayDPI(display_index as c_int, &mut dpi.diagonal, &mut dpi.horizontal, &mut dpi.vertical)
+    };
+
+    if result == 0 {
+        Ok(dpi)
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Gets all the display modes a display supports, best first.
+pub fn get_display_modes(display_index: i32) -> SdlResult<Vec<DisplayMode>> {
+    let count = try!(get_num_display_modes(display_index));
+    let mut modes = Vec::with_capacity(count as usize);
+    for mode_index in 0..count {
+        modes.push(try!(get_display_mode(display_index, mode_index)));
+    }
+    Ok(modes)
+}
+
+/// A display found by `list_displays()`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DisplayInfo {
+    pub index: i32,
+    pub name: String,
+    /// The area of the display within the desktop, in screen coordinates.
+    pub bounds: Rect,
+    /// The part of `bounds` that windows can use without being covered by
+    /// the task bar, dock or menu bar.
+    #[cfg(feature = "sdl_2_0_5")]
+    pub usable_bounds: Rect,
+    /// `None` if the platform can't tell.
+    #[cfg(feature = "sdl_2_0_4")]
+    pub dpi: Option<DisplayDpi>
+}
+
+impl DisplayInfo {
+    /// Gets all the display modes the display supports, best first.
+    pub fn modes(&self) -> SdlResult<Vec<DisplayMode>> {
+        get_display_modes(self.index)
+    }
+}
+
+/// Lists the connected displays.
+///
+/// # Example
+/// ```no_run
+/// let _sdl_context = sdl2::init().video().unwrap();
+///
+/// for display in sdl2::video::list_displays().unwrap() {
+///     println!("{}: {} at {:?}", display.index, display.name, display.bounds);
+///     for mode in display.modes().unwrap() {
+///         println!("    {}x{} @ {} Hz", mode.w, mode.h, mode.refresh_rate);
+///     }
+/// }
+/// ```
+pub fn list_displays() -> SdlResult<Vec<DisplayInfo>> {
+    let count = try!(get_num_video_displays());
+    let mut displays = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+        displays.push(DisplayInfo {
+            index: index,
+            name: get_display_name(index),
+            bounds: try!(get_display_bounds(index)),
+            #[cfg(feature = "sdl_2_0_5")]
+            usable_bounds: try!(get_display_usable_bounds(index)),
+            #[cfg(feature = "sdl_2_0_4")]
+            dpi: get_display_dpi(index).ok()
+        });
+    }
+
+    Ok(displays)
+}
+
 pub fn get_num_display_modes(display_index: i32) -> SdlResult<i32> {
     let result = unsafe { ll::SDL_GetNumDisplayModes(display_index as c_int) };
     if result < 0 {
@@ -1034,6 +2320,10 @@ pub fn get_closest_display_mode(display_index: i32, mode: &DisplayMode) -> SdlRe
     }
 }
 
+/// Returns true if the system may blank the screen or start a screensaver.
+///
+/// SDL disables the screensaver by default when the video subsystem is
+/// initialized.
 pub fn is_screen_saver_enabled() -> bool {
     unsafe { ll::SDL_IsScreenSaverEnabled() == 1 }
 }
@@ -1046,6 +2336,55 @@ pub fn disable_screen_saver() {
     unsafe { ll::SDL_DisableScreenSaver() }
 }
 
+/// Keeps the screensaver disabled while it's alive; see `inhibit_screen_saver()`.
+#[must_use]
+pub struct ScreenSaverInhibitor {
+    was_enabled: bool,
+    /// The screensaver is controlled from the main thread.
+    /// `!Send` and `!Sync`
+    _nosendsync: PhantomData<*mut ()>
+}
+
+impl ScreenSaverInhibitor {
+    /// Returns true if the screensaver was enabled when the inhibitor was created.
+    pub fn was_enabled(&self) -> bool { self.was_enabled }
+}
+
+impl Drop for ScreenSaverInhibitor {
+    fn drop(&mut self) {
+        if self.was_enabled {
+            enable_screen_saver();
+        } else {
+            disable_screen_saver();
+        }
+    }
+}
+
+/// Disables the screensaver until the returned inhibitor is dropped, which
+/// restores the previous setting.
+///
+/// # Example
+/// ```no_run
+/// let sdl_context = sdl2::init().video().unwrap();
+/// sdl2::video::enable_screen_saver();
+///
+/// {
+///     let _inhibitor = sdl2::video::inhibit_screen_saver(&sdl_context);
+///     // play the cutscene; the screen won't blank
+/// }
+///
+/// assert!(sdl2::video::is_screen_saver_enabled());
+/// ```
+pub fn inhibit_screen_saver(_sdl: &Sdl) -> ScreenSaverInhibitor {
+    let was_enabled = is_screen_saver_enabled();
+    disable_screen_saver();
+
+    ScreenSaverInhibitor {
+        was_enabled: was_enabled,
+        _nosendsync: PhantomData
+    }
+}
+
 pub fn gl_load_library<P: AsRef<::std::path::Path>>(path: P) -> SdlResult<()> {
     unsafe {
         // TODO: use OsStr::to_cstring() once it's stable
@@ -1058,6 +2397,43 @@ pub fn gl_load_library<P: AsRef<::std::path::Path>>(path: P) -> SdlResult<()> {
     }
 }
 
+/// Loads a Vulkan loader library, or the default one if `path` is `None`.
+///
+/// This is done automatically when a window is built with
+/// `WindowBuilder::vulkan()`. Requires SDL 2.0.6 or later.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn vulkan_load_library<P: AsRef<::std::path::Path>>(path: Option<P>) -> SdlResult<()> {
+    let path = match path {
+        Some(path) => match path.as_ref().to_str() {
+            Some(path) => Some(try!(CString::new(path).unwrap_or_sdlresult())),
+            None => return Err("Path is not valid UTF-8".to_owned())
+        },
+        None => None
+    };
+
+    let result = unsafe { vk_ll::SDL_Vulkan_LoadLibrary(path.as_ref().map_or(ptr::null(), |path| path.as_ptr())) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Unloads the library loaded with `vulkan_load_library()`.
+/// Requires SDL 2.0.6 or later.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn vulkan_unload_library() {
+    unsafe { vk_ll::SDL_Vulkan_UnloadLibrary() }
+}
+
+/// Gets the address of `vkGetInstanceProcAddr`, from which all other Vulkan
+/// functions can be loaded, or null if no Vulkan library is loaded.
+/// Requires SDL 2.0.6 or later.
+#[cfg(feature = "sdl_2_0_6")]
+pub fn vulkan_get_instance_proc_addr() -> *const ::std::os::raw::c_void {
+    unsafe { vk_ll::SDL_Vulkan_GetVkGetInstanceProcAddr() as *const ::std::os::raw::c_void }
+}
+
 pub fn gl_unload_library() {
     unsafe { ll::SDL_GL_UnloadLibrary(); }
 }
@@ -1078,6 +2454,19 @@ pub fn gl_extension_supported(extension: &str) -> bool {
     }
 }
 
+/// Gets the id of the window that has grabbed input, if any.
+///
+/// See `WindowProperties::set_grab()`.
+#[cfg(feature = "sdl_2_0_4")]
+pub fn get_grabbed_window_id() -> Option<u32> {
+    let raw = unsafe { ll::SDL_GetGrabbedWindow() };
+    if raw.is_null() {
+        None
+    } else {
+        Some(unsafe { ll::SDL_GetWindowID(raw) })
+    }
+}
+
 pub fn gl_get_current_window_id() -> SdlResult<u32> {
     let raw = unsafe { ll::SDL_GL_GetCurrentWindow() };
     if raw == ptr::null_mut() {
@@ -1099,10 +2488,98 @@ pub fn gl_release_current_context() -> SdlResult<()> {
     }
 }
 
-pub fn gl_set_swap_interval(interval: i32) -> bool {
-    unsafe { ll::SDL_GL_SetSwapInterval(interval as c_int) == 0 }
+/// How buffer swaps are synchronized with the display's vertical retrace.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SwapInterval {
+    /// Swaps happen immediately, which may cause tearing.
+    Immediate = 0,
+    /// Swaps wait for the vertical retrace.
+    VSync = 1,
+    /// Adaptive vsync: swaps wait for the vertical retrace, unless the
+    /// frame was late, in which case they happen immediately.
+    LateSwapTearing = -1
+}
+
+impl From<SwapInterval> for i32 {
+    fn from(interval: SwapInterval) -> i32 {
+        interval as i32
+    }
+}
+
+impl TryFrom<i32> for SwapInterval {
+    type Error = String;
+
+    /// Fails for intervals greater than 1, which wait for more than one
+    /// vertical retrace per swap.
+    fn try_from(interval: i32) -> SdlResult<SwapInterval> {
+        match interval {
+            -1 => Ok(SwapInterval::LateSwapTearing),
+            0 => Ok(SwapInterval::Immediate),
+            1 => Ok(SwapInterval::VSync),
+            _ => Err(format!("Swap interval {} has no SwapInterval variant", interval))
+        }
+    }
 }
 
+/// Sets the swap interval of the current OpenGL context.
+///
+/// `interval` is either a `SwapInterval` or the raw number of vertical
+/// retraces to wait for per swap, which may be greater than 1.
+///
+/// Returns an error if the interval isn't supported, which is common for
+/// `LateSwapTearing`.
+///
+/// # Example
+/// ```no_run
+/// use sdl2::video::{gl_set_swap_interval, SwapInterval};
+///
+/// let sdl_context = sdl2::init().video().unwrap();
+/// let window = sdl_context.window("Game", 800, 600).opengl().build().unwrap();
+/// let _context = window.gl_create_context().unwrap();
+///
+/// // Prefer adaptive vsync, and fall back to regular vsync
+/// if gl_set_swap_interval(SwapInterval::LateSwapTearing).is_err() {
+///     gl_set_swap_interval(SwapInterval::VSync).unwrap();
+/// }
+/// ```
+pub fn gl_set_swap_interval<S: Into<i32>>(interval: S) -> SdlResult<()> {
+    let result = unsafe { ll::SDL_GL_SetSwapInterval(interval.into() as c_int) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
+
+/// Gets the swap interval of the current OpenGL context, as passed to
+/// `gl_set_swap_interval()`; use `SwapInterval::try_from()` to match on it.
+///
+/// Returns 0 if there's no current context.
 pub fn gl_get_swap_interval() -> i32 {
     unsafe { ll::SDL_GL_GetSwapInterval() as i32 }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{FullscreenType, WindowGeometry};
+
+    #[test]
+    fn test_geometry_rect() {
+        let mut geometry = WindowGeometry {
+            x: 100,
+            y: 50,
+            width: 800,
+            height: 600,
+            maximized: false,
+            fullscreen: FullscreenType::FTOff,
+            display_index: 0
+        };
+        assert_eq!(geometry.rect().map(|rect| rect.xywh()), Some((100, 50, 800, 600)));
+
+        // Sizes from a hand-edited file can't panic.
+        geometry.width = u32::MAX;
+        assert_eq!(geometry.rect(), None);
+        geometry.width = i32::MAX as u32;
+        assert_eq!(geometry.rect(), None);
+    }
+}